@@ -0,0 +1,22 @@
+drop table if exists sys_api_key;
+create table sys_api_key
+(
+    id             bigint auto_increment comment '主键'
+        primary key,
+    user_id        bigint                                 not null comment '所属用户id',
+    key_name       varchar(50)                            not null comment '密钥名称',
+    key_prefix     varchar(16)                            not null comment '密钥前缀(用于识别密钥)',
+    key_hash       char(64)                               not null comment '密钥摘要(sha256,密钥只在创建时返回一次)',
+    permissions    varchar(4000) default ''               not null comment '允许访问的接口(逗号分隔,为空时与所属用户的权限相同)',
+    expire_time    datetime                               null comment '过期时间(为空时不过期)',
+    status         tinyint       default 1                not null comment '状态(1:正常,0:已吊销)',
+    last_used_time datetime                               null comment '最后使用时间',
+    last_used_ip   varchar(128)  default ''               not null comment '最后使用IP',
+    remark         varchar(255)                           null comment '备注',
+    create_time    datetime      default CURRENT_TIMESTAMP not null comment '创建时间',
+    update_time    datetime                               null on update CURRENT_TIMESTAMP comment '修改时间',
+    constraint uk_key_hash
+        unique (key_hash)
+) comment = 'API密钥表';
+
+create index idx_user_id on sys_api_key (user_id);
@@ -0,0 +1,35 @@
+drop table if exists sys_config;
+create table sys_config
+(
+    id           bigint auto_increment comment '参数主键'
+        primary key,
+    config_name  varchar(100) default ''                not null comment '参数名称',
+    config_key   varchar(100) default ''                not null comment '参数键名',
+    config_value varchar(500) default ''                not null comment '参数键值',
+    value_type   tinyint      default 1                 not null comment '参数值类型（1:字符串,2:数字,3:布尔,4:JSON）',
+    is_builtin   tinyint      default 0                 not null comment '是否系统内置（0:否,1:是）',
+    remark       varchar(500) default ''                not null comment '备注',
+    create_time  datetime     default CURRENT_TIMESTAMP not null comment '创建时间',
+    update_time  datetime     null on update CURRENT_TIMESTAMP comment '修改时间',
+    constraint config_key
+        unique (config_key)
+) comment = '参数配置表';
+
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('用户管理-账号初始密码', 'sys.user.initPassword', '123456', 1, 1, '新增用户未填写密码时使用的初始密码');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('账号自助-验证码开关', 'sys.account.captchaEnabled', 'true', 3, 1, '是否开启验证码功能（true开启，false关闭）');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('账号自助-是否开启用户注册功能', 'sys.account.registerUser', 'false', 3, 1, '是否开启注册用户功能（true开启，false关闭）');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('日志清理-登录日志保留天数', 'sys.log.loginLogRetentionDays', '180', 2, 1, '超过保留天数的登录日志由定时任务清理（0表示不按天数清理）');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('日志清理-登录日志最大行数', 'sys.log.loginLogMaxRows', '0', 2, 1, '超过最大行数时清理最早的登录日志（0表示不限制）');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('日志清理-操作日志保留天数', 'sys.log.operateLogRetentionDays', '180', 2, 1, '超过保留天数的操作日志由定时任务清理（0表示不按天数清理）');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('日志清理-操作日志最大行数', 'sys.log.operateLogMaxRows', '0', 2, 1, '超过最大行数时清理最早的操作日志（0表示不限制）');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('日志清理-清理前归档', 'sys.log.archiveEnabled', 'false', 3, 1, '清理日志前是否归档为gzip压缩的JSONL文件（true开启，false关闭）');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('日志清理-归档目录', 'sys.log.archiveDir', 'logs/archive', 1, 1, '日志归档文件的保存目录');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('登录风险-是否开启检测', 'sys.login.riskEnabled', 'true', 3, 1, '检测新设备、新国家/城市、短时间内跨国登录和非允许时段登录');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('登录风险-允许登录时段', 'sys.login.allowedHours', '', 1, 1, '格式: 08:00-20:00,支持跨天(22:00-06:00),为空时不限制');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('登录风险-跨国登录间隔(分钟)', 'sys.login.travelMinutes', '120', 2, 1, '与上次登录的国家不同且间隔小于该分钟数时视为高风险,0表示不检测');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('登录风险-二次验证等级', 'sys.login.verifyRiskLevel', '0', 2, 1, '风险等级大于等于该值时需要输入验证码完成登录(1:低,2:中,3:高),0表示不需要');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('登录-允许的账号类型', 'sys.login.accountTypes', 'mobile,username,email', 1, 1, '密码登录允许使用的账号类型,多个用逗号分隔(mobile:手机,username:用户账号,email:邮箱)');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('密码策略-最小长度', 'sys.password.minLength', '8', 2, 1, '新密码的最小长度');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('密码策略-字符种类', 'sys.password.charTypes', '2', 2, 1, '新密码至少包含小写字母、大写字母、数字、特殊字符中的几种(1-4)');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('密码策略-历史密码次数', 'sys.password.historyCount', '3', 2, 1, '新密码不能与最近N次使用过的密码相同,0表示不限制');
+INSERT INTO sys_config (config_name, config_key, config_value, value_type, is_builtin, remark) VALUES ('密码策略-有效天数', 'sys.password.maxAgeDays', '90', 2, 1, '密码超过有效天数后登录时需要先修改密码,0表示永不过期');
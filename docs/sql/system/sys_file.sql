@@ -0,0 +1,36 @@
+drop table if exists sys_file;
+create table sys_file
+(
+    id           bigint auto_increment comment '文件id'
+        primary key,
+    blob_id      bigint                                 not null comment '存储文件id',
+    file_name    varchar(255)                           not null comment '原始文件名',
+    file_key     varchar(255)                           not null comment '存储路径',
+    storage_type varchar(10)                            not null comment '存储类型(local:本地文件系统,s3:对象存储)',
+    file_size    bigint       default 0                 not null comment '文件大小(字节)',
+    mime_type    varchar(100) default ''                not null comment '文件类型',
+    file_hash    char(64)                               not null comment '文件内容的sha256',
+    owner_id     bigint                                 not null comment '上传者id',
+    create_time  datetime     default CURRENT_TIMESTAMP not null comment '创建时间',
+    update_time  datetime     null on update CURRENT_TIMESTAMP comment '更新时间'
+) comment = '文件信息表';
+
+create index idx_owner_id on sys_file (owner_id);
+create index idx_blob_id on sys_file (blob_id);
+
+drop table if exists sys_file_blob;
+create table sys_file_blob
+(
+    id           bigint auto_increment comment '存储文件id'
+        primary key,
+    file_key     varchar(255)                           not null comment '存储路径',
+    storage_type varchar(10)                            not null comment '存储类型(local:本地文件系统,s3:对象存储)',
+    file_size    bigint       default 0                 not null comment '文件大小(字节)',
+    mime_type    varchar(100) default ''                not null comment '文件类型',
+    file_hash    char(64)                               not null comment '文件内容的sha256',
+    ref_count    int          default 1                 not null comment '引用次数(引用这个存储文件的文件信息数量)',
+    create_time  datetime     default CURRENT_TIMESTAMP not null comment '创建时间',
+    update_time  datetime     null on update CURRENT_TIMESTAMP comment '更新时间',
+    constraint uk_file_hash
+        unique (file_hash, storage_type)
+) comment = '存储文件表';
@@ -0,0 +1,22 @@
+drop table if exists sys_job;
+create table sys_job
+(
+    id              bigint auto_increment comment '任务ID'
+        primary key,
+    job_name        varchar(64)  default ''                not null comment '任务名称',
+    job_group       varchar(64)  default 'DEFAULT'         not null comment '任务组名',
+    invoke_target   varchar(100) default ''                not null comment '调用目标(代码中注册的任务名称)',
+    job_params      varchar(500) default ''                not null comment '任务参数',
+    cron_expression varchar(255) default ''                not null comment 'cron执行表达式(秒 分 时 日 月 周 [年])',
+    misfire_policy  tinyint      default 3                 not null comment '计划执行错误策略（1:立即执行,2:执行一次,3:放弃执行）',
+    concurrent      tinyint      default 1                 not null comment '是否禁止并发执行（0:允许,1:禁止）',
+    status          tinyint      default 0                 not null comment '状态（0:暂停,1:正常）',
+    remark          varchar(500) default ''                not null comment '备注',
+    create_time     datetime     default CURRENT_TIMESTAMP not null comment '创建时间',
+    update_time     datetime     null on update CURRENT_TIMESTAMP comment '修改时间'
+) comment = '定时任务调度表';
+
+INSERT INTO sys_job (job_name, job_group, invoke_target, job_params, cron_expression, misfire_policy, concurrent, status, remark) VALUES ('系统默认（无参）', 'DEFAULT', 'demoTask.noParams', '', '0/10 * * * * ?', 3, 1, 0, '示例任务');
+INSERT INTO sys_job (job_name, job_group, invoke_target, job_params, cron_expression, misfire_policy, concurrent, status, remark) VALUES ('系统默认（有参）', 'DEFAULT', 'demoTask.params', 'hello', '0/15 * * * * ?', 3, 1, 0, '示例任务');
+INSERT INTO sys_job (job_name, job_group, invoke_target, job_params, cron_expression, misfire_policy, concurrent, status, remark) VALUES ('刷新参数缓存', 'SYSTEM', 'configTask.refreshCache', '', '0 0 2 * * ?', 3, 1, 0, '每天凌晨2点清空参数缓存');
+INSERT INTO sys_job (job_name, job_group, invoke_target, job_params, cron_expression, misfire_policy, concurrent, status, remark) VALUES ('清理过期日志', 'SYSTEM', 'logTask.cleanExpiredLog', '', '0 0 3 * * ?', 2, 1, 1, '每天凌晨3点按参数设置清理登录日志和操作日志');
@@ -0,0 +1,21 @@
+drop table if exists sys_job_log;
+create table sys_job_log
+(
+    id            bigint auto_increment comment '任务日志ID'
+        primary key,
+    job_id        bigint                                  not null comment '任务ID',
+    job_name      varchar(64)   default ''                not null comment '任务名称',
+    job_group     varchar(64)   default ''                not null comment '任务组名',
+    invoke_target varchar(100)  default ''                not null comment '调用目标',
+    job_params    varchar(500)  default ''                not null comment '任务参数',
+    trigger_type  tinyint       default 1                 not null comment '触发方式（1:定时触发,2:手动执行,3:错过补偿）',
+    job_message   varchar(500)  default ''                not null comment '日志信息',
+    status        tinyint       default 1                 not null comment '执行状态（0:失败,1:成功）',
+    error_msg     varchar(2000) default ''                not null comment '异常信息',
+    start_time    datetime                                not null comment '开始时间',
+    end_time      datetime                                not null comment '结束时间',
+    duration      bigint        default 0                 not null comment '执行耗时(毫秒)',
+    create_time   datetime      default CURRENT_TIMESTAMP not null comment '创建时间'
+) comment = '定时任务调度日志表';
+
+create index idx_sys_job_log_job_id on sys_job_log (job_id, start_time);
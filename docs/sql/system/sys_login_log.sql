@@ -14,7 +14,13 @@ create table sys_login_log
     engine         varchar(50)  default ''                not null comment '渲染引擎信息',
     engine_details varchar(50)  default ''                not null comment '渲染引擎详细信息',
     extra          varchar(50)  default ''                not null comment '其他信息（可选）',
+    client_type    varchar(10)  default 'human'           not null comment '客户端类型(human:用户,bot:爬虫,api:接口调用)',
     status         tinyint      default 0                 not null comment '登录状态(0:失败,1:成功)',
     msg            varchar(255) default ''                not null comment '提示消息',
+    risk_level     tinyint      default 0                 not null comment '风险等级(0:无,1:低,2:中,3:高)',
+    risk_reason    varchar(255) default ''                not null comment '风险原因',
     login_time     datetime     default CURRENT_TIMESTAMP not null comment '访问时间'
 ) comment = '系统访问记录';
+
+create index idx_sys_login_log_name on sys_login_log (login_name, status);
+create index idx_sys_login_log_time on sys_login_log (login_time, id);
@@ -166,3 +166,57 @@ INSERT INTO sys_menu (menu_name, menu_type, `status`, sort, parent_id, menu_url,
 
 
 
+
+-- 配置参数设置权限
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('参数设置', 2, 1, 9, 2, '/system/config', '', 'ControlOutlined', '参数设置管理');
+
+select * from sys_menu where menu_name='参数设置';
+
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('添加参数配置', 3, 1, 1, 88, '', '/api/system/config/addConfig', '', '添加参数配置');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('删除参数配置', 3, 1, 2, 88, '', '/api/system/config/deleteConfig', '', '删除参数配置');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('更新参数配置', 3, 1, 3, 88, '', '/api/system/config/updateConfig', '', '更新参数配置');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询参数配置详情', 3, 1, 4, 88, '', '/api/system/config/queryConfigDetail', '', '查询参数配置详情');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询参数配置列', 3, 1, 5, 88, '', '/api/system/config/queryConfigList', '', '查询参数配置列');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('刷新参数缓存', 3, 1, 6, 88, '', '/api/system/config/refreshConfigCache', '', '刷新参数缓存');
+
+-- 配置通知公告阅读统计权限
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询通知公告阅读统计', 3, 1, 7, 65, '', '/api/system/notice/queryNoticeReadStats', '', '查询通知公告阅读统计');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询通知公告已读用户', 3, 1, 8, 65, '', '/api/system/notice/queryNoticeReadUserList', '', '查询通知公告已读用户');
+
+-- 配置强制用户下线权限
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('强制用户下线', 3, 1, 11, 3, '', '/api/system/user/forceLogout', '', '强制用户下线');
+
+-- 配置定时任务权限
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('定时任务', 2, 1, 10, 2, '/system/job', '', 'FieldTimeOutlined', '定时任务管理');
+
+select * from sys_menu where menu_name='定时任务';
+
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('添加定时任务', 3, 1, 1, 98, '', '/api/system/job/addJob', '', '添加定时任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('删除定时任务', 3, 1, 2, 98, '', '/api/system/job/deleteJob', '', '删除定时任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('更新定时任务', 3, 1, 3, 98, '', '/api/system/job/updateJob', '', '更新定时任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('暂停/恢复定时任务', 3, 1, 4, 98, '', '/api/system/job/updateJobStatus', '', '暂停/恢复定时任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('立即执行定时任务', 3, 1, 5, 98, '', '/api/system/job/runJob', '', '立即执行一次定时任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询定时任务详情', 3, 1, 6, 98, '', '/api/system/job/queryJobDetail', '', '查询定时任务详情');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询定时任务列表', 3, 1, 7, 98, '', '/api/system/job/queryJobList', '', '查询定时任务列表');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询已注册的调用目标', 3, 1, 8, 98, '', '/api/system/job/queryInvokeTargetList', '', '查询代码中已注册的任务');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('删除定时任务日志', 3, 1, 9, 98, '', '/api/system/jobLog/deleteJobLog', '', '删除定时任务日志');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('清空定时任务日志', 3, 1, 10, 98, '', '/api/system/jobLog/cleanJobLog', '', '清空定时任务日志');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询定时任务日志详情', 3, 1, 11, 98, '', '/api/system/jobLog/queryJobLogDetail', '', '查询定时任务日志详情');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询定时任务日志列表', 3, 1, 12, 98, '', '/api/system/jobLog/queryJobLogList', '', '查询定时任务日志列表');
+
+-- 配置按时间范围删除日志权限
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('按时间删除系统访问记录', 3, 1, 7, 73, '', '/api/system/loginLog/deleteLoginLogByTime', '', '按时间范围删除系统访问记录');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('按时间删除操作日志记录', 3, 1, 7, 80, '', '/api/system/operateLog/deleteOperateLogByTime', '', '按时间范围删除操作日志记录');
+
+-- 配置文件管理权限
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('文件管理', 2, 1, 11, 2, '/system/file', '', 'FileOutlined', '文件管理');
+
+select * from sys_menu where menu_name='文件管理';
+
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('删除文件', 3, 1, 1, 113, '', '/api/system/file/deleteFile', '', '删除文件');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询文件详情', 3, 1, 2, 113, '', '/api/system/file/queryFileDetail', '', '查询文件详情');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('查询文件列表', 3, 1, 3, 113, '', '/api/system/file/queryFileList', '', '查询文件列表');
+
+-- 配置日志游标分页查询权限
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('游标查询系统访问记录列表', 3, 1, 8, 73, '', '/api/system/loginLog/queryLoginLogCursorList', '', '游标分页查询系统访问记录列表');
+INSERT INTO sys_menu (menu_name, menu_type, status, sort, parent_id, menu_url, api_url, menu_icon, remark) VALUES ('游标查询操作日志记录列表', 3, 1, 8, 80, '', '/api/system/operateLog/queryOperateLogCursorList', '', '游标分页查询操作日志记录列表');
@@ -6,11 +6,18 @@ create table sys_notice
     notice_type    tinyint      default 1                 not null comment '公告类型（1:通知,2:公告）',
     notice_content varchar(255) default ''                not null comment '公告内容',
     status         tinyint      default 0                 not null comment '公告状态（0:关闭,1:正常 ）',
+    target_type    tinyint      default 0                 not null comment '发送范围（0:全部用户,1:指定部门,2:指定角色,3:指定用户）',
+    target_ids     varchar(500) default ''                not null comment '发送对象id(逗号分隔)',
+    publish_time   datetime                               null comment '定时发布时间',
+    expire_time    datetime                               null comment '过期时间(到期后自动关闭)',
+    scheduled      tinyint      default 0                 not null comment '是否等待定时发布(0:否,1:是)',
     remark         varchar(255) default ''                not null comment '备注',
     create_time    datetime     default CURRENT_TIMESTAMP not null comment '创建时间',
     update_time    datetime      null on update CURRENT_TIMESTAMP comment '修改时间'
 ) comment '通知公告表';
 
+create index idx_sys_notice_scheduled on sys_notice (scheduled, publish_time);
+
 
 INSERT INTO sys_notice (notice_title, notice_type, notice_content, status) VALUES ('测试通知1', 1, '这是一条测试通知内容', 1);
 INSERT INTO sys_notice (notice_title, notice_type, notice_content, status) VALUES ('测试公告2', 2, '这是一条测试公告内容', 1);
@@ -0,0 +1,14 @@
+drop table if exists sys_notice_read;
+create table sys_notice_read
+(
+    id        bigint auto_increment comment '主键'
+        primary key,
+    notice_id bigint                             not null comment '公告ID',
+    user_id   bigint                             not null comment '用户ID',
+    read_time datetime default CURRENT_TIMESTAMP not null comment '阅读时间',
+    constraint notice_user
+        unique (notice_id, user_id)
+) comment '通知公告已读记录';
+
+create index user_id_index
+    on sys_notice_read (user_id);
@@ -22,3 +22,4 @@ create table sys_operate_log
 
 ) comment = '操作日志记录';
 
+create index idx_sys_operate_log_time on sys_operate_log (operate_time, id);
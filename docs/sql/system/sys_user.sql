@@ -2,7 +2,7 @@ create table sys_user
 (
     id              bigint auto_increment comment '主键'
         primary key,
-    mobile          char(11)     default ''                not null comment '手机号码',
+    mobile          char(11)     null comment '手机号码(没有手机号码时为null)',
     user_name       varchar(50)                            not null comment '用户账号',
     nick_name       varchar(30)                            not null comment '用户昵称',
     user_type       varchar(2)   default '00'              not null comment '用户类型（00系统用户）',
@@ -16,6 +16,8 @@ create table sys_user
     login_browser   varchar(50)  default ''                not null comment '浏览器类型',
     login_os        varchar(50)  default ''                not null comment '操作系统',
     pwd_update_date datetime comment '密码最后更新时间',
+    pwd_must_change tinyint      default 0                 not null comment '是否需要修改密码(0:否,1:是,新增用户和重置密码后需要修改)',
+    locale          varchar(10)  default ''                not null comment '语言偏好(zh-CN,en-US,为空时根据请求头Accept-Language)',
     remark          varchar(255) null comment '备注',
     del_flag        tinyint      default 1                 not null comment '删除标志（0代表删除 1代表存在）',
     create_time     datetime     default CURRENT_TIMESTAMP not null comment '创建时间',
@@ -24,6 +26,11 @@ create table sys_user
         unique (mobile)
 ) comment '用户信息';
 
+-- 单点登录、LDAP自动创建的用户可能没有手机号码,保存为null(唯一索引允许多个null)
+-- 已有数据升级: alter table sys_user modify mobile char(11) null comment '手机号码(没有手机号码时为null)';
+--             update sys_user set mobile = null where mobile = '';
+--             alter table sys_user drop index idx_mobile, add constraint AK_phone unique (mobile);
+
 
 INSERT INTO sys_user (id, mobile, user_name, nick_name, avatar, email, password, status, remark) VALUES (1, '18613030111', 'admin','admin', 'https://gw.alipayobjects.com/zos/antfincdn/XAosXuNZyF/BiazfanxmamNRoxxVxka.png','xx@qq.com','123456', 1,  '超级管理员');
 INSERT INTO sys_user (id, mobile, user_name, nick_name, avatar, email, password, status, remark) VALUES (2, '18613030222', 'test', 'test', 'https://gw.alipayobjects.com/zos/antfincdn/XAosXuNZyF/BiazfanxmamNRoxxVxka.png','123@qq.com','123456', 1, '演示权限');
@@ -0,0 +1,14 @@
+drop table if exists sys_user_identity;
+create table sys_user_identity
+(
+    id          bigint auto_increment comment '主键'
+        primary key,
+    user_id     bigint                             not null comment '用户id',
+    provider    varchar(255)                       not null comment '身份提供方(OIDC的issuer,LDAP的服务器地址)',
+    subject     varchar(255)                       not null comment '用户在身份提供方的唯一标识(OIDC的sub,LDAP的DN)',
+    create_time datetime default CURRENT_TIMESTAMP not null comment '创建时间',
+    constraint uk_provider_subject
+        unique (provider, subject)
+) comment = '用户第三方身份关联表';
+
+create index idx_user_id on sys_user_identity (user_id);
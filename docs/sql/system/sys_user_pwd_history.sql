@@ -0,0 +1,11 @@
+drop table if exists sys_user_pwd_history;
+create table sys_user_pwd_history
+(
+    id          bigint auto_increment comment '主键'
+        primary key,
+    user_id     bigint                             not null comment '用户id',
+    password    char(64)                           not null comment '密码摘要(sha256,用户id作为盐)',
+    create_time datetime default CURRENT_TIMESTAMP not null comment '创建时间'
+) comment = '用户历史密码表';
+
+create index idx_user_id on sys_user_pwd_history (user_id);
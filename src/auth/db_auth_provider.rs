@@ -0,0 +1,40 @@
+use crate::auth::{AuthProvider, AuthResult, ACCOUNT_TYPE_EMAIL, ACCOUNT_TYPE_MOBILE};
+use crate::common::error::AppResult;
+use crate::model::system::sys_user_model::User;
+use crate::AppState;
+use async_trait::async_trait;
+use log::info;
+
+/*
+ *数据库密码认证(使用sys_user中的密码)
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+pub struct DbAuthProvider;
+
+#[async_trait]
+impl AuthProvider for DbAuthProvider {
+    fn kind(&self) -> &'static str {
+        "db"
+    }
+
+    fn local_password(&self) -> bool {
+        true
+    }
+
+    async fn authenticate(&self, state: &AppState, account: &str, account_type: &str, password: &str) -> AppResult<AuthResult> {
+        let rb = &state.batis;
+        let user_result = match account_type {
+            ACCOUNT_TYPE_MOBILE => User::select_by_mobile(rb, account).await?,
+            ACCOUNT_TYPE_EMAIL => User::select_by_email(rb, account).await?,
+            _ => User::select_by_user_name(rb, account).await?,
+        };
+        info!("query user by {}: {:?}", account_type, user_result);
+
+        Ok(match user_result {
+            None => AuthResult::UserNotFound,
+            Some(user) if user.password.ne(password) => AuthResult::BadPassword,
+            Some(user) => AuthResult::Success(Box::new(user)),
+        })
+    }
+}
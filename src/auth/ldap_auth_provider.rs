@@ -0,0 +1,449 @@
+use crate::auth::{create_external_user, grant_mapped_roles, AuthProvider, AuthResult, ExternalUser};
+use crate::common::error::{AppError, AppResult};
+use crate::model::system::sys_user_identity_model::UserIdentity;
+use crate::model::system::sys_user_model::User;
+use crate::sms::is_valid_mobile;
+use crate::AppState;
+use async_trait::async_trait;
+use ldap3::{dn_escape, ldap_escape, Ldap, LdapConnAsync, LdapConnSettings, Scope, SearchEntry};
+use log::{info, warn};
+use rbatis::rbdc::DateTime;
+use rbs::value;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+// 绑定失败时的结果码(invalidCredentials)
+const LDAP_INVALID_CREDENTIALS: u32 = 49;
+
+/*
+ *LDAP认证配置(支持OpenLDAP和Active Directory)
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+#[derive(Clone, Debug, Deserialize)]
+pub struct LdapConfig {
+    #[serde(default = "default_url")]
+    pub url: String, //服务器地址(ldap://或者ldaps://)
+    #[serde(default)]
+    pub starttls: bool, //是否使用STARTTLS
+    #[serde(default)]
+    pub no_tls_verify: bool, //是否不校验服务器证书(只用于测试环境)
+    #[serde(default = "default_timeout")]
+    pub timeout: u64, //连接和请求超时时间(秒)
+    #[serde(default)]
+    pub user_dn: String, //用户DN模板({username}替换为登录账号),为空时先搜索用户再绑定
+    #[serde(default)]
+    pub bind_dn: String, //搜索用户使用的账号(为空时匿名搜索)
+    #[serde(default)]
+    pub bind_password: String, //搜索用户使用的密码
+    #[serde(default)]
+    pub base_dn: String, //搜索用户的根节点
+    #[serde(default = "default_user_filter")]
+    pub user_filter: String, //搜索用户的条件({username}替换为登录账号)
+    #[serde(default = "default_username_attr")]
+    pub username_attr: String, //用户账号属性(AD为sAMAccountName)
+    #[serde(default = "default_nickname_attr")]
+    pub nickname_attr: String, //用户昵称属性
+    #[serde(default = "default_email_attr")]
+    pub email_attr: String, //邮箱属性
+    #[serde(default = "default_mobile_attr")]
+    pub mobile_attr: String, //手机号码属性
+    #[serde(default)]
+    pub group_base_dn: String, //搜索用户组的根节点(为空时使用用户的memberOf属性)
+    #[serde(default = "default_group_filter")]
+    pub group_filter: String, //搜索用户组的条件({dn}替换为用户DN)
+    #[serde(default = "default_group_attr")]
+    pub group_attr: String, //用户组名称属性
+    #[serde(default = "default_auto_create")]
+    pub auto_create: bool, //系统用户不存在时是否自动创建
+    #[serde(default = "default_dept_id")]
+    pub dept_id: i64, //自动创建用户的部门ID
+    #[serde(default)]
+    pub role_mapping: HashMap<String, String>, //用户组与角色权限字符(role_key)的对应关系
+}
+
+impl Default for LdapConfig {
+    fn default() -> Self {
+        LdapConfig {
+            url: default_url(),
+            starttls: false,
+            no_tls_verify: false,
+            timeout: default_timeout(),
+            user_dn: String::new(),
+            bind_dn: String::new(),
+            bind_password: String::new(),
+            base_dn: String::new(),
+            user_filter: default_user_filter(),
+            username_attr: default_username_attr(),
+            nickname_attr: default_nickname_attr(),
+            email_attr: default_email_attr(),
+            mobile_attr: default_mobile_attr(),
+            group_base_dn: String::new(),
+            group_filter: default_group_filter(),
+            group_attr: default_group_attr(),
+            auto_create: default_auto_create(),
+            dept_id: default_dept_id(),
+            role_mapping: HashMap::new(),
+        }
+    }
+}
+
+fn default_url() -> String {
+    "ldap://127.0.0.1:389".to_string()
+}
+
+fn default_timeout() -> u64 {
+    5
+}
+
+fn default_user_filter() -> String {
+    "(uid={username})".to_string()
+}
+
+fn default_username_attr() -> String {
+    "uid".to_string()
+}
+
+fn default_nickname_attr() -> String {
+    "cn".to_string()
+}
+
+fn default_email_attr() -> String {
+    "mail".to_string()
+}
+
+fn default_mobile_attr() -> String {
+    "mobile".to_string()
+}
+
+fn default_group_filter() -> String {
+    "(member={dn})".to_string()
+}
+
+fn default_group_attr() -> String {
+    "cn".to_string()
+}
+
+fn default_auto_create() -> bool {
+    true
+}
+
+fn default_dept_id() -> i64 {
+    1
+}
+
+/*
+ *LDAP中查询到的用户
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+#[derive(Clone, Debug, Default)]
+pub struct LdapUser {
+    pub dn: String,          //用户DN
+    pub user: ExternalUser,  //用户信息
+    pub groups: Vec<String>, //用户组
+}
+
+/*
+ *LDAP认证结果
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+#[derive(Debug)]
+pub enum LdapLookup {
+    Found(LdapUser), //绑定成功
+    UserNotFound,    //用户不存在
+    BadPassword,     //密码不正确
+}
+
+/*
+ *LDAP认证(使用用户DN模板直接绑定,或者先搜索用户再绑定),认证成功后同步用户信息到sys_user
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+pub struct LdapAuthProvider {
+    config: LdapConfig,
+}
+
+impl LdapAuthProvider {
+    pub fn new(config: LdapConfig) -> Self {
+        LdapAuthProvider { config }
+    }
+
+    /*
+     *在LDAP中认证账号密码并查询用户信息和用户组
+     *author：刘飞华
+     *date：2026/10/19 00:35:18
+     */
+    pub async fn lookup(&self, account: &str, password: &str) -> AppResult<LdapLookup> {
+        // 密码为空时LDAP会当作匿名绑定并返回成功
+        if password.is_empty() {
+            return Ok(LdapLookup::BadPassword);
+        }
+
+        let timeout = Duration::from_secs(self.config.timeout);
+        let settings = LdapConnSettings::new()
+            .set_conn_timeout(timeout)
+            .set_starttls(self.config.starttls)
+            .set_no_tls_verify(self.config.no_tls_verify);
+        let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &self.config.url).await.map_err(ldap_error)?;
+        ldap3::drive!(conn);
+
+        let result = self.lookup_entry(&mut ldap, account, password).await;
+        let _ = ldap.unbind().await;
+        result
+    }
+
+    async fn lookup_entry(&self, ldap: &mut Ldap, account: &str, password: &str) -> AppResult<LdapLookup> {
+        let timeout = Duration::from_secs(self.config.timeout);
+        let attrs = vec![
+            self.config.username_attr.as_str(),
+            self.config.nickname_attr.as_str(),
+            self.config.email_attr.as_str(),
+            self.config.mobile_attr.as_str(),
+            "memberOf",
+        ];
+
+        let entry = if !self.config.user_dn.is_empty() {
+            let dn = self.user_dn(account);
+            let bind = ldap.with_timeout(timeout).simple_bind(&dn, password).await.map_err(ldap_error)?;
+            if bind.rc == LDAP_INVALID_CREDENTIALS {
+                return Ok(LdapLookup::BadPassword);
+            }
+            bind.success().map_err(ldap_error)?;
+
+            let (entries, _) = ldap
+                .with_timeout(timeout)
+                .search(&dn, Scope::Base, "(objectClass=*)", attrs)
+                .await
+                .map_err(ldap_error)?
+                .success()
+                .map_err(ldap_error)?;
+            match entries.into_iter().next() {
+                None => return Ok(LdapLookup::UserNotFound),
+                Some(x) => SearchEntry::construct(x),
+            }
+        } else {
+            if !self.config.bind_dn.is_empty() {
+                let bind = ldap.with_timeout(timeout).simple_bind(&self.config.bind_dn, &self.config.bind_password).await.map_err(ldap_error)?;
+                bind.success().map_err(ldap_error)?;
+            }
+
+            let (entries, _) = ldap
+                .with_timeout(timeout)
+                .search(&self.config.base_dn, Scope::Subtree, &self.user_filter(account), attrs)
+                .await
+                .map_err(ldap_error)?
+                .success()
+                .map_err(ldap_error)?;
+            if entries.len() != 1 {
+                info!("ldap search user: {}, found: {}", account, entries.len());
+                return Ok(LdapLookup::UserNotFound);
+            }
+            let entry = SearchEntry::construct(entries.into_iter().next().unwrap());
+
+            let bind = ldap.with_timeout(timeout).simple_bind(&entry.dn, password).await.map_err(ldap_error)?;
+            if bind.rc == LDAP_INVALID_CREDENTIALS {
+                return Ok(LdapLookup::BadPassword);
+            }
+            bind.success().map_err(ldap_error)?;
+            entry
+        };
+
+        let groups = if self.config.group_base_dn.is_empty() {
+            entry.attrs.get("memberOf").map(|x| x.iter().map(|dn| group_name(dn)).collect()).unwrap_or_default()
+        } else {
+            let filter = self.config.group_filter.replace("{dn}", &ldap_escape(entry.dn.as_str()));
+            let (entries, _) = ldap
+                .with_timeout(timeout)
+                .search(&self.config.group_base_dn, Scope::Subtree, &filter, vec![self.config.group_attr.as_str()])
+                .await
+                .map_err(ldap_error)?
+                .success()
+                .map_err(ldap_error)?;
+            entries.into_iter().filter_map(|x| first_attr(&SearchEntry::construct(x), &self.config.group_attr)).collect()
+        };
+
+        let user_name = first_attr(&entry, &self.config.username_attr).unwrap_or_else(|| account.to_string());
+        Ok(LdapLookup::Found(LdapUser {
+            user: ExternalUser {
+                user_name,
+                nick_name: first_attr(&entry, &self.config.nickname_attr).unwrap_or_default(),
+                email: first_attr(&entry, &self.config.email_attr).unwrap_or_default(),
+                mobile: first_attr(&entry, &self.config.mobile_attr).unwrap_or_default(),
+            },
+            dn: entry.dn,
+            groups,
+        }))
+    }
+
+    // 用户DN(账号按DN规则转义)
+    fn user_dn(&self, account: &str) -> String {
+        self.config.user_dn.replace("{username}", &dn_escape(account))
+    }
+
+    // 搜索用户的条件(账号按过滤器规则转义)
+    fn user_filter(&self, account: &str) -> String {
+        self.config.user_filter.replace("{username}", &ldap_escape(account))
+    }
+
+    /*
+     *同步LDAP用户信息到sys_user(通过关联的LDAP身份查找系统用户,没有关联时自动创建并关联)
+     *不会自动关联用户账号相同的系统用户(本地账号可能属于其他人)
+     *author：刘飞华
+     *date：2026/10/19 00:35:18
+     */
+    async fn sync_user(&self, state: &AppState, ldap_user: &LdapUser) -> AppResult<Option<User>> {
+        let rb = &state.batis;
+        let external = &ldap_user.user;
+        let provider = self.config.url.trim_end_matches('/');
+        let subject = ldap_user.dn.to_lowercase();
+
+        let linked = match UserIdentity::select_by_subject(rb, provider, &subject).await? {
+            Some(x) => User::select_by_id(rb, x.user_id).await?,
+            None => None,
+        };
+        let Some(mut user) = linked else {
+            if User::select_by_user_name(rb, &external.user_name).await?.is_some() {
+                warn!("ldap user: {} not linked, local user with the same user_name exists", ldap_user.dn);
+                return Ok(None);
+            }
+            if !self.config.auto_create {
+                return Ok(None);
+            }
+            let user = create_external_user(rb, external, self.config.dept_id, "LDAP认证自动创建").await?;
+            let identity = UserIdentity {
+                id: None,
+                user_id: user.id.unwrap_or_default(),
+                provider: provider.to_string(),
+                subject,
+                create_time: Some(DateTime::now()),
+            };
+            UserIdentity::insert(rb, &identity).await?;
+            info!("link ldap user dn: {}, user_id: {:?}", ldap_user.dn, user.id);
+            return Ok(Some(user));
+        };
+
+        let mut changed = false;
+        if !external.nick_name.is_empty() && external.nick_name != user.nick_name {
+            user.nick_name = external.nick_name.chars().take(30).collect();
+            changed = true;
+        }
+        if !external.email.is_empty() && external.email != user.email && User::select_by_email(rb, &external.email).await?.is_none() {
+            user.email = external.email.clone();
+            changed = true;
+        }
+        let mobile = external.mobile.trim_start_matches("+86");
+        if is_valid_mobile(mobile) && mobile != user.mobile && User::select_by_mobile(rb, mobile).await?.is_none() {
+            user.mobile = mobile.to_string();
+            changed = true;
+        }
+        if changed {
+            info!("sync ldap user: {}, dn: {}", user.user_name, ldap_user.dn);
+            User::update_by_map(rb, &user, value! {"id": &user.id}).await?;
+        }
+        Ok(Some(user))
+    }
+}
+
+#[async_trait]
+impl AuthProvider for LdapAuthProvider {
+    fn kind(&self) -> &'static str {
+        "ldap"
+    }
+
+    fn local_password(&self) -> bool {
+        false
+    }
+
+    async fn authenticate(&self, state: &AppState, account: &str, _account_type: &str, password: &str) -> AppResult<AuthResult> {
+        let ldap_user = match self.lookup(account, password).await? {
+            LdapLookup::UserNotFound => return Ok(AuthResult::UserNotFound),
+            LdapLookup::BadPassword => return Ok(AuthResult::BadPassword),
+            LdapLookup::Found(x) => x,
+        };
+        info!("ldap user: {:?}", ldap_user);
+
+        let Some(user) = self.sync_user(state, &ldap_user).await? else {
+            return Ok(AuthResult::UserNotFound);
+        };
+        grant_mapped_roles(&state.batis, user.id.unwrap_or_default(), &ldap_user.groups, &self.config.role_mapping).await?;
+        Ok(AuthResult::Success(Box::new(user)))
+    }
+}
+
+fn first_attr(entry: &SearchEntry, name: &str) -> Option<String> {
+    entry.attrs.get(name).and_then(|x| x.first()).filter(|x| !x.is_empty()).cloned()
+}
+
+// 用户组DN的第一个RDN的值(cn=admins,ou=groups,dc=example,dc=com => admins)
+fn group_name(dn: &str) -> String {
+    let rdn = dn.split(',').next().unwrap_or_default();
+    rdn.split_once('=').map(|x| x.1).unwrap_or(rdn).trim().to_string()
+}
+
+fn ldap_error(e: ldap3::LdapError) -> AppError {
+    AppError::LdapError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth::ldap_auth_provider::*;
+
+    #[test]
+    fn test_escape() {
+        let provider = LdapAuthProvider::new(LdapConfig {
+            user_dn: "uid={username},ou=people,dc=example,dc=com".to_string(),
+            user_filter: "(&(objectClass=inetOrgPerson)(|(uid={username})(mail={username})))".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(provider.user_dn("zhangsan"), "uid=zhangsan,ou=people,dc=example,dc=com");
+        assert_eq!(provider.user_dn("a,b=c"), "uid=a\\2cb\\3dc,ou=people,dc=example,dc=com");
+        assert_eq!(
+            provider.user_filter("*)(uid=*"),
+            "(&(objectClass=inetOrgPerson)(|(uid=\\2a\\29\\28uid=\\2a)(mail=\\2a\\29\\28uid=\\2a)))"
+        );
+    }
+
+    #[test]
+    fn test_group_name() {
+        assert_eq!(group_name("cn=admins,ou=groups,dc=example,dc=com"), "admins");
+        assert_eq!(group_name("CN=Domain Users,CN=Users,DC=corp,DC=local"), "Domain Users");
+    }
+
+    // 需要本地OpenLDAP容器,导入docs/test/ldap/users.ldif后执行:
+    // LDAP_TEST_URL=ldap://127.0.0.1:389 cargo test ldap -- --ignored
+    #[tokio::test]
+    #[ignore]
+    async fn test_ldap_lookup() {
+        let url = std::env::var("LDAP_TEST_URL").unwrap_or_else(|_| default_url());
+        let search = LdapAuthProvider::new(LdapConfig {
+            url: url.clone(),
+            bind_dn: "cn=admin,dc=example,dc=com".to_string(),
+            bind_password: "admin".to_string(),
+            base_dn: "ou=people,dc=example,dc=com".to_string(),
+            group_base_dn: "ou=groups,dc=example,dc=com".to_string(),
+            ..Default::default()
+        });
+        let LdapLookup::Found(user) = search.lookup("zhangsan", "Zhangsan@2026").await.unwrap() else {
+            panic!("ldap user not found");
+        };
+        assert_eq!(user.user.user_name, "zhangsan");
+        assert_eq!(user.user.email, "zhangsan@example.com");
+        assert_eq!(user.user.mobile, "18613030333");
+        assert_eq!(user.groups, vec!["admins"]);
+
+        assert!(matches!(search.lookup("zhangsan", "wrong").await.unwrap(), LdapLookup::BadPassword));
+        assert!(matches!(search.lookup("zhangsan", "").await.unwrap(), LdapLookup::BadPassword));
+        assert!(matches!(search.lookup("nobody", "Zhangsan@2026").await.unwrap(), LdapLookup::UserNotFound));
+
+        let template = LdapAuthProvider::new(LdapConfig {
+            url,
+            user_dn: "uid={username},ou=people,dc=example,dc=com".to_string(),
+            ..Default::default()
+        });
+        assert!(matches!(template.lookup("zhangsan", "Zhangsan@2026").await.unwrap(), LdapLookup::Found(_)));
+        assert!(matches!(template.lookup("zhangsan", "wrong").await.unwrap(), LdapLookup::BadPassword));
+    }
+}
@@ -0,0 +1,246 @@
+pub mod db_auth_provider;
+pub mod ldap_auth_provider;
+
+use crate::auth::db_auth_provider::DbAuthProvider;
+use crate::auth::ldap_auth_provider::{LdapAuthProvider, LdapConfig};
+use crate::common::error::AppResult;
+use crate::model::system::sys_role_model::Role;
+use crate::model::system::sys_user_model::User;
+use crate::model::system::sys_user_role_model::UserRole;
+use crate::sms::is_valid_mobile;
+use crate::AppState;
+use async_trait::async_trait;
+use log::info;
+use rbatis::rbatis_codegen::ops::AsProxy;
+use rbatis::rbdc::DateTime;
+use rbatis::RBatis;
+use rbs::value;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+
+// 登录账号类型(手机、用户账号、邮箱)
+pub const ACCOUNT_TYPE_MOBILE: &str = "mobile";
+pub const ACCOUNT_TYPE_USERNAME: &str = "username";
+pub const ACCOUNT_TYPE_EMAIL: &str = "email";
+
+/*
+ *账号密码认证结果
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+#[derive(Debug)]
+pub enum AuthResult {
+    Success(Box<User>), //认证成功(返回对应的系统用户)
+    UserNotFound,       //用户不存在
+    BadPassword,        //密码不正确
+}
+
+/*
+ *账号密码认证方式(数据库密码、LDAP)
+ *登录时按配置的顺序依次认证,第一个认证成功的结果生效
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+#[async_trait]
+pub trait AuthProvider: Send + Sync {
+    // 认证方式(db,ldap)
+    fn kind(&self) -> &'static str;
+
+    // 是否使用系统用户的密码(使用时需要检查密码过期和首次登录修改密码)
+    fn local_password(&self) -> bool;
+
+    // 认证账号密码
+    async fn authenticate(&self, state: &AppState, account: &str, account_type: &str, password: &str) -> AppResult<AuthResult>;
+}
+
+/*
+ *认证方式配置
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+#[derive(Clone, Debug, Deserialize)]
+pub struct AuthConfig {
+    #[serde(default = "default_providers")]
+    pub providers: Vec<String>, //认证方式(db:数据库密码,ldap:LDAP/AD),按顺序认证
+    #[serde(default)]
+    pub ldap: LdapConfig, //LDAP配置
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        AuthConfig {
+            providers: default_providers(),
+            ldap: LdapConfig::default(),
+        }
+    }
+}
+
+fn default_providers() -> Vec<String> {
+    vec!["db".to_string()]
+}
+
+/*
+ *根据配置创建认证方式
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+pub fn build_auth_providers(config: &AuthConfig) -> Vec<Box<dyn AuthProvider>> {
+    let mut providers: Vec<Box<dyn AuthProvider>> = Vec::new();
+    for kind in config.providers.iter() {
+        match kind.as_str() {
+            "db" => providers.push(Box::new(DbAuthProvider)),
+            "ldap" => providers.push(Box::new(LdapAuthProvider::new(config.ldap.clone()))),
+            x => log::error!("unknown auth provider: {}", x),
+        }
+    }
+    if providers.is_empty() {
+        log::error!("no auth provider configured, use db auth provider");
+        providers.push(Box::new(DbAuthProvider));
+    }
+    providers
+}
+
+/*
+ *识别登录账号类型(包含@为邮箱,符合手机号码格式为手机,否则为用户账号)
+ *author：刘飞华
+ *date：2026/10/18 23:30:12
+ */
+pub fn detect_account_type(account: &str) -> &'static str {
+    if account.contains('@') {
+        ACCOUNT_TYPE_EMAIL
+    } else if is_valid_mobile(account) {
+        ACCOUNT_TYPE_MOBILE
+    } else {
+        ACCOUNT_TYPE_USERNAME
+    }
+}
+
+/*
+ *外部用户信息(单点登录、LDAP)
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+#[derive(Clone, Debug, Default)]
+pub struct ExternalUser {
+    pub user_name: String, //用户账号
+    pub nick_name: String, //用户昵称
+    pub email: String,     //邮箱
+    pub mobile: String,    //手机号码
+}
+
+/*
+ *创建外部用户对应的系统用户(使用随机密码,只能通过外部认证或者重置密码后登录)
+ *用户账号已存在时增加随机后缀,手机号码和邮箱格式不正确或者已被使用时不保存
+ *author：刘飞华
+ *date：2026/10/19 00:35:18
+ */
+pub async fn create_external_user(rb: &RBatis, external: &ExternalUser, dept_id: i64, remark: &str) -> AppResult<User> {
+    let mut user_name = external.user_name.chars().take(40).collect::<String>();
+    if User::select_by_user_name(rb, &user_name).await?.is_some() {
+        user_name = format!("{}_{:04x}", user_name, rand::random::<u16>());
+    }
+
+    let mut mobile = external.mobile.trim_start_matches("+86").to_string();
+    if !is_valid_mobile(&mobile) || User::select_by_mobile(rb, &mobile).await?.is_some() {
+        mobile = String::new();
+    }
+
+    let mut email = external.email.clone();
+    if !email.is_empty() && User::select_by_email(rb, &email).await?.is_some() {
+        email = String::new();
+    }
+
+    let nick_name = if external.nick_name.is_empty() { user_name.clone() } else { external.nick_name.clone() };
+    let mut user = User {
+        id: None,
+        mobile,
+        user_name,
+        nick_name: nick_name.chars().take(30).collect(),
+        user_type: Some("01".to_string()),
+        email,
+        avatar: None,
+        password: format!("{:032x}", rand::random::<u128>()),
+        status: 1,
+        dept_id,
+        login_ip: "".to_string(),
+        login_date: None,
+        login_browser: "".to_string(),
+        login_os: "".to_string(),
+        pwd_update_date: Some(DateTime::now()),
+        pwd_must_change: 0,
+        locale: "".to_string(),
+        remark: Some(remark.to_string()),
+        del_flag: 1,
+        create_time: Some(DateTime::now()),
+        update_time: None,
+    };
+
+    let result = User::insert(rb, &user).await?;
+    user.id = Some(result.last_insert_id.i64());
+    info!("create external user: {:?}", user);
+    Ok(user)
+}
+
+/*
+ *根据外部用户组分配角色(只增加配置了对应关系的角色,不删除已有角色)
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+pub async fn grant_mapped_roles(rb: &RBatis, user_id: i64, groups: &[String], role_mapping: &HashMap<String, String>) -> AppResult<()> {
+    if role_mapping.is_empty() {
+        return Ok(());
+    }
+
+    let role_ids = UserRole::select_by_map(rb, value! {"user_id": user_id}).await?.iter().map(|x| x.role_id).collect::<HashSet<i64>>();
+    let mut granted = false;
+    for role_key in groups.iter().filter_map(|x| role_mapping.get(x)) {
+        let Some(role) = Role::select_by_role_key(rb, role_key).await? else {
+            log::error!("role mapping error, role_key not found: {}", role_key);
+            continue;
+        };
+        let role_id = role.id.unwrap_or_default();
+        if role_ids.contains(&role_id) {
+            continue;
+        }
+        let user_role = UserRole {
+            id: None,
+            user_id,
+            role_id,
+            create_time: Some(DateTime::now()),
+        };
+        UserRole::insert(rb, &user_role).await?;
+        granted = true;
+    }
+
+    if granted {
+        info!("grant mapped roles user_id: {}, groups: {:?}", user_id, groups);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auth::*;
+
+    #[test]
+    fn test_detect_account_type() {
+        assert_eq!(detect_account_type("18613030111"), ACCOUNT_TYPE_MOBILE);
+        assert_eq!(detect_account_type("admin@qq.com"), ACCOUNT_TYPE_EMAIL);
+        assert_eq!(detect_account_type("admin"), ACCOUNT_TYPE_USERNAME);
+        assert_eq!(detect_account_type("1861303"), ACCOUNT_TYPE_USERNAME);
+    }
+
+    #[test]
+    fn test_build_auth_providers() {
+        let providers = build_auth_providers(&AuthConfig::default());
+        assert_eq!(providers.iter().map(|x| x.kind()).collect::<Vec<&str>>(), vec!["db"]);
+
+        let config = AuthConfig {
+            providers: vec!["ldap".to_string(), "db".to_string(), "unknown".to_string()],
+            ..Default::default()
+        };
+        let providers = build_auth_providers(&config);
+        assert_eq!(providers.iter().map(|x| x.kind()).collect::<Vec<&str>>(), vec!["ldap", "db"]);
+        assert!(!providers[0].local_password());
+    }
+}
@@ -1,9 +1,33 @@
-use crate::common::result::BaseResponse;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::ErrorResponse;
+use crate::i18n::{contains_key, tr};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use redis::RedisError;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
 use thiserror::Error;
+use utoipa::ToSchema;
+use validator::{ValidationError, ValidationErrorsKind};
+
+// 兼容旧客户端: 开启后所有错误都返回HTTP 200,通过响应体的code判断是否成功
+static LEGACY_STATUS: AtomicBool = AtomicBool::new(false);
+
+pub fn set_legacy_status(legacy: bool) {
+    LEGACY_STATUS.store(legacy, Ordering::Relaxed);
+}
+
+pub fn is_legacy_status() -> bool {
+    LEGACY_STATUS.load(Ordering::Relaxed)
+}
+
+// 字段验证错误
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
 
 #[derive(Error, Debug)]
 pub enum AppError {
@@ -24,59 +48,239 @@ pub enum AppError {
     #[error("redis错误: {0}")]
     RedisError(#[from] RedisError),
 
-    #[error("业务异常: {0}")]
-    BusinessError(&'static str),
+    #[error("业务异常: {}", .0.msg())]
+    BusinessError(ErrorCode),
 
     #[error("验证异常: {0}")]
     ValidationError(String),
+
+    #[error("验证异常: {}", field_errors_message(.0))]
+    FieldValidationError(Vec<FieldError>),
+
+    #[error("{0}")]
+    Unauthorized(String),
+
+    #[error("{0}")]
+    Forbidden(String),
+
+    #[error("文件读写错误: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("存储错误: {0}")]
+    StorageError(String),
+
+    #[error("邮件发送失败: {0}")]
+    MailError(String),
+
+    #[error("短信发送失败: {0}")]
+    SmsError(String),
+
+    #[error("单点登录失败: {0}")]
+    OidcError(String),
+
+    #[error("LDAP错误: {0}")]
+    LdapError(String),
 }
 pub type AppResult<T> = Result<T, AppError>;
 
-#[async_trait]
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let response = BaseResponse {
-            msg: self.to_string(),
-            code: 1,
-            data: Some("None".to_string()),
-        };
+        self.build_response(is_legacy_status())
+    }
+}
 
+impl AppError {
+    // 错误码
+    pub fn error_code(&self) -> ErrorCode {
         match self {
-            AppError::DbError(_) | AppError::RedisError(_) => {
-                (StatusCode::INTERNAL_SERVER_ERROR, Json(response)).into_response()
-            },
-            AppError::DiskCacheRead { source:_ } => {
-                (StatusCode::INTERNAL_SERVER_ERROR, Json(response)).into_response()
-            },
-            AppError::JwtTokenError (_msg) => {
-                (StatusCode::UNAUTHORIZED, Json(response)).into_response()
-            },
-            AppError::BusinessError(_msg) => {
-                (StatusCode::INTERNAL_SERVER_ERROR, Json(response)).into_response()
-            },
-            AppError::ValidationError(_msg) => {
-                (StatusCode::INTERNAL_SERVER_ERROR, Json(response)).into_response()
-            },
+            AppError::BusinessError(code) => *code,
+            AppError::DbError(_) => ErrorCode::DbError,
+            AppError::RedisError(_) => ErrorCode::RedisError,
+            AppError::DiskCacheRead { source: _ } | AppError::IoError(_) => ErrorCode::IoError,
+            AppError::StorageError(_) => ErrorCode::StorageError,
+            AppError::MailError(_) => ErrorCode::MailError,
+            AppError::SmsError(_) => ErrorCode::SmsError,
+            AppError::LdapError(_) => ErrorCode::LdapError,
+            AppError::OidcError(_) => ErrorCode::OidcError,
+            AppError::JwtTokenError(_) | AppError::Unauthorized(_) => ErrorCode::Unauthorized,
+            AppError::Forbidden(_) => ErrorCode::Forbidden,
+            AppError::ValidationError(_) | AppError::FieldValidationError(_) => ErrorCode::ValidationFailed,
         }
     }
-}
 
-
-impl AppError {
-    pub fn build_validation_error_message(e: &validator::ValidationErrors) -> String {
-        e.field_errors().iter().map(|(field, errors)| {
-            let messages: Vec<String> = errors.iter().map(|error| {
-                if let Some(message) = &error.message {
-                    message.to_string()
+    // 响应体的code: 未登录401,没有权限403(兼容模式下也是401),其他错误为1
+    fn response_code(&self, legacy: bool) -> i32 {
+        match self {
+            AppError::Unauthorized(_) => 401,
+            AppError::Forbidden(_) => {
+                if legacy {
+                    401
                 } else {
-                    format!("字段 '{}' 验证失败", field)
+                    403
                 }
-            }).collect();
-            messages.join(", ")
-        }).collect::<Vec<String>>().join("; ")
+            }
+            _ => 1,
+        }
+    }
+
+    // 当前语言的错误信息
+    pub fn message(&self) -> String {
+        let (key, msg) = match self {
+            AppError::BusinessError(code) => ("error.business", code.msg()),
+            AppError::ValidationError(msg) => ("error.validation", msg.clone()),
+            AppError::FieldValidationError(errors) => ("error.validation", field_errors_message(errors)),
+            AppError::Unauthorized(msg) | AppError::Forbidden(msg) => return msg.clone(),
+            AppError::JwtTokenError(msg) => ("error.jwt", msg.clone()),
+            AppError::DbError(e) => ("error.db", e.to_string()),
+            AppError::RedisError(e) => ("error.redis", e.to_string()),
+            AppError::DiskCacheRead { source } => ("error.io", source.to_string()),
+            AppError::IoError(e) => ("error.io", e.to_string()),
+            AppError::StorageError(msg) => ("error.storage", msg.clone()),
+            AppError::MailError(msg) => ("error.mail", msg.clone()),
+            AppError::SmsError(msg) => ("error.sms", msg.clone()),
+            AppError::OidcError(msg) => ("error.oidc", msg.clone()),
+            AppError::LdapError(msg) => ("error.ldap", msg.clone()),
+        };
+        tr(key, &[("msg", &msg)])
+    }
+
+    // 构建错误响应(兼容模式下HTTP状态码固定为200,data为"None")
+    pub fn build_response(self, legacy: bool) -> Response {
+        let error_code = self.error_code();
+        let status = if legacy { StatusCode::OK } else { error_code.status() };
+        let response = ErrorResponse {
+            code: self.response_code(legacy),
+            msg: self.message(),
+            error_code: error_code.code(),
+            errors: match self {
+                AppError::FieldValidationError(errors) => errors,
+                _ => Vec::new(),
+            },
+            data: if legacy { Some("None".to_string()) } else { None },
+        };
+        (status, Json(response)).into_response()
+    }
+
+    pub fn build_validation_error_message(e: &validator::ValidationErrors) -> String {
+        field_errors_message(&Self::field_errors(e))
+    }
+
+    // 字段验证错误(提示信息为语言包中的key时翻译,没有提示信息时按验证规则翻译)
+    // 字段名与请求参数一致(驼峰),多个字段之间的验证错误使用参数中的field
+    pub fn field_errors(e: &validator::ValidationErrors) -> Vec<FieldError> {
+        e.errors()
+            .iter()
+            .flat_map(|(field, kind)| match kind {
+                ValidationErrorsKind::Field(errors) => errors.iter().map(|error| field_error(field, error)).collect(),
+                // 嵌套参数(如分页参数)平铺在请求参数中,字段名不加前缀
+                ValidationErrorsKind::Struct(errors) => Self::field_errors(errors),
+                ValidationErrorsKind::List(list) => list.values().flat_map(|x| Self::field_errors(x)).collect(),
+            })
+            .collect()
     }
 
     pub fn validation_error(e: &validator::ValidationErrors) -> AppError {
-        AppError::ValidationError(Self::build_validation_error_message(e))
+        AppError::FieldValidationError(Self::field_errors(e))
+    }
+}
+
+fn field_error(field: &str, error: &ValidationError) -> FieldError {
+    let field = match error.params.get("field").and_then(|x| x.as_str()) {
+        Some(x) if field == "__all__" => x.to_string(),
+        _ => to_camel_case(field),
+    };
+    let params = error
+        .params
+        .iter()
+        .filter(|(k, _)| *k != "field" && *k != "value")
+        .map(|(k, v)| (k.to_string(), v.as_str().map(|x| x.to_string()).unwrap_or_else(|| v.to_string())))
+        .collect::<Vec<(String, String)>>();
+    let mut args = params.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect::<Vec<(&str, &str)>>();
+    args.push(("field", &field));
+    let message = match &error.message {
+        Some(message) if contains_key(message) => tr(message, &args),
+        Some(message) => message.to_string(),
+        None => {
+            let key = format!("validation.{}", error.code);
+            tr(if contains_key(&key) { &key } else { "validation.invalid" }, &args)
+        }
+    };
+    FieldError { field, message }
+}
+
+// 字段名转为驼峰(page_no -> pageNo)
+fn to_camel_case(field: &str) -> String {
+    let mut upper = false;
+    let mut result = String::with_capacity(field.len());
+    for c in field.chars() {
+        if c == '_' {
+            upper = !result.is_empty();
+        } else if upper {
+            result.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn field_errors_message(errors: &[FieldError]) -> String {
+    errors.iter().map(|x| x.message.as_str()).collect::<Vec<&str>>().join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error::*;
+    use crate::i18n::{scope, Locale};
+
+    async fn response_json(response: Response) -> (StatusCode, serde_json::Value) {
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), 4096).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_build_response() {
+        let (status, json) = response_json(AppError::BusinessError(ErrorCode::UserMobileExists).build_response(false)).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(json["code"], 1);
+        assert_eq!(json["errorCode"], "USER_MOBILE_EXISTS");
+        assert_eq!(json["msg"], "业务异常: 手机号码已存在");
+        assert!(json["data"].is_null());
+
+        let (status, json) = response_json(AppError::BusinessError(ErrorCode::UserMobileExists).build_response(true)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["code"], 1);
+        assert_eq!(json["data"], "None");
+
+        let (status, json) = response_json(AppError::Forbidden("用户还没有授权url:/system/user/addUser".to_string()).build_response(false)).await;
+        assert_eq!((status, json["code"].as_i64()), (StatusCode::FORBIDDEN, Some(403)));
+        let (status, json) = response_json(AppError::Forbidden("用户还没有授权url:/system/user/addUser".to_string()).build_response(true)).await;
+        assert_eq!((status, json["code"].as_i64()), (StatusCode::OK, Some(401)));
+
+        let errors = vec![FieldError {
+            field: "mobile".to_string(),
+            message: "手机号码格式不正确".to_string(),
+        }];
+        let (status, json) = response_json(AppError::FieldValidationError(errors).build_response(false)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json["errorCode"], "VALIDATION_FAILED");
+        assert_eq!(json["errors"][0]["field"], "mobile");
+        assert_eq!(json["msg"], "验证异常: 手机号码格式不正确");
+    }
+
+    #[tokio::test]
+    async fn test_build_response_locale() {
+        let (_, json) = scope(Locale::EnUs, async { response_json(AppError::BusinessError(ErrorCode::UserMobileExists).build_response(false)).await }).await.0;
+        assert_eq!(json["msg"], "Business error: Mobile number already exists");
+
+        let mut errors = validator::ValidationErrors::new();
+        errors.add("mobile", validator::ValidationError::new("regex"));
+        errors.add("nickName", validator::ValidationError::new("length").with_message("NICK_NAME_REQUIRED".into()));
+        let (field_errors, _) = scope(Locale::EnUs, async { AppError::field_errors(&errors) }).await;
+        let mut messages = field_errors.iter().map(|x| x.message.as_str()).collect::<Vec<&str>>();
+        messages.sort();
+        assert_eq!(messages, vec!["Field 'mobile' has an invalid format", "Nickname is required"]);
     }
 }
@@ -0,0 +1,187 @@
+use crate::i18n::t;
+use axum::http::StatusCode;
+
+/*
+ *定义错误码目录: 错误码名称 => (HTTP状态码, 错误码)
+ *错误码发布后不能修改,客户端可以根据错误码处理对应的业务异常,提示信息在语言包(i18n/locales)中以错误码为key
+ *author：刘飞华
+ *date：2026/10/19 02:05:16
+ */
+macro_rules! error_codes {
+    ($($name:ident => ($status:ident, $code:literal)),* $(,)?) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum ErrorCode {
+            $($name),*
+        }
+
+        impl ErrorCode {
+            // 全部错误码
+            pub const ALL: &'static [ErrorCode] = &[$(ErrorCode::$name),*];
+
+            // 错误码(大写字母和下划线)
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $(ErrorCode::$name => $code),*
+                }
+            }
+
+            // HTTP状态码
+            pub fn status(&self) -> StatusCode {
+                match self {
+                    $(ErrorCode::$name => StatusCode::$status),*
+                }
+            }
+        }
+    };
+}
+
+impl ErrorCode {
+    // 当前语言的提示信息
+    pub fn msg(&self) -> String {
+        t(self.code())
+    }
+}
+
+error_codes! {
+    // 通用错误
+    InternalError => (INTERNAL_SERVER_ERROR, "INTERNAL_ERROR"),
+    DbError => (INTERNAL_SERVER_ERROR, "DB_ERROR"),
+    RedisError => (INTERNAL_SERVER_ERROR, "REDIS_ERROR"),
+    IoError => (INTERNAL_SERVER_ERROR, "IO_ERROR"),
+    StorageError => (INTERNAL_SERVER_ERROR, "STORAGE_ERROR"),
+    MailError => (INTERNAL_SERVER_ERROR, "MAIL_ERROR"),
+    SmsError => (INTERNAL_SERVER_ERROR, "SMS_ERROR"),
+    LdapError => (INTERNAL_SERVER_ERROR, "LDAP_ERROR"),
+    OidcError => (UNAUTHORIZED, "OIDC_ERROR"),
+    Unauthorized => (UNAUTHORIZED, "UNAUTHORIZED"),
+    Forbidden => (FORBIDDEN, "FORBIDDEN"),
+    ValidationFailed => (UNPROCESSABLE_ENTITY, "VALIDATION_FAILED"),
+    TooManyRequests => (TOO_MANY_REQUESTS, "TOO_MANY_REQUESTS"),
+    TimeRangeInvalid => (BAD_REQUEST, "TIME_RANGE_INVALID"),
+    ExpireTimeInvalid => (BAD_REQUEST, "EXPIRE_TIME_INVALID"),
+
+    // 用户、登录
+    UserNotFound => (NOT_FOUND, "USER_NOT_FOUND"),
+    UserDisabled => (FORBIDDEN, "USER_DISABLED"),
+    UserNoPermission => (FORBIDDEN, "USER_NO_PERMISSION"),
+    UserNameExists => (CONFLICT, "USER_NAME_EXISTS"),
+    UserMobileExists => (CONFLICT, "USER_MOBILE_EXISTS"),
+    UserEmailExists => (CONFLICT, "USER_EMAIL_EXISTS"),
+    AdminUserForbidden => (FORBIDDEN, "ADMIN_USER_FORBIDDEN"),
+    CurrentUserDeleteForbidden => (FORBIDDEN, "CURRENT_USER_DELETE_FORBIDDEN"),
+    CurrentUserKickoutForbidden => (FORBIDDEN, "CURRENT_USER_KICKOUT_FORBIDDEN"),
+    MobileInvalid => (BAD_REQUEST, "MOBILE_INVALID"),
+    NickNameRequired => (BAD_REQUEST, "NICK_NAME_REQUIRED"),
+    AccountRequired => (BAD_REQUEST, "ACCOUNT_REQUIRED"),
+    EmailRequired => (BAD_REQUEST, "EMAIL_REQUIRED"),
+    LoginTypeUnsupported => (BAD_REQUEST, "LOGIN_TYPE_UNSUPPORTED"),
+    PasswordIncorrect => (UNAUTHORIZED, "PASSWORD_INCORRECT"),
+    OldPasswordIncorrect => (BAD_REQUEST, "OLD_PASSWORD_INCORRECT"),
+    PasswordUnchanged => (BAD_REQUEST, "PASSWORD_UNCHANGED"),
+    PasswordChangeExpired => (UNAUTHORIZED, "PASSWORD_CHANGE_EXPIRED"),
+    VerifyCodeIncorrect => (BAD_REQUEST, "VERIFY_CODE_INCORRECT"),
+    VerifyCodeExpired => (BAD_REQUEST, "VERIFY_CODE_EXPIRED"),
+    VerifyCodeTooManyAttempts => (TOO_MANY_REQUESTS, "VERIFY_CODE_TOO_MANY_ATTEMPTS"),
+    LoginVerifyExpired => (UNAUTHORIZED, "LOGIN_VERIFY_EXPIRED"),
+    LoginVerifyTooManyAttempts => (TOO_MANY_REQUESTS, "LOGIN_VERIFY_TOO_MANY_ATTEMPTS"),
+    LoginVerifyEmailMissing => (FORBIDDEN, "LOGIN_VERIFY_EMAIL_MISSING"),
+    OidcDisabled => (BAD_REQUEST, "OIDC_DISABLED"),
+    OidcStateExpired => (UNAUTHORIZED, "OIDC_STATE_EXPIRED"),
+    OidcUserNotFound => (FORBIDDEN, "OIDC_USER_NOT_FOUND"),
+
+    // 角色、菜单
+    RoleNotFound => (NOT_FOUND, "ROLE_NOT_FOUND"),
+    RoleNameExists => (CONFLICT, "ROLE_NAME_EXISTS"),
+    RoleKeyExists => (CONFLICT, "ROLE_KEY_EXISTS"),
+    RoleInUse => (CONFLICT, "ROLE_IN_USE"),
+    AdminRoleForbidden => (FORBIDDEN, "ADMIN_ROLE_FORBIDDEN"),
+    MenuNotFound => (NOT_FOUND, "MENU_NOT_FOUND"),
+    MenuNameExists => (CONFLICT, "MENU_NAME_EXISTS"),
+    MenuPathExists => (CONFLICT, "MENU_PATH_EXISTS"),
+    MenuHasChildren => (CONFLICT, "MENU_HAS_CHILDREN"),
+    MenuInUse => (CONFLICT, "MENU_IN_USE"),
+
+    // 部门、岗位
+    DeptNotFound => (NOT_FOUND, "DEPT_NOT_FOUND"),
+    ParentDeptNotFound => (NOT_FOUND, "PARENT_DEPT_NOT_FOUND"),
+    DeptParentSelf => (BAD_REQUEST, "DEPT_PARENT_SELF"),
+    DeptNameExists => (CONFLICT, "DEPT_NAME_EXISTS"),
+    DeptDisabled => (BAD_REQUEST, "DEPT_DISABLED"),
+    DeptHasChildren => (CONFLICT, "DEPT_HAS_CHILDREN"),
+    DeptHasUsers => (CONFLICT, "DEPT_HAS_USERS"),
+    DeptHasEnabledChildren => (CONFLICT, "DEPT_HAS_ENABLED_CHILDREN"),
+    PostNotFound => (NOT_FOUND, "POST_NOT_FOUND"),
+    PostNameExists => (CONFLICT, "POST_NAME_EXISTS"),
+    PostCodeExists => (CONFLICT, "POST_CODE_EXISTS"),
+    PostInUse => (CONFLICT, "POST_IN_USE"),
+
+    // 字典、参数配置
+    DictTypeNotFound => (NOT_FOUND, "DICT_TYPE_NOT_FOUND"),
+    DictTypeExists => (CONFLICT, "DICT_TYPE_EXISTS"),
+    DictTypeInUse => (CONFLICT, "DICT_TYPE_IN_USE"),
+    DictDataNotFound => (NOT_FOUND, "DICT_DATA_NOT_FOUND"),
+    DictLabelExists => (CONFLICT, "DICT_LABEL_EXISTS"),
+    DictValueExists => (CONFLICT, "DICT_VALUE_EXISTS"),
+    ConfigNotFound => (NOT_FOUND, "CONFIG_NOT_FOUND"),
+    ConfigKeyExists => (CONFLICT, "CONFIG_KEY_EXISTS"),
+    ConfigValueTypeMismatch => (BAD_REQUEST, "CONFIG_VALUE_TYPE_MISMATCH"),
+    ConfigBuiltinDeleteForbidden => (FORBIDDEN, "CONFIG_BUILTIN_DELETE_FORBIDDEN"),
+    ConfigBuiltinKeyForbidden => (FORBIDDEN, "CONFIG_BUILTIN_KEY_FORBIDDEN"),
+
+    // 通知公告
+    NoticeNotFound => (NOT_FOUND, "NOTICE_NOT_FOUND"),
+    NoticeTitleExists => (CONFLICT, "NOTICE_TITLE_EXISTS"),
+    NoticeTargetInvalid => (BAD_REQUEST, "NOTICE_TARGET_INVALID"),
+    NoticeTargetRequired => (BAD_REQUEST, "NOTICE_TARGET_REQUIRED"),
+    NoticeIdsRequired => (BAD_REQUEST, "NOTICE_IDS_REQUIRED"),
+    NoticeExpireBeforePublish => (BAD_REQUEST, "NOTICE_EXPIRE_BEFORE_PUBLISH"),
+
+    // 定时任务、日志
+    JobNotFound => (NOT_FOUND, "JOB_NOT_FOUND"),
+    JobLogNotFound => (NOT_FOUND, "JOB_LOG_NOT_FOUND"),
+    JobCronInvalid => (BAD_REQUEST, "JOB_CRON_INVALID"),
+    JobStatusInvalid => (BAD_REQUEST, "JOB_STATUS_INVALID"),
+    JobConcurrentInvalid => (BAD_REQUEST, "JOB_CONCURRENT_INVALID"),
+    JobMisfirePolicyInvalid => (BAD_REQUEST, "JOB_MISFIRE_POLICY_INVALID"),
+    JobTargetNotRegistered => (BAD_REQUEST, "JOB_TARGET_NOT_REGISTERED"),
+    LoginLogNotFound => (NOT_FOUND, "LOGIN_LOG_NOT_FOUND"),
+    OperateLogNotFound => (NOT_FOUND, "OPERATE_LOG_NOT_FOUND"),
+
+    // 文件、头像
+    FileNotFound => (NOT_FOUND, "FILE_NOT_FOUND"),
+    FileRequired => (BAD_REQUEST, "FILE_REQUIRED"),
+    FileEmpty => (BAD_REQUEST, "FILE_EMPTY"),
+    FileTooLarge => (PAYLOAD_TOO_LARGE, "FILE_TOO_LARGE"),
+    FileTypeUnsupported => (UNSUPPORTED_MEDIA_TYPE, "FILE_TYPE_UNSUPPORTED"),
+    FileStorageMismatch => (CONFLICT, "FILE_STORAGE_MISMATCH"),
+    FileNoPermission => (FORBIDDEN, "FILE_NO_PERMISSION"),
+    AvatarRequired => (BAD_REQUEST, "AVATAR_REQUIRED"),
+    AvatarTooLarge => (PAYLOAD_TOO_LARGE, "AVATAR_TOO_LARGE"),
+    AvatarTypeUnsupported => (UNSUPPORTED_MEDIA_TYPE, "AVATAR_TYPE_UNSUPPORTED"),
+
+    // API密钥
+    ApiKeyNotFound => (NOT_FOUND, "API_KEY_NOT_FOUND"),
+    ApiKeyRevoked => (CONFLICT, "API_KEY_REVOKED"),
+    ApiKeyNameInvalid => (BAD_REQUEST, "API_KEY_NAME_INVALID"),
+    ApiKeyPermissionExceeded => (FORBIDDEN, "API_KEY_PERMISSION_EXCEEDED"),
+    ApiKeyPermissionTooMany => (BAD_REQUEST, "API_KEY_PERMISSION_TOO_MANY"),
+
+    // 语言
+    LocaleUnsupported => (BAD_REQUEST, "LOCALE_UNSUPPORTED"),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error_code::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_error_codes_unique() {
+        let mut codes = HashSet::new();
+        for x in ErrorCode::ALL {
+            assert!(codes.insert(x.code()), "duplicate error code: {}", x.code());
+            assert!(x.code().chars().all(|c| c.is_ascii_uppercase() || c == '_'), "invalid error code: {}", x.code());
+            assert!(x.status().is_client_error() || x.status().is_server_error());
+        }
+    }
+}
@@ -1,2 +1,5 @@
 pub mod error;
+pub mod error_code;
+pub mod page;
 pub mod result;
+pub mod valid;
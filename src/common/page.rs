@@ -0,0 +1,342 @@
+use crate::common::error::{AppError, AppResult, FieldError};
+use crate::common::valid::cross_field_error;
+use crate::i18n::tr;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rbatis::plugin::page::PageRequest;
+use rbatis::rbdc::DateTime;
+use rbs::{value, Value};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+// 每页最大条数
+pub const MAX_PAGE_SIZE: u64 = 500;
+
+/*
+ *排序方向(兼容antd表格的ascend、descend)
+ *author：刘飞华
+ *date：2026/10/19 04:20:36
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[serde(alias = "ascend")]
+    Asc, //升序
+    #[default]
+    #[serde(alias = "descend")]
+    Desc, //降序
+}
+
+impl SortOrder {
+    fn sql(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+}
+
+/*
+ *列表接口允许排序的字段
+ *author：刘飞华
+ *date：2026/10/19 04:20:36
+ */
+pub struct SortColumns {
+    pub columns: &'static [(&'static str, &'static str)], //允许排序的字段(请求参数中的字段名,数据库字段)
+    pub default: &'static str,                            //默认排序
+}
+
+/*
+ *分页、排序和时间范围查询参数(列表请求参数通过#[serde(flatten)]复用)
+ *author：刘飞华
+ *date：2026/10/19 04:20:36
+ */
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_page_query"))]
+pub struct PageQuery {
+    #[serde(default = "default_page_no")]
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub page_no: u64, //页码(从1开始)
+    #[serde(default = "default_page_size")]
+    #[validate(range(min = 1, max = MAX_PAGE_SIZE, message = "validation.range_between"))]
+    pub page_size: u64, //每页条数
+    #[validate(length(max = 50, message = "validation.length_max"))]
+    pub sort_field: Option<String>, //排序字段(只能使用接口允许的字段,为空时使用默认排序)
+    pub sort_order: Option<SortOrder>, //排序方向(asc,desc,默认desc)
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub begin_time: Option<DateTime>, //开始时间
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub end_time: Option<DateTime>, //结束时间
+    #[serde(skip_deserializing)]
+    #[schema(ignore)]
+    pub order_by: String, //排序sql(由sort根据允许排序的字段生成,不能通过请求参数指定)
+}
+
+fn default_page_no() -> u64 {
+    1
+}
+
+fn default_page_size() -> u64 {
+    10
+}
+
+impl Default for PageQuery {
+    fn default() -> Self {
+        PageQuery {
+            page_no: default_page_no(),
+            page_size: default_page_size(),
+            sort_field: None,
+            sort_order: None,
+            begin_time: None,
+            end_time: None,
+            order_by: String::new(),
+        }
+    }
+}
+
+// 结束时间必须晚于开始时间
+fn validate_time_range(begin_time: &Option<DateTime>, end_time: &Option<DateTime>) -> Result<(), ValidationError> {
+    if let (Some(begin_time), Some(end_time)) = (begin_time, end_time) {
+        if !end_time.after(begin_time) {
+            return Err(cross_field_error("endTime", "TIME_RANGE_INVALID"));
+        }
+    }
+    Ok(())
+}
+
+fn validate_page_query(page: &PageQuery) -> Result<(), ValidationError> {
+    validate_time_range(&page.begin_time, &page.end_time)
+}
+
+impl PageQuery {
+    // 分页查询参数
+    pub fn page_request(&self) -> PageRequest {
+        PageRequest::new(self.page_no, self.page_size)
+    }
+
+    // 分页查询的偏移量
+    pub fn offset(&self) -> u64 {
+        self.page_no.saturating_sub(1) * self.page_size
+    }
+
+    // 拼接时间范围条件(手写sql的列表使用)
+    pub fn push_time_range(&self, column: &str, sql: &mut String, param: &mut Vec<Value>) {
+        if let Some(begin_time) = &self.begin_time {
+            sql.push_str(&format!(" and {} >= ?", column));
+            param.push(value!(begin_time));
+        }
+        if let Some(end_time) = &self.end_time {
+            sql.push_str(&format!(" and {} <= ?", column));
+            param.push(value!(end_time));
+        }
+    }
+
+    /*
+     *根据允许排序的字段生成排序sql(字段不允许排序时返回验证错误)
+     *author：刘飞华
+     *date：2026/10/19 04:20:36
+     */
+    pub fn sort(&mut self, sort: &SortColumns) -> AppResult<()> {
+        self.order_by = match self.sort_field.as_deref().filter(|x| !x.is_empty()) {
+            None => sort.default.to_string(),
+            Some(field) => match sort.columns.iter().find(|(name, _)| *name == field) {
+                Some((_, column)) => format!("{} {}", column, self.sort_order.unwrap_or_default().sql()),
+                None => {
+                    let allowed = sort.columns.iter().map(|(name, _)| *name).collect::<Vec<&str>>().join(", ");
+                    return Err(AppError::FieldValidationError(vec![FieldError {
+                        field: "sortField".to_string(),
+                        message: tr("validation.sort_field", &[("field", "sortField"), ("allowed", &allowed)]),
+                    }]));
+                }
+            },
+        };
+        Ok(())
+    }
+}
+
+/*
+ *分页信息(列表接口响应中的页码、每页条数和总页数)
+ *author：刘飞华
+ *date：2026/10/19 04:20:36
+ */
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    pub page_no: u64,   //页码
+    pub page_size: u64, //每页条数
+    pub pages: u64,     //总页数
+}
+
+impl PageInfo {
+    pub fn new(page: &PageQuery, total: u64) -> Self {
+        PageInfo {
+            page_no: page.page_no,
+            page_size: page.page_size,
+            pages: total.div_ceil(page.page_size.max(1)),
+        }
+    }
+}
+
+/*
+ *游标分页查询参数(按时间和id倒序查询,不统计总数也不跳过前面的记录,适合数据量大的日志表)
+ *author：刘飞华
+ *date：2026/10/19 05:12:40
+ */
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_cursor_query"))]
+pub struct CursorQuery {
+    #[validate(length(max = 200, message = "validation.length_max"))]
+    pub cursor: Option<String>, //游标(上一页返回的nextCursor,为空时查询第一页)
+    #[serde(default = "default_page_size")]
+    #[validate(range(min = 1, max = MAX_PAGE_SIZE, message = "validation.range_between"))]
+    pub page_size: u64, //每页条数
+    #[serde(default)]
+    pub with_total: bool, //是否返回近似总数(表的估算行数,不受查询条件影响)
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub begin_time: Option<DateTime>, //开始时间
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub end_time: Option<DateTime>, //结束时间
+    #[serde(skip_deserializing)]
+    #[schema(ignore)]
+    pub cursor_time: Option<DateTime>, //游标对应记录的时间(由decode解析游标生成)
+    #[serde(skip_deserializing)]
+    #[schema(ignore)]
+    pub cursor_id: Option<i64>, //游标对应记录的id(由decode解析游标生成)
+    #[serde(skip_deserializing)]
+    #[schema(ignore)]
+    pub limit: u64, //查询条数(多查一条用来判断是否还有下一页)
+}
+
+fn validate_cursor_query(page: &CursorQuery) -> Result<(), ValidationError> {
+    validate_time_range(&page.begin_time, &page.end_time)
+}
+
+impl Default for CursorQuery {
+    fn default() -> Self {
+        CursorQuery {
+            cursor: None,
+            page_size: default_page_size(),
+            with_total: false,
+            begin_time: None,
+            end_time: None,
+            cursor_time: None,
+            cursor_id: None,
+            limit: 0,
+        }
+    }
+}
+
+// 生成游标(记录的id和时间,客户端不需要解析)
+pub fn encode_cursor(time: &DateTime, id: i64) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{}_{}", id, time))
+}
+
+impl CursorQuery {
+    /*
+     *解析游标和查询条数(游标无效时返回验证错误)
+     *author：刘飞华
+     *date：2026/10/19 05:12:40
+     */
+    pub fn decode(&mut self) -> AppResult<()> {
+        self.limit = self.page_size + 1;
+        let Some(cursor) = self.cursor.as_deref().filter(|x| !x.is_empty()) else {
+            return Ok(());
+        };
+
+        let decoded = URL_SAFE_NO_PAD.decode(cursor).ok().and_then(|x| String::from_utf8(x).ok()).and_then(|x| {
+            let (id, time) = x.split_once('_')?;
+            Some((id.parse::<i64>().ok()?, DateTime::from_str(time).ok()?))
+        });
+        let Some((id, time)) = decoded else {
+            return Err(AppError::FieldValidationError(vec![FieldError {
+                field: "cursor".to_string(),
+                message: tr("validation.cursor", &[("field", "cursor")]),
+            }]));
+        };
+        self.cursor_id = Some(id);
+        self.cursor_time = Some(time);
+        Ok(())
+    }
+
+    /*
+     *截取当前页的记录并生成下一页的游标(没有下一页时游标为空)
+     *author：刘飞华
+     *date：2026/10/19 05:12:40
+     */
+    pub fn next_page<T>(&self, mut records: Vec<T>, key: impl Fn(&T) -> (Option<&DateTime>, Option<i64>)) -> (Vec<T>, Option<String>) {
+        if records.len() as u64 <= self.page_size {
+            return (records, None);
+        }
+        records.truncate(self.page_size as usize);
+        let next_cursor = match records.last().map(key) {
+            Some((Some(time), Some(id))) => Some(encode_cursor(time, id)),
+            _ => None,
+        };
+        (records, next_cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::page::*;
+
+    const SORT: SortColumns = SortColumns {
+        columns: &[("id", "id"), ("createTime", "create_time")],
+        default: "create_time desc",
+    };
+
+    #[test]
+    fn test_sort() {
+        let mut page = PageQuery::default();
+        page.sort(&SORT).unwrap();
+        assert_eq!(page.order_by, "create_time desc");
+
+        let mut page: PageQuery = serde_json::from_str(r#"{"sortField":"createTime","sortOrder":"ascend"}"#).unwrap();
+        page.sort(&SORT).unwrap();
+        assert_eq!(page.order_by, "create_time asc");
+
+        // 不允许排序的字段和sql注入
+        let mut page: PageQuery = serde_json::from_str(r#"{"sortField":"password","orderBy":"id; drop table sys_user"}"#).unwrap();
+        assert_eq!(page.order_by, "");
+        assert!(matches!(page.sort(&SORT), Err(AppError::FieldValidationError(_))));
+    }
+
+    #[test]
+    fn test_page() {
+        let page: PageQuery = serde_json::from_str(r#"{"pageNo":3,"pageSize":20}"#).unwrap();
+        assert_eq!(page.offset(), 40);
+        assert_eq!(PageInfo::new(&page, 41).pages, 3);
+        assert_eq!(PageInfo::new(&page, 0).pages, 0);
+
+        let page: PageQuery = serde_json::from_str(r#"{"pageSize":1000}"#).unwrap();
+        assert!(page.validate().unwrap_err().field_errors().contains_key("page_size"));
+
+        let page: PageQuery = serde_json::from_str(r#"{"beginTime":"2026-10-19 00:00:00","endTime":"2026-10-18 00:00:00"}"#).unwrap();
+        assert!(page.validate().unwrap_err().field_errors().contains_key("__all__"));
+    }
+
+    #[test]
+    fn test_cursor() {
+        let time = DateTime::from_str("2026-10-19 05:12:40").unwrap();
+        let mut page: CursorQuery = serde_json::from_str(&format!(r#"{{"cursor":"{}","pageSize":2,"cursorId":1}}"#, encode_cursor(&time, 42))).unwrap();
+        assert_eq!(page.cursor_id, None);
+        page.decode().unwrap();
+        assert_eq!((page.cursor_id, page.cursor_time.as_ref(), page.limit), (Some(42), Some(&time), 3));
+
+        let (records, next_cursor) = page.next_page(vec![(3, time.clone()), (2, time.clone()), (1, time.clone())], |x| (Some(&x.1), Some(x.0)));
+        assert_eq!(records.len(), 2);
+        assert_eq!(next_cursor, Some(encode_cursor(&time, 2)));
+        let (records, next_cursor) = page.next_page(records, |x| (Some(&x.1), Some(x.0)));
+        assert_eq!((records.len(), next_cursor), (2, None));
+
+        let mut page: CursorQuery = serde_json::from_str(r#"{"cursor":"not a cursor"}"#).unwrap();
+        assert!(matches!(page.decode(), Err(AppError::FieldValidationError(_))));
+        let mut page = CursorQuery::default();
+        page.decode().unwrap();
+        assert_eq!((page.cursor_id, page.limit), (None, 11));
+    }
+}
@@ -1,28 +1,60 @@
-use crate::common::error::AppResult;
+use crate::common::error::{AppResult, FieldError};
+use crate::common::page::{PageInfo, PageQuery};
+use crate::i18n::t;
 use axum::Json;
 use rbatis::rbdc::DateTime;
 use serde::Serialize;
 use std::fmt::Debug;
+use utoipa::ToSchema;
 
 // 统一返回vo
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, ToSchema)]
 pub struct BaseResponse<T> {
     pub code: i32,
     pub msg: String,
     pub data: Option<T>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+// 错误返回vo(errorCode为错误码目录中的错误码,errors为字段验证错误)
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorResponse {
+    pub code: i32,
+    pub msg: String,
+    pub error_code: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+    pub data: Option<String>,
+}
+
+// 分页返回vo(pageNo、pageSize、pages为页码、每页条数、总页数)
+#[derive(Serialize, Debug, Clone, ToSchema)]
 pub struct ResponsePage<T> {
     pub code: i32,
     pub msg: String,
     pub total: u64,
+    #[serde(flatten)]
+    pub page: PageInfo,
+    pub success: bool,
+    pub data: Option<T>,
+}
+
+// 游标分页返回vo(nextCursor为下一页的游标,没有下一页时为空;total为近似总数,请求withTotal时才返回)
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseCursorPage<T> {
+    pub code: i32,
+    pub msg: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
     pub success: bool,
     pub data: Option<T>,
 }
 
 pub fn ok_result() -> AppResult<Json<BaseResponse<String>>> {
-    ok_result_msg("操作成功")
+    ok_result_msg(&t("common.success"))
 }
 
 pub fn ok_result_msg(msg: &str) -> AppResult<Json<BaseResponse<String>>> {
@@ -35,19 +67,32 @@ pub fn ok_result_msg(msg: &str) -> AppResult<Json<BaseResponse<String>>> {
 
 pub fn ok_result_data<T>(data: T) -> AppResult<Json<BaseResponse<T>>> {
     Ok(Json(BaseResponse {
-        msg: "操作成功".to_string(),
+        msg: t("common.success"),
         code: 0,
         data: Some(data),
     }))
 }
 
-pub fn ok_result_page<T>(data: T, total: u64) -> AppResult<Json<ResponsePage<T>>> {
+pub fn ok_result_page<T>(data: T, total: u64, page: &PageQuery) -> AppResult<Json<ResponsePage<T>>> {
     Ok(Json(ResponsePage {
-        msg: "操作成功".to_string(),
+        msg: t("common.success"),
+        code: 0,
+        success: true,
+        data: Some(data),
+        total,
+        page: PageInfo::new(page, total),
+    }))
+}
+
+pub fn ok_result_cursor_page<T>(data: T, next_cursor: Option<String>, total: Option<u64>) -> AppResult<Json<ResponseCursorPage<T>>> {
+    Ok(Json(ResponseCursorPage {
+        msg: t("common.success"),
         code: 0,
         success: true,
         data: Some(data),
         total,
+        has_more: next_cursor.is_some(),
+        next_cursor,
     }))
 }
 
@@ -0,0 +1,141 @@
+use crate::common::error::AppError;
+use crate::i18n::tr;
+use crate::sms::is_valid_mobile;
+use axum::extract::{FromRequest, FromRequestParts, Query, Request};
+use axum::http::request::Parts;
+use axum::Json;
+use axum_valid::{Valid, ValidationRejection};
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use validator::{Validate, ValidationError};
+
+/*
+ *验证后的json请求参数(json解析失败或者验证失败时返回422和字段错误)
+ *author：刘飞华
+ *date：2026/10/19 03:35:12
+ */
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Valid::<Json<T>>::from_request(req, state).await {
+            Ok(Valid(Json(value))) => Ok(ValidJson(value)),
+            Err(ValidationRejection::Valid(e)) => Err(AppError::validation_error(&e)),
+            Err(ValidationRejection::Inner(e)) => Err(AppError::ValidationError(tr("validation.json", &[("msg", &e.body_text())]))),
+        }
+    }
+}
+
+/*
+ *验证后的查询参数(解析失败或者验证失败时返回422和字段错误)
+ *author：刘飞华
+ *date：2026/10/19 03:35:12
+ */
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ValidQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match Valid::<Query<T>>::from_request_parts(parts, state).await {
+            Ok(Valid(Query(value))) => Ok(ValidQuery(value)),
+            Err(ValidationRejection::Valid(e)) => Err(AppError::validation_error(&e)),
+            Err(ValidationRejection::Inner(e)) => Err(AppError::ValidationError(tr("validation.json", &[("msg", &e.body_text())]))),
+        }
+    }
+}
+
+// 手机号码
+pub fn validate_mobile(mobile: &str) -> Result<(), ValidationError> {
+    if is_valid_mobile(mobile) {
+        return Ok(());
+    }
+    Err(ValidationError::new("mobile").with_message(Cow::from("validation.mobile")))
+}
+
+// 手机号码(可以为空)
+pub fn validate_optional_mobile(mobile: &str) -> Result<(), ValidationError> {
+    if mobile.is_empty() {
+        return Ok(());
+    }
+    validate_mobile(mobile)
+}
+
+// 邮箱(可以为空)
+pub fn validate_optional_email(email: &str) -> Result<(), ValidationError> {
+    if email.is_empty() || validator::ValidateEmail::validate_email(&email) {
+        return Ok(());
+    }
+    Err(ValidationError::new("email"))
+}
+
+// 多个字段之间的验证错误(field为对应的请求字段,message为语言包中的key)
+pub fn cross_field_error(field: &'static str, message: &'static str) -> ValidationError {
+    let mut error = ValidationError::new("cross_field").with_message(Cow::from(message));
+    error.add_param(Cow::from("field"), &field);
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error::AppError;
+    use crate::common::valid::*;
+    use crate::vo::system::sys_user_vo::{QueryUserListReq, UpdateUserPwdReq, UserReq};
+    use axum::body::Body;
+    use axum::http::header::CONTENT_TYPE;
+
+    async fn extract<T: DeserializeOwned + Validate>(body: &str) -> Result<T, AppError> {
+        let req = Request::post("/").header(CONTENT_TYPE, "application/json").body(Body::from(body.to_string())).unwrap();
+        ValidJson::<T>::from_request(req, &()).await.map(|x| x.0)
+    }
+
+    fn fields(e: AppError) -> Vec<String> {
+        match e {
+            AppError::FieldValidationError(errors) => {
+                let mut fields = errors.into_iter().map(|x| x.field).collect::<Vec<String>>();
+                fields.sort();
+                fields
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_valid_json() {
+        let req = extract::<QueryUserListReq>(r#"{"pageNo":1,"pageSize":10}"#).await.unwrap();
+        assert_eq!((req.page.page_no, req.page.page_size), (1, 10));
+
+        let e = extract::<QueryUserListReq>(r#"{"pageNo":0,"pageSize":10000}"#).await.unwrap_err();
+        assert_eq!(fields(e), vec!["pageNo", "pageSize"]);
+
+        let body = r#"{"status":2,"beginTime":"2026-10-19 00:00:00","endTime":"2026-10-18 00:00:00"}"#;
+        let e = extract::<QueryUserListReq>(body).await.unwrap_err();
+        assert_eq!(fields(e), vec!["endTime", "status"]);
+
+        let body = r#"{"mobile":"123","userName":"","nickName":"test","email":"test","status":3,"deptId":1,"postIds":[]}"#;
+        let e = extract::<UserReq>(body).await.unwrap_err();
+        assert_eq!(fields(e), vec!["email", "mobile", "status", "userName"]);
+
+        let body = r#"{"mobile":"","userName":"test","nickName":"test","email":"","status":1,"deptId":1,"postIds":[]}"#;
+        assert!(extract::<UserReq>(body).await.is_ok());
+
+        let e = extract::<UpdateUserPwdReq>(r#"{"pwd":"123456","rePwd":"123456"}"#).await.unwrap_err();
+        assert_eq!(fields(e), vec!["rePwd"]);
+
+        let e = extract::<QueryUserListReq>(r#"{"pageNo":-1}"#).await.unwrap_err();
+        assert_eq!(e.error_code(), crate::common::error_code::ErrorCode::ValidationFailed);
+        assert!(matches!(e, AppError::ValidationError(_)));
+    }
+}
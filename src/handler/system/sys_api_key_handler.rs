@@ -0,0 +1,178 @@
+use crate::common::error::{AppError, AppResult};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::handler::system::sys_user_handler::query_btn_menu;
+use crate::model::system::sys_api_key_model::{revoke_api_key, update_api_key, ApiKey, API_KEY_SORT};
+use crate::utils::api_key_util::generate_api_key;
+use crate::vo::system::sys_api_key_vo::*;
+use crate::AppState;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::IntoResponse;
+use log::info;
+use rbatis::rbatis_codegen::ops::AsProxy;
+use rbatis::rbdc::DateTime;
+use rbatis::RBatis;
+use std::sync::Arc;
+
+/*
+ *添加API密钥(密钥只在创建时返回一次,数据库只保存摘要)
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/apiKey/addApiKey",
+    tag = "API密钥",
+    summary = "添加API密钥(密钥只在创建时返回一次,数据库只保存摘要)",
+    request_body = AddApiKeyReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<AddApiKeyResp>))
+)]
+pub async fn add_sys_api_key(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<AddApiKeyReq>) -> impl IntoResponse {
+    info!("add sys_api_key params: {:?}", &item);
+    let rb = &state.batis;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    let key_name = check_api_key_name(&item.key_name)?;
+    check_expire_time(&item.expire_time)?;
+    let permissions = check_api_key_permissions(rb, user_id, &item.permissions).await?;
+
+    let (api_key, key_prefix, key_hash) = generate_api_key();
+    let sys_api_key = ApiKey {
+        id: None,
+        user_id,
+        key_name,
+        key_prefix: key_prefix.clone(),
+        key_hash,
+        permissions,
+        expire_time: item.expire_time,
+        status: 1,
+        last_used_time: None,
+        last_used_ip: "".to_string(),
+        remark: item.remark,
+        create_time: Some(DateTime::now()),
+        update_time: None,
+    };
+
+    let id = ApiKey::insert(rb, &sys_api_key).await?.last_insert_id.i64();
+    ok_result_data(AddApiKeyResp { id, key_prefix, api_key })
+}
+
+/*
+ *更新API密钥(名称、允许访问的接口、过期时间)
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/apiKey/updateApiKey",
+    tag = "API密钥",
+    summary = "更新API密钥(名称、允许访问的接口、过期时间)",
+    request_body = UpdateApiKeyReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_api_key(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateApiKeyReq>) -> impl IntoResponse {
+    info!("update sys_api_key params: {:?}", &item);
+    let rb = &state.batis;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    let mut sys_api_key = match ApiKey::select_by_id(rb, item.id).await? {
+        Some(x) if x.user_id == user_id => x,
+        _ => return Err(AppError::BusinessError(ErrorCode::ApiKeyNotFound)),
+    };
+    if sys_api_key.status != 1 {
+        return Err(AppError::BusinessError(ErrorCode::ApiKeyRevoked));
+    }
+
+    sys_api_key.key_name = check_api_key_name(&item.key_name)?;
+    check_expire_time(&item.expire_time)?;
+    sys_api_key.permissions = check_api_key_permissions(rb, user_id, &item.permissions).await?;
+    sys_api_key.expire_time = item.expire_time;
+    sys_api_key.remark = item.remark;
+
+    update_api_key(rb, &sys_api_key).await.map(|_| ok_result())?
+}
+
+/*
+ *吊销API密钥(吊销后立即失效,不能恢复)
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/apiKey/revokeApiKey",
+    tag = "API密钥",
+    summary = "吊销API密钥(吊销后立即失效,不能恢复)",
+    request_body = RevokeApiKeyReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn revoke_sys_api_key(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<RevokeApiKeyReq>) -> impl IntoResponse {
+    info!("revoke sys_api_key params: {:?}", &item);
+    let rb = &state.batis;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    revoke_api_key(rb, user_id, &item.ids).await.map(|_| ok_result())?
+}
+
+/*
+ *查询当前用户的API密钥列表
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/apiKey/queryApiKeyList",
+    tag = "API密钥",
+    summary = "查询当前用户的API密钥列表",
+    request_body = QueryApiKeyListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<ApiKeyResp>>))
+)]
+pub async fn query_sys_api_key_list(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryApiKeyListReq>) -> impl IntoResponse {
+    info!("query sys_api_key_list params: {:?}", &item);
+    let rb = &state.batis;
+
+    item.user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    item.page.sort(&API_KEY_SORT)?;
+    let page = &item.page.page_request();
+
+    ApiKey::select_api_key_list(rb, page, &item)
+        .await
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<ApiKeyResp>>(), x.total, &item.page))?
+}
+
+fn check_api_key_name(key_name: &str) -> AppResult<String> {
+    let key_name = key_name.trim();
+    if key_name.is_empty() || key_name.chars().count() > 50 {
+        return Err(AppError::BusinessError(ErrorCode::ApiKeyNameInvalid));
+    }
+    Ok(key_name.to_string())
+}
+
+fn check_expire_time(expire_time: &Option<DateTime>) -> AppResult<()> {
+    if expire_time.as_ref().is_some_and(|x| x.unix_timestamp() <= DateTime::now().unix_timestamp()) {
+        return Err(AppError::BusinessError(ErrorCode::ExpireTimeInvalid));
+    }
+    Ok(())
+}
+
+// 密钥允许访问的接口只能是当前用户权限的子集,返回逗号分隔的接口
+async fn check_api_key_permissions(rb: &RBatis, user_id: i64, permissions: &[String]) -> AppResult<String> {
+    if permissions.is_empty() {
+        return Ok("".to_string());
+    }
+
+    let (btn_menu, _) = query_btn_menu(&user_id, rb.clone()).await;
+    if permissions.iter().any(|x| !btn_menu.contains(x)) {
+        return Err(AppError::BusinessError(ErrorCode::ApiKeyPermissionExceeded));
+    }
+
+    let mut list = permissions.to_vec();
+    list.sort();
+    list.dedup();
+    let permissions = list.join(",");
+    if permissions.len() > 4000 {
+        return Err(AppError::BusinessError(ErrorCode::ApiKeyPermissionTooMany));
+    }
+    Ok(permissions)
+}
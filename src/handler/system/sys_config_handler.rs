@@ -0,0 +1,196 @@
+use crate::common::error::AppError;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::model::system::sys_config_model::{Config, CONFIG_SORT};
+use crate::utils::config_util::{clear_config_cache, is_valid_config_value, remove_config_cache};
+use crate::vo::system::sys_config_vo::*;
+use crate::AppState;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use log::info;
+use rbs::value;
+use std::sync::Arc;
+/*
+ *添加参数配置
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/addConfig",
+    tag = "参数配置",
+    summary = "添加参数配置",
+    request_body = ConfigReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn add_sys_config(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<ConfigReq>) -> impl IntoResponse {
+    info!("add sys_config params: {:?}", &item);
+    let rb = &state.batis;
+
+    if Config::select_by_config_key(rb, &item.config_key).await?.is_some() {
+        return Err(AppError::BusinessError(ErrorCode::ConfigKeyExists));
+    }
+
+    if !is_valid_config_value(item.value_type, &item.config_value) {
+        return Err(AppError::BusinessError(ErrorCode::ConfigValueTypeMismatch));
+    }
+
+    item.id = None;
+    let key = item.config_key.clone();
+    Config::insert(rb, &Config::from(item)).await?;
+
+    remove_config_cache(&state, &[key]).map(|_| ok_result())?
+}
+
+/*
+ *删除参数配置
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/deleteConfig",
+    tag = "参数配置",
+    summary = "删除参数配置",
+    request_body = DeleteConfigReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_config(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteConfigReq>) -> impl IntoResponse {
+    info!("delete sys_config params: {:?}", &item);
+    let rb = &state.batis;
+
+    let mut keys: Vec<String> = Vec::new();
+    for id in item.ids.clone() {
+        let x = match Config::select_by_id(rb, &id).await? {
+            None => return Err(AppError::BusinessError(ErrorCode::ConfigNotFound)),
+            Some(x) => x,
+        };
+
+        if x.is_builtin == 1 {
+            return Err(AppError::BusinessError(ErrorCode::ConfigBuiltinDeleteForbidden));
+        }
+        keys.push(x.config_key);
+    }
+
+    Config::delete_by_map(rb, value! {"id": &item.ids}).await?;
+
+    remove_config_cache(&state, &keys).map(|_| ok_result())?
+}
+
+/*
+ *更新参数配置
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/updateConfig",
+    tag = "参数配置",
+    summary = "更新参数配置",
+    request_body = ConfigReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_config(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<ConfigReq>) -> impl IntoResponse {
+    info!("update sys_config params: {:?}", &item);
+    let rb = &state.batis;
+
+    let id = item.id;
+
+    let old = match Config::select_by_id(rb, &id.unwrap_or_default()).await? {
+        None => return Err(AppError::BusinessError(ErrorCode::ConfigNotFound)),
+        Some(x) => x,
+    };
+
+    if old.is_builtin == 1 {
+        if old.config_key != item.config_key {
+            return Err(AppError::BusinessError(ErrorCode::ConfigBuiltinKeyForbidden));
+        }
+        // 内置参数只允许修改参数值,不允许取消内置标识
+        item.is_builtin = 1;
+    }
+
+    if let Some(x) = Config::select_by_config_key(rb, &item.config_key).await? {
+        if x.id != id {
+            return Err(AppError::BusinessError(ErrorCode::ConfigKeyExists));
+        }
+    }
+
+    if !is_valid_config_value(item.value_type, &item.config_value) {
+        return Err(AppError::BusinessError(ErrorCode::ConfigValueTypeMismatch));
+    }
+
+    let keys = vec![old.config_key, item.config_key.clone()];
+    Config::update_by_map(rb, &Config::from(item), value! {"id": &id}).await?;
+
+    remove_config_cache(&state, &keys).map(|_| ok_result())?
+}
+
+/*
+ *刷新参数缓存
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/config/refreshConfigCache",
+    tag = "参数配置",
+    summary = "刷新参数缓存",
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn refresh_sys_config_cache(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    info!("refresh sys_config cache");
+
+    clear_config_cache(&state).map(|_| ok_result())?
+}
+
+/*
+ *查询参数配置详情
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/queryConfigDetail",
+    tag = "参数配置",
+    summary = "查询参数配置详情",
+    request_body = QueryConfigDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<ConfigResp>))
+)]
+pub async fn query_sys_config_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryConfigDetailReq>) -> impl IntoResponse {
+    info!("query sys_config_detail params: {:?}", &item);
+    let rb = &state.batis;
+
+    Config::select_by_id(rb, &item.id).await?.map_or_else(
+        || Err(AppError::BusinessError(ErrorCode::ConfigNotFound)),
+        |x| {
+            let data: ConfigResp = x.into();
+            ok_result_data(data)
+        },
+    )
+}
+
+/*
+ *查询参数配置列表
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/config/queryConfigList",
+    tag = "参数配置",
+    summary = "查询参数配置列表",
+    request_body = QueryConfigListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<ConfigResp>>))
+)]
+pub async fn query_sys_config_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryConfigListReq>) -> impl IntoResponse {
+    info!("query sys_config_list params: {:?}", &item);
+    let rb = &state.batis;
+
+    item.page.sort(&CONFIG_SORT)?;
+    let page = &item.page.page_request();
+
+    Config::select_config_list(rb, page, &item)
+        .await
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<ConfigResp>>(), x.total, &item.page))?
+}
@@ -1,12 +1,12 @@
 use crate::common::error::AppError;
-use crate::common::result::{ok_result, ok_result_data};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, BaseResponse};
+use crate::common::valid::ValidJson;
 use crate::model::system::sys_dept_model::{check_dept_exist_user, select_children_dept_by_id, select_dept_count, select_normal_children_dept_by_id, Dept};
 use crate::vo::system::sys_dept_vo::*;
 use crate::AppState;
 use axum::extract::State;
 use axum::response::IntoResponse;
-use axum::Json;
-use axum_valid::Valid;
 use log::info;
 use rbatis::rbatis_codegen::ops::AsProxy;
 use rbatis::rbdc::DateTime;
@@ -18,19 +18,27 @@ use validator::Validate;
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn add_sys_dept(State(state): State<Arc<AppState>>, Valid(Json(item)): Valid<Json<DeptReq>>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/addDept",
+    tag = "部门",
+    summary = "添加部门表",
+    request_body = DeptReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn add_sys_dept(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeptReq>) -> impl IntoResponse {
     info!("add sys_dept params: {:?}", &item);
     let rb = &state.batis;
 
     if Dept::select_by_dept_name(rb, &item.dept_name, item.parent_id).await?.is_some() {
-        return Err(AppError::BusinessError("部门名称已存在"));
+        return Err(AppError::BusinessError(ErrorCode::DeptNameExists));
     }
 
     match Dept::select_by_id(rb, &item.parent_id).await? {
-        None => Err(AppError::BusinessError("添加失败,上级部门不存在")),
+        None => Err(AppError::BusinessError(ErrorCode::ParentDeptNotFound)),
         Some(dept) => {
             if dept.status == 0 {
-                return Err(AppError::BusinessError("部门停用，不允许添加"));
+                return Err(AppError::BusinessError(ErrorCode::DeptDisabled));
             }
             let ancestors = format!("{},{}", dept.ancestors.unwrap_or_default(), &item.parent_id);
             let mut sys_dept = Dept::from(item);
@@ -48,16 +56,24 @@ pub async fn add_sys_dept(State(state): State<Arc<AppState>>, Valid(Json(item)):
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn delete_sys_dept(State(state): State<Arc<AppState>>, Json(item): Json<DeleteDeptReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/deleteDept",
+    tag = "部门",
+    summary = "删除部门表",
+    request_body = DeleteDeptReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_dept(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteDeptReq>) -> impl IntoResponse {
     info!("delete sys_dept params: {:?}", &item);
     let rb = &state.batis;
 
     if select_dept_count(rb, &item.id).await? > 0 {
-        return Err(AppError::BusinessError("存在下级部门,不允许删除"));
+        return Err(AppError::BusinessError(ErrorCode::DeptHasChildren));
     }
 
     if check_dept_exist_user(rb, &item.id).await? > 0 {
-        return Err(AppError::BusinessError("部门存在用户,不允许删除"));
+        return Err(AppError::BusinessError(ErrorCode::DeptHasUsers));
     }
 
     Dept::delete_by_map(rb, value! {"id": &item.id}).await.map(|_| ok_result())?
@@ -68,22 +84,30 @@ pub async fn delete_sys_dept(State(state): State<Arc<AppState>>, Json(item): Jso
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn update_sys_dept(State(state): State<Arc<AppState>>, Valid(Json(mut item)): Valid<Json<DeptReq>>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/updateDept",
+    tag = "部门",
+    summary = "更新部门表",
+    request_body = DeptReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_dept(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<DeptReq>) -> impl IntoResponse {
     info!("update sys_dept params: {:?}", &item);
     let rb = &state.batis;
 
     let id = item.id;
     if Some(item.parent_id) == id {
-        return Err(AppError::BusinessError("上级部门不能是自己"));
+        return Err(AppError::BusinessError(ErrorCode::DeptParentSelf));
     }
 
     let old_ancestors = match Dept::select_by_id(rb, &id.unwrap_or_default()).await? {
-        None => return Err(AppError::BusinessError("部门不存在")),
+        None => return Err(AppError::BusinessError(ErrorCode::DeptNotFound)),
         Some(dept) => dept.ancestors.unwrap_or_default(),
     };
 
     let ancestors = match Dept::select_by_id(rb, &item.parent_id).await? {
-        None => return Err(AppError::BusinessError("上级部门不存在")),
+        None => return Err(AppError::BusinessError(ErrorCode::ParentDeptNotFound)),
         Some(dept) => {
             format!("{},{}", dept.ancestors.unwrap_or_default(), &item.parent_id)
         }
@@ -91,12 +115,12 @@ pub async fn update_sys_dept(State(state): State<Arc<AppState>>, Valid(Json(mut
 
     if let Some(dept) = Dept::select_by_dept_name(rb, &item.dept_name, item.parent_id).await? {
         if dept.id != id {
-            return Err(AppError::BusinessError("部门名称已存在"));
+            return Err(AppError::BusinessError(ErrorCode::DeptNameExists));
         }
     }
 
     if select_normal_children_dept_by_id(rb, &id.unwrap_or_default()).await? > 0 && item.status == 0 {
-        return Err(AppError::BusinessError("该部门包含未停用的子部门"));
+        return Err(AppError::BusinessError(ErrorCode::DeptHasEnabledChildren));
     }
 
     for mut x in select_children_dept_by_id(rb, &id.unwrap_or_default()).await? {
@@ -131,7 +155,15 @@ pub async fn update_sys_dept(State(state): State<Arc<AppState>>, Valid(Json(mut
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn update_sys_dept_status(State(state): State<Arc<AppState>>, Json(item): Json<UpdateDeptStatusReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/updateDeptStatus",
+    tag = "部门",
+    summary = "更新部门表状态",
+    request_body = UpdateDeptStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_dept_status(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateDeptStatusReq>) -> impl IntoResponse {
     info!("update sys_dept_status params: {:?}", &item);
     let rb = &state.batis;
 
@@ -152,12 +184,20 @@ pub async fn update_sys_dept_status(State(state): State<Arc<AppState>>, Json(ite
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_dept_detail(State(state): State<Arc<AppState>>, Json(item): Json<QueryDeptDetailReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/queryDeptDetail",
+    tag = "部门",
+    summary = "查询部门表详情",
+    request_body = QueryDeptDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<DeptResp>))
+)]
+pub async fn query_sys_dept_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryDeptDetailReq>) -> impl IntoResponse {
     info!("query sys_dept_detail params: {:?}", &item);
     let rb = &state.batis;
 
     Dept::select_by_id(rb, &item.id).await?.map_or_else(
-        || Err(AppError::BusinessError("部门不存在")),
+        || Err(AppError::BusinessError(ErrorCode::DeptNotFound)),
         |x| {
             let data: DeptResp = x.into();
             ok_result_data(data)
@@ -170,7 +210,15 @@ pub async fn query_sys_dept_detail(State(state): State<Arc<AppState>>, Json(item
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_dept_list(State(state): State<Arc<AppState>>, Json(item): Json<QueryDeptListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dept/queryDeptList",
+    tag = "部门",
+    summary = "查询部门表列表",
+    request_body = QueryDeptListReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<Vec<DeptResp>>))
+)]
+pub async fn query_sys_dept_list(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryDeptListReq>) -> impl IntoResponse {
     info!("query sys_dept_list params: {:?}", &item);
     let rb = &state.batis;
 
@@ -1,13 +1,13 @@
 use crate::common::error::AppError;
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
-use crate::model::system::sys_dict_data_model::DictData;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::model::system::sys_dict_data_model::{DictData, DICT_DATA_SORT};
 use crate::vo::system::sys_dict_data_vo::*;
 use crate::AppState;
 use axum::extract::State;
 use axum::response::IntoResponse;
-use axum::Json;
 use log::info;
-use rbatis::plugin::page::PageRequest;
 use rbs::value;
 use std::sync::Arc;
 /*
@@ -15,16 +15,24 @@ use std::sync::Arc;
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn add_sys_dict_data(State(state): State<Arc<AppState>>, Json(mut item): Json<DictDataReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/addDictData",
+    tag = "字典数据",
+    summary = "添加字典数据",
+    request_body = DictDataReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn add_sys_dict_data(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<DictDataReq>) -> impl IntoResponse {
     info!("add sys_dict_data params: {:?}", &item);
     let rb = &state.batis;
 
     if DictData::select_by_dict_label(rb, &item.dict_type, &item.dict_label).await?.is_some() {
-        return Err(AppError::BusinessError("字典标签已存在"));
+        return Err(AppError::BusinessError(ErrorCode::DictLabelExists));
     }
 
     if DictData::select_by_dict_value(rb, &item.dict_type, &item.dict_value).await?.is_some() {
-        return Err(AppError::BusinessError("字典键值已存在"));
+        return Err(AppError::BusinessError(ErrorCode::DictValueExists));
     }
 
     item.id = None;
@@ -36,7 +44,15 @@ pub async fn add_sys_dict_data(State(state): State<Arc<AppState>>, Json(mut item
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn delete_sys_dict_data(State(state): State<Arc<AppState>>, Json(item): Json<DeleteDictDataReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/deleteDictData",
+    tag = "字典数据",
+    summary = "删除字典数据",
+    request_body = DeleteDictDataReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_dict_data(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteDictDataReq>) -> impl IntoResponse {
     info!("delete sys_dict_data params: {:?}", &item);
     let rb = &state.batis;
 
@@ -48,25 +64,33 @@ pub async fn delete_sys_dict_data(State(state): State<Arc<AppState>>, Json(item)
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn update_sys_dict_data(State(state): State<Arc<AppState>>, Json(item): Json<DictDataReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/updateDictData",
+    tag = "字典数据",
+    summary = "更新字典数据",
+    request_body = DictDataReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_dict_data(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DictDataReq>) -> impl IntoResponse {
     info!("update sys_dict_data params: {:?}", &item);
     let rb = &state.batis;
 
     let id = item.id;
 
     if DictData::select_by_id(rb, &id.unwrap_or_default()).await?.is_none() {
-        return Err(AppError::BusinessError("字典数据不存在"));
+        return Err(AppError::BusinessError(ErrorCode::DictDataNotFound));
     }
 
     if let Some(x) = DictData::select_by_dict_label(rb, &item.dict_type, &item.dict_label).await? {
         if x.id != item.id {
-            return Err(AppError::BusinessError("字典标签已存在"));
+            return Err(AppError::BusinessError(ErrorCode::DictLabelExists));
         }
     }
 
     if let Some(x) = DictData::select_by_dict_value(rb, &item.dict_type, &item.dict_value).await? {
         if x.id != item.id {
-            return Err(AppError::BusinessError("字典键值已存在"));
+            return Err(AppError::BusinessError(ErrorCode::DictValueExists));
         }
     }
 
@@ -78,7 +102,15 @@ pub async fn update_sys_dict_data(State(state): State<Arc<AppState>>, Json(item)
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn update_sys_dict_data_status(State(state): State<Arc<AppState>>, Json(item): Json<UpdateDictDataStatusReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/updateDictDataStatus",
+    tag = "字典数据",
+    summary = "更新字典数据状态",
+    request_body = UpdateDictDataStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_dict_data_status(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateDictDataStatusReq>) -> impl IntoResponse {
     info!("update sys_dict_data_status params: {:?}", &item);
     let rb = &state.batis;
 
@@ -94,12 +126,20 @@ pub async fn update_sys_dict_data_status(State(state): State<Arc<AppState>>, Jso
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_dict_data_detail(State(state): State<Arc<AppState>>, Json(item): Json<QueryDictDataDetailReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/queryDictDataDetail",
+    tag = "字典数据",
+    summary = "查询字典数据详情",
+    request_body = QueryDictDataDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<DictDataResp>))
+)]
+pub async fn query_sys_dict_data_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryDictDataDetailReq>) -> impl IntoResponse {
     info!("query sys_dict_data_detail params: {:?}", &item);
     let rb = &state.batis;
 
     DictData::select_by_id(rb, &item.id).await?.map_or_else(
-        || Err(AppError::BusinessError("字典数据不存在")),
+        || Err(AppError::BusinessError(ErrorCode::DictDataNotFound)),
         |x| {
             let data: DictDataResp = x.into();
             ok_result_data(data)
@@ -112,13 +152,22 @@ pub async fn query_sys_dict_data_detail(State(state): State<Arc<AppState>>, Json
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_dict_data_list(State(state): State<Arc<AppState>>, Json(item): Json<QueryDictDataListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictData/queryDictDataList",
+    tag = "字典数据",
+    summary = "查询字典数据列",
+    request_body = QueryDictDataListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<DictDataResp>>))
+)]
+pub async fn query_sys_dict_data_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryDictDataListReq>) -> impl IntoResponse {
     info!("query sys_dict_data_list params: {:?}", &item);
     let rb = &state.batis;
 
-    let page = &PageRequest::new(item.page_no, item.page_size);
+    item.page.sort(&DICT_DATA_SORT)?;
+    let page = &item.page.page_request();
 
     DictData::select_dict_data_list(rb, page, &item)
         .await
-        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<DictDataResp>>(), x.total))?
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<DictDataResp>>(), x.total, &item.page))?
 }
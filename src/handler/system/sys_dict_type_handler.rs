@@ -1,14 +1,14 @@
 use crate::common::error::AppError;
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::ValidJson;
 use crate::model::system::sys_dict_data_model::{count_dict_data_by_type, update_dict_data_type};
-use crate::model::system::sys_dict_type_model::DictType;
+use crate::model::system::sys_dict_type_model::{DictType, DICT_TYPE_SORT};
 use crate::vo::system::sys_dict_type_vo::*;
 use crate::AppState;
 use axum::extract::State;
 use axum::response::IntoResponse;
-use axum::Json;
 use log::info;
-use rbatis::plugin::page::PageRequest;
 use rbs::value;
 use std::sync::Arc;
 /*
@@ -16,12 +16,20 @@ use std::sync::Arc;
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn add_sys_dict_type(State(state): State<Arc<AppState>>, Json(mut item): Json<DictTypeReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/addDictType",
+    tag = "字典类型",
+    summary = "添加字典类型",
+    request_body = DictTypeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn add_sys_dict_type(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<DictTypeReq>) -> impl IntoResponse {
     info!("add sys_dict_type params: {:?}", &item);
     let rb = &state.batis;
 
     if DictType::select_by_dict_type(rb, &item.dict_type).await?.is_some() {
-        return Err(AppError::BusinessError("字典类型已存在"));
+        return Err(AppError::BusinessError(ErrorCode::DictTypeExists));
     }
 
     item.id = None;
@@ -33,19 +41,27 @@ pub async fn add_sys_dict_type(State(state): State<Arc<AppState>>, Json(mut item
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn delete_sys_dict_type(State(state): State<Arc<AppState>>, Json(item): Json<DeleteDictTypeReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/deleteDictType",
+    tag = "字典类型",
+    summary = "删除字典类型",
+    request_body = DeleteDictTypeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_dict_type(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteDictTypeReq>) -> impl IntoResponse {
     info!("delete sys_dict_type params: {:?}", &item);
     let rb = &state.batis;
 
     let ids = item.ids.clone();
     for id in ids {
         let p = match DictType::select_by_id(rb, &id).await? {
-            None => return Err(AppError::BusinessError("字典类型不存在,不能删除")),
+            None => return Err(AppError::BusinessError(ErrorCode::DictTypeNotFound)),
             Some(p) => p,
         };
 
         if count_dict_data_by_type(rb, &p.dict_type).await? > 0 {
-            return Err(AppError::BusinessError("已分配,不能删除"));
+            return Err(AppError::BusinessError(ErrorCode::DictTypeInUse));
         }
     }
 
@@ -57,18 +73,26 @@ pub async fn delete_sys_dict_type(State(state): State<Arc<AppState>>, Json(item)
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn update_sys_dict_type(State(state): State<Arc<AppState>>, Json(item): Json<DictTypeReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/updateDictType",
+    tag = "字典类型",
+    summary = "更新字典类型",
+    request_body = DictTypeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_dict_type(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DictTypeReq>) -> impl IntoResponse {
     info!("update sys_dict_type params: {:?}", &item);
     let rb = &state.batis;
 
     let id = item.id;
     if DictType::select_by_id(rb, &id.unwrap_or_default()).await?.is_none() {
-        return Err(AppError::BusinessError("字典类型不存在"));
+        return Err(AppError::BusinessError(ErrorCode::DictTypeNotFound));
     }
 
     if let Some(x) = DictType::select_by_dict_type(rb, &item.dict_type).await? {
         if x.id != id {
-            return Err(AppError::BusinessError("字典类型已存在"));
+            return Err(AppError::BusinessError(ErrorCode::DictTypeExists));
         }
 
         let dict_type = x.dict_type;
@@ -83,7 +107,15 @@ pub async fn update_sys_dict_type(State(state): State<Arc<AppState>>, Json(item)
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn update_sys_dict_type_status(State(state): State<Arc<AppState>>, Json(item): Json<UpdateDictTypeStatusReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/updateDictTypeStatus",
+    tag = "字典类型",
+    summary = "更新字典类型状态",
+    request_body = UpdateDictTypeStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_dict_type_status(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateDictTypeStatusReq>) -> impl IntoResponse {
     info!("update sys_dict_type_status params: {:?}", &item);
     let rb = &state.batis;
 
@@ -99,12 +131,20 @@ pub async fn update_sys_dict_type_status(State(state): State<Arc<AppState>>, Jso
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_dict_type_detail(State(state): State<Arc<AppState>>, Json(item): Json<QueryDictTypeDetailReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/queryDictTypeDetail",
+    tag = "字典类型",
+    summary = "查询字典类型详情",
+    request_body = QueryDictTypeDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<DictTypeResp>))
+)]
+pub async fn query_sys_dict_type_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryDictTypeDetailReq>) -> impl IntoResponse {
     info!("query sys_dict_type_detail params: {:?}", &item);
     let rb = &state.batis;
 
     DictType::select_by_id(rb, &item.id).await?.map_or_else(
-        || Err(AppError::BusinessError("字典类型不存在")),
+        || Err(AppError::BusinessError(ErrorCode::DictTypeNotFound)),
         |x| {
             let data: DictTypeResp = x.into();
             ok_result_data(data)
@@ -117,13 +157,22 @@ pub async fn query_sys_dict_type_detail(State(state): State<Arc<AppState>>, Json
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_dict_type_list(State(state): State<Arc<AppState>>, Json(item): Json<QueryDictTypeListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/dictType/queryDictTypeList",
+    tag = "字典类型",
+    summary = "查询字典类型列",
+    request_body = QueryDictTypeListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<DictTypeResp>>))
+)]
+pub async fn query_sys_dict_type_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryDictTypeListReq>) -> impl IntoResponse {
     info!("query sys_dict_type_list params: {:?}", &item);
     let rb = &state.batis;
 
-    let page = &PageRequest::new(item.page_no, item.page_size);
+    item.page.sort(&DICT_TYPE_SORT)?;
+    let page = &item.page.page_request();
 
     DictType::select_dict_type_list(rb, page, &item)
         .await
-        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<DictTypeResp>>(), x.total))?
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<DictTypeResp>>(), x.total, &item.page))?
 }
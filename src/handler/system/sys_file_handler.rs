@@ -0,0 +1,410 @@
+use crate::common::error::{AppError, AppResult};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::{ValidJson, ValidQuery};
+use crate::handler::system::sys_user_handler::query_btn_menu;
+use crate::model::system::sys_file_model::{SysFile, SysFileBlob, FILE_SORT};
+use crate::model::system::sys_user_role_model::is_admin;
+use crate::storage::parse_range;
+use crate::vo::system::sys_file_vo::*;
+use crate::AppState;
+use axum::body::{Body, Bytes};
+use axum::extract::{Multipart, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use log::info;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use rbatis::executor::Executor;
+use rbatis::rbatis_codegen::ops::AsProxy;
+use rbatis::rbdc::DateTime;
+use rbatis::RBatis;
+use rbs::value;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+// 文件管理权限(可以下载其他用户上传的文件)
+const FILE_MANAGE_PERMISSION: &str = "/api/system/file/queryFileList";
+
+/*
+ *上传文件(multipart表单字段: file,大小和类型限制见配置文件[storage])
+ *每次上传都保存一条文件信息,相同内容的文件只保存一份,重复上传时存储文件的引用次数加1
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/file/upload",
+    tag = "文件管理",
+    summary = "上传文件",
+    request_body(content_type = "multipart/form-data", description = "文件(表单字段: file)"),
+    responses((status = 200, description = "操作成功", body = BaseResponse<FileResp>))
+)]
+pub async fn upload_file(headers: HeaderMap, State(state): State<Arc<AppState>>, mut multipart: Multipart) -> impl IntoResponse {
+    let rb = &state.batis;
+    let config = &state.storage_config;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+
+    let mut file = None;
+    while let Some(mut field) = multipart.next_field().await.map_err(|e| AppError::ValidationError(e.to_string()))? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let file_name = clean_file_name(field.file_name().unwrap_or_default());
+        let mut data = Vec::new();
+        while let Some(chunk) = field.chunk().await.map_err(|e| AppError::ValidationError(e.to_string()))? {
+            if data.len() + chunk.len() > config.max_size {
+                return Err(AppError::BusinessError(ErrorCode::FileTooLarge));
+            }
+            data.extend_from_slice(&chunk);
+        }
+        file = Some((file_name, Bytes::from(data)));
+        break;
+    }
+
+    let (file_name, data) = match file {
+        None => return Err(AppError::BusinessError(ErrorCode::FileRequired)),
+        Some(x) => x,
+    };
+    info!("upload file params: {:?}, {}, {}", user_id, file_name, data.len());
+    if data.is_empty() {
+        return Err(AppError::BusinessError(ErrorCode::FileEmpty));
+    }
+
+    let ext = file_ext(&file_name);
+    if !config.is_allowed_type(&ext) {
+        return Err(AppError::BusinessError(ErrorCode::FileTypeUnsupported));
+    }
+
+    let file_hash = hex::encode(Sha256::digest(&data));
+
+    // 已经有相同内容的文件时引用次数加1,否则保存到存储中(同时上传相同的新文件时唯一索引冲突,重试一次)
+    let blob = match acquire_blob(&state, &ext, &file_hash, data.clone()).await {
+        Ok(x) => x,
+        Err(e) => {
+            log::warn!("acquire file blob error hash: {}, error message: {:?}, retry", file_hash, e);
+            acquire_blob(&state, &ext, &file_hash, data).await?
+        }
+    };
+
+    let mut file = SysFile {
+        id: None,
+        blob_id: blob.id.unwrap_or_default(),
+        file_name,
+        file_key: blob.file_key,
+        storage_type: blob.storage_type,
+        file_size: blob.file_size,
+        mime_type: blob.mime_type,
+        file_hash: blob.file_hash,
+        owner_id: user_id,
+        create_time: Some(DateTime::now()),
+        update_time: None,
+    };
+
+    match SysFile::insert(rb, &file).await {
+        Ok(x) => {
+            file.id = Some(x.last_insert_id.i64());
+            let data: FileResp = file.into();
+            ok_result_data(data)
+        }
+        // 文件信息保存失败时释放存储文件的引用
+        Err(e) => {
+            decrease_ref_count(&state, file.blob_id).await?;
+            Err(e.into())
+        }
+    }
+}
+
+/*
+ *下载文件(支持Range请求,断点续传,只有上传者、超级管理员和有文件管理权限的用户可以下载)
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/file/download",
+    tag = "文件管理",
+    summary = "下载文件(支持Range请求,断点续传)",
+    params(("id" = i64, Query, description = "文件id")),
+    responses((status = 200, description = "文件内容", content_type = "application/octet-stream"))
+)]
+pub async fn download_file(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidQuery(item): ValidQuery<QueryFileDetailReq>) -> AppResult<Response> {
+    info!("download file params: {:?}", &item);
+    let rb = &state.batis;
+
+    let file = match SysFile::select_by_id(rb, &item.id).await? {
+        None => return Err(AppError::BusinessError(ErrorCode::FileNotFound)),
+        Some(x) => x,
+    };
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    if !can_access_file(rb, &file, user_id).await {
+        return Err(AppError::BusinessError(ErrorCode::FileNoPermission));
+    }
+    if file.storage_type != state.storage.kind() {
+        return Err(AppError::BusinessError(ErrorCode::FileStorageMismatch));
+    }
+
+    let size = file.file_size as u64;
+    let builder = Response::builder()
+        .header(header::CONTENT_TYPE, &file.mime_type)
+        .header(header::CONTENT_DISPOSITION, content_disposition(&file.file_name, &file.mime_type))
+        .header(header::X_CONTENT_TYPE_OPTIONS, "nosniff")
+        .header(header::ACCEPT_RANGES, "bytes")
+        .header(header::ETAG, format!("\"{}\"", file.file_hash))
+        .header(header::CACHE_CONTROL, "private, max-age=86400");
+
+    // 只处理bytes单位的Range,其他的忽略,返回整个文件
+    let range = headers.get(header::RANGE).and_then(|x| x.to_str().ok()).filter(|x| x.trim().starts_with("bytes="));
+    let response = match range.map(|x| parse_range(x, size)) {
+        None => builder.status(StatusCode::OK).header(header::CONTENT_LENGTH, size).body(state.storage.get(&file.file_key, None).await?),
+        Some(None) => builder
+            .status(StatusCode::RANGE_NOT_SATISFIABLE)
+            .header(header::CONTENT_RANGE, format!("bytes */{}", size))
+            .body(Body::empty()),
+        Some(Some((start, end))) => builder
+            .status(StatusCode::PARTIAL_CONTENT)
+            .header(header::CONTENT_RANGE, format!("bytes {}-{}/{}", start, end, size))
+            .header(header::CONTENT_LENGTH, end - start + 1)
+            .body(state.storage.get(&file.file_key, Some((start, end))).await?),
+    };
+
+    Ok(response.unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response()))
+}
+
+/*
+ *删除文件(只能删除自己上传的文件,超级管理员可以删除全部文件;存储文件的引用次数减1,没有引用时删除存储中的文件)
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/file/deleteFile",
+    tag = "文件管理",
+    summary = "删除文件(只能删除自己上传的文件,没有引用时删除存储中的文件)",
+    request_body = DeleteFileReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_file(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteFileReq>) -> impl IntoResponse {
+    info!("delete sys_file params: {:?}", &item);
+    let rb = &state.batis;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    let admin = is_admin(rb, &user_id).await? == 1;
+
+    let mut files = Vec::new();
+    for id in item.ids {
+        match SysFile::select_by_id(rb, &id).await? {
+            None => continue,
+            Some(x) if !admin && x.owner_id != user_id => return Err(AppError::BusinessError(ErrorCode::FileNoPermission)),
+            Some(x) => files.push(x),
+        }
+    }
+
+    for file in files {
+        let result = rb.exec("delete from sys_file where id = ?", vec![value!(file.id)]).await?;
+        if result.rows_affected > 0 {
+            decrease_ref_count(&state, file.blob_id).await?;
+        }
+    }
+
+    ok_result()
+}
+
+/*
+ *查询文件详情
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/file/queryFileDetail",
+    tag = "文件管理",
+    summary = "查询文件详情",
+    request_body = QueryFileDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<FileResp>))
+)]
+pub async fn query_sys_file_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryFileDetailReq>) -> impl IntoResponse {
+    info!("query sys_file_detail params: {:?}", &item);
+    let rb = &state.batis;
+
+    match SysFile::select_by_id(rb, &item.id).await? {
+        None => Err(AppError::BusinessError(ErrorCode::FileNotFound)),
+        Some(x) => {
+            let data: FileResp = x.into();
+            ok_result_data(data)
+        }
+    }
+}
+
+/*
+ *查询文件列表
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/file/queryFileList",
+    tag = "文件管理",
+    summary = "查询文件列表",
+    request_body = QueryFileListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<FileResp>>))
+)]
+pub async fn query_sys_file_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryFileListReq>) -> impl IntoResponse {
+    info!("query sys_file_list params: {:?}", &item);
+    let rb = &state.batis;
+
+    item.page.sort(&FILE_SORT)?;
+    let page = &item.page.page_request();
+
+    SysFile::select_file_list(rb, page, &item)
+        .await
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<FileResp>>(), x.total, &item.page))?
+}
+
+/*
+ *引用存储文件(已经有相同内容的存储文件时引用次数加1,否则保存新的存储文件)
+ *在事务中锁定存储文件记录,和删除存储文件互斥,不会引用到已经删除的文件
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+async fn acquire_blob(state: &AppState, ext: &str, file_hash: &str, data: Bytes) -> AppResult<SysFileBlob> {
+    let storage_type = state.storage.kind();
+    let tx = state.batis.acquire_begin().await?.defer_async(|tx| async move {
+        if !tx.done() {
+            let _ = tx.rollback().await;
+        }
+    });
+
+    let sql = "select * from sys_file_blob where file_hash = ? and storage_type = ? limit 1 for update";
+    let blobs: Vec<SysFileBlob> = tx.query_decode(sql, vec![value!(file_hash), value!(storage_type)]).await?;
+    let blob = match blobs.into_iter().next() {
+        Some(mut blob) => {
+            tx.exec("update sys_file_blob set ref_count = ref_count + 1 where id = ?", vec![value!(blob.id)]).await?;
+            blob.ref_count += 1;
+            blob
+        }
+        None => {
+            let mime_type = mime_guess::from_ext(ext).first_or_octet_stream().to_string();
+            let file_key = if ext.is_empty() {
+                format!("{}/{}", &file_hash[..2], file_hash)
+            } else {
+                format!("{}/{}.{}", &file_hash[..2], file_hash, ext)
+            };
+            let mut blob = SysFileBlob {
+                id: None,
+                file_key,
+                storage_type: storage_type.to_string(),
+                file_size: data.len() as i64,
+                mime_type,
+                file_hash: file_hash.to_string(),
+                ref_count: 1,
+                create_time: Some(DateTime::now()),
+                update_time: None,
+            };
+            // 先插入记录(提交前其他事务不能引用或者删除),再保存到存储中
+            let result = SysFileBlob::insert(&tx, &blob).await?;
+            blob.id = Some(result.last_insert_id.i64());
+            state.storage.put(&blob.file_key, data, &blob.mime_type).await?;
+            blob
+        }
+    };
+
+    tx.commit().await?;
+    Ok(blob)
+}
+
+/*
+ *释放存储文件的引用(引用次数减1,没有引用时删除存储文件)
+ *在事务中锁定存储文件记录,删除存储中的文件后才提交,同时上传相同内容的文件时会等待删除完成后重新保存
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+async fn decrease_ref_count(state: &AppState, blob_id: i64) -> AppResult<()> {
+    let tx = state.batis.acquire_begin().await?.defer_async(|tx| async move {
+        if !tx.done() {
+            let _ = tx.rollback().await;
+        }
+    });
+
+    let blobs: Vec<SysFileBlob> = tx.query_decode("select * from sys_file_blob where id = ? for update", vec![value!(blob_id)]).await?;
+    let Some(blob) = blobs.into_iter().next() else {
+        tx.commit().await?;
+        return Ok(());
+    };
+
+    if blob.ref_count > 1 {
+        tx.exec("update sys_file_blob set ref_count = ref_count - 1 where id = ?", vec![value!(blob_id)]).await?;
+    } else {
+        tx.exec("delete from sys_file_blob where id = ?", vec![value!(blob_id)]).await?;
+        if blob.storage_type == state.storage.kind() {
+            if let Err(e) = state.storage.delete(&blob.file_key).await {
+                log::warn!("delete file error key: {}, error message: {:?}", blob.file_key, e);
+            }
+        }
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+// 上传者、超级管理员或者有文件管理权限(查询文件列表)的用户可以访问文件
+async fn can_access_file(rb: &RBatis, file: &SysFile, user_id: i64) -> bool {
+    if file.owner_id == user_id {
+        return true;
+    }
+    let (permissions, is_admin) = query_btn_menu(&user_id, rb.clone()).await;
+    is_admin || permissions.iter().any(|x| x == FILE_MANAGE_PERMISSION)
+}
+
+// 去掉文件名中的路径,最多保留255个字符
+fn clean_file_name(file_name: &str) -> String {
+    let file_name = file_name.rsplit(['/', '\\']).next().unwrap_or_default().trim();
+    let file_name = if file_name.is_empty() { "file" } else { file_name };
+    file_name.chars().filter(|c| !c.is_control()).take(255).collect()
+}
+
+// 文件扩展名(小写,只包含字母和数字)
+fn file_ext(file_name: &str) -> String {
+    match file_name.rsplit_once('.') {
+        Some((name, ext)) if !name.is_empty() && !ext.is_empty() && ext.len() <= 10 && ext.chars().all(|c| c.is_ascii_alphanumeric()) => ext.to_ascii_lowercase(),
+        _ => String::new(),
+    }
+}
+
+// 浏览器中直接显示的图片类型(svg可以包含脚本,不在其中)
+const INLINE_MIME_TYPES: [&str; 5] = ["image/png", "image/jpeg", "image/gif", "image/webp", "image/bmp"];
+
+// 下载文件名(filename*支持中文文件名),图片以外的文件都作为附件下载
+fn content_disposition(file_name: &str, mime_type: &str) -> String {
+    let disposition = if INLINE_MIME_TYPES.contains(&mime_type) { "inline" } else { "attachment" };
+    let ascii_name = file_name
+        .chars()
+        .map(|c| if c.is_ascii_graphic() && c != '"' && c != '\\' || c == ' ' { c } else { '_' })
+        .collect::<String>();
+    format!("{}; filename=\"{}\"; filename*=UTF-8''{}", disposition, ascii_name, utf8_percent_encode(file_name, NON_ALPHANUMERIC))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::handler::system::sys_file_handler::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(clean_file_name("C:\\Users\\a\\报告.PDF"), "报告.PDF");
+        assert_eq!(clean_file_name("../../etc/passwd"), "passwd");
+        assert_eq!(clean_file_name(""), "file");
+
+        assert_eq!(file_ext("报告.PDF"), "pdf");
+        assert_eq!(file_ext("archive.tar.gz"), "gz");
+        assert_eq!(file_ext(".bashrc"), "");
+        assert_eq!(file_ext("a.p/g"), "");
+
+        assert_eq!(
+            content_disposition("报告 1.pdf", "application/pdf"),
+            "attachment; filename=\"__ 1.pdf\"; filename*=UTF-8''%E6%8A%A5%E5%91%8A%201%2Epdf"
+        );
+        assert_eq!(content_disposition("a.png", "image/png"), "inline; filename=\"a.png\"; filename*=UTF-8''a%2Epng");
+        assert!(content_disposition("a.svg", "image/svg+xml").starts_with("attachment;"));
+        assert!(content_disposition("a.html", "text/html").starts_with("attachment;"));
+    }
+}
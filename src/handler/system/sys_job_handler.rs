@@ -0,0 +1,248 @@
+use crate::common::error::AppError;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::model::system::sys_job_model::{Job, JOB_SORT};
+use crate::task::scheduler::is_valid_cron;
+use crate::vo::system::sys_job_vo::*;
+use crate::AppState;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use log::info;
+use rbatis::rbatis_codegen::ops::AsProxy;
+use rbs::value;
+use std::sync::Arc;
+/*
+ *添加定时任务
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/addJob",
+    tag = "定时任务",
+    summary = "添加定时任务",
+    request_body = JobReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn add_sys_job(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<JobReq>) -> impl IntoResponse {
+    info!("add sys_job params: {:?}", &item);
+    let rb = &state.batis;
+
+    check_job(&state, &item)?;
+
+    item.id = None;
+    let mut job = Job::from(item);
+    job.id = Some(Job::insert(rb, &job).await?.last_insert_id.i64());
+
+    if job.status == 1 {
+        state.scheduler.schedule_job(&state, job);
+    }
+    ok_result()
+}
+
+/*
+ *删除定时任务
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/deleteJob",
+    tag = "定时任务",
+    summary = "删除定时任务",
+    request_body = DeleteJobReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_job(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteJobReq>) -> impl IntoResponse {
+    info!("delete sys_job params: {:?}", &item);
+    let rb = &state.batis;
+
+    Job::delete_by_map(rb, value! {"id": &item.ids}).await?;
+
+    for id in item.ids {
+        state.scheduler.unschedule_job(id);
+    }
+    ok_result()
+}
+
+/*
+ *更新定时任务
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/updateJob",
+    tag = "定时任务",
+    summary = "更新定时任务",
+    request_body = JobReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_job(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<JobReq>) -> impl IntoResponse {
+    info!("update sys_job params: {:?}", &item);
+    let rb = &state.batis;
+
+    let id = item.id;
+    if Job::select_by_id(rb, &id.unwrap_or_default()).await?.is_none() {
+        return Err(AppError::BusinessError(ErrorCode::JobNotFound));
+    }
+
+    check_job(&state, &item)?;
+
+    Job::update_by_map(rb, &Job::from(item), value! {"id": &id}).await?;
+
+    match Job::select_by_id(rb, &id.unwrap_or_default()).await? {
+        Some(job) if job.status == 1 => state.scheduler.schedule_job(&state, job),
+        _ => state.scheduler.unschedule_job(id.unwrap_or_default()),
+    }
+    ok_result()
+}
+
+/*
+ *更新定时任务状态(暂停/恢复)
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/updateJobStatus",
+    tag = "定时任务",
+    summary = "更新定时任务状态(暂停/恢复)",
+    request_body = UpdateJobStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_job_status(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateJobStatusReq>) -> impl IntoResponse {
+    info!("update sys_job_status params: {:?}", &item);
+    let rb = &state.batis;
+
+    let update_sql = format!("update sys_job set status = ? where id in ({})", item.ids.iter().map(|_| "?").collect::<Vec<&str>>().join(", "));
+
+    let mut param = vec![value!(item.status)];
+    param.extend(item.ids.iter().map(|&id| value!(id)));
+    rb.exec(&update_sql, param).await?;
+
+    for id in item.ids {
+        if item.status == 0 {
+            state.scheduler.unschedule_job(id);
+        } else if let Some(job) = Job::select_by_id(rb, &id).await? {
+            state.scheduler.schedule_job(&state, job);
+        }
+    }
+    ok_result()
+}
+
+/*
+ *立即执行一次定时任务
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/runJob",
+    tag = "定时任务",
+    summary = "立即执行一次定时任务",
+    request_body = RunJobReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn run_sys_job(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<RunJobReq>) -> impl IntoResponse {
+    info!("run sys_job params: {:?}", &item);
+    let rb = &state.batis;
+
+    let job = match Job::select_by_id(rb, &item.id).await? {
+        None => return Err(AppError::BusinessError(ErrorCode::JobNotFound)),
+        Some(x) => x,
+    };
+
+    if !state.scheduler.has_handler(&job.invoke_target) {
+        return Err(AppError::BusinessError(ErrorCode::JobTargetNotRegistered));
+    }
+
+    state.scheduler.run_job(&state, job);
+    ok_result()
+}
+
+/*
+ *查询定时任务详情
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/queryJobDetail",
+    tag = "定时任务",
+    summary = "查询定时任务详情",
+    request_body = QueryJobDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<JobResp>))
+)]
+pub async fn query_sys_job_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryJobDetailReq>) -> impl IntoResponse {
+    info!("query sys_job_detail params: {:?}", &item);
+    let rb = &state.batis;
+
+    Job::select_by_id(rb, &item.id).await?.map_or_else(
+        || Err(AppError::BusinessError(ErrorCode::JobNotFound)),
+        |x| {
+            let data: JobResp = x.into();
+            ok_result_data(data)
+        },
+    )
+}
+
+/*
+ *查询定时任务列表
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/job/queryJobList",
+    tag = "定时任务",
+    summary = "查询定时任务列表",
+    request_body = QueryJobListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<JobResp>>))
+)]
+pub async fn query_sys_job_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryJobListReq>) -> impl IntoResponse {
+    info!("query sys_job_list params: {:?}", &item);
+    let rb = &state.batis;
+
+    item.page.sort(&JOB_SORT)?;
+    let page = &item.page.page_request();
+
+    Job::select_job_list(rb, page, &item)
+        .await
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<JobResp>>(), x.total, &item.page))?
+}
+
+/*
+ *查询代码中已注册的调用目标
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/job/queryInvokeTargetList",
+    tag = "定时任务",
+    summary = "查询代码中已注册的调用目标",
+    responses((status = 200, description = "操作成功", body = BaseResponse<Vec<String>>))
+)]
+pub async fn query_invoke_target_list(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    info!("query invoke_target_list");
+
+    ok_result_data(state.scheduler.handler_names())
+}
+
+/*
+ *校验定时任务参数
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+fn check_job(state: &AppState, item: &JobReq) -> Result<(), AppError> {
+    if !is_valid_cron(&item.cron_expression) {
+        return Err(AppError::BusinessError(ErrorCode::JobCronInvalid));
+    }
+
+    if !state.scheduler.has_handler(&item.invoke_target) {
+        return Err(AppError::BusinessError(ErrorCode::JobTargetNotRegistered));
+    }
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::common::error::AppError;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::model::system::sys_job_log_model::{clean_job_log, JobLog, JOB_LOG_SORT};
+use crate::vo::system::sys_job_log_vo::*;
+use crate::AppState;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use log::info;
+use rbs::value;
+use std::sync::Arc;
+/*
+ *删除定时任务日志
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/jobLog/deleteJobLog",
+    tag = "定时任务日志",
+    summary = "删除定时任务日志",
+    request_body = DeleteJobLogReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_job_log(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteJobLogReq>) -> impl IntoResponse {
+    info!("delete sys_job_log params: {:?}", &item);
+    let rb = &state.batis;
+
+    JobLog::delete_by_map(rb, value! {"id": &item.ids}).await.map(|_| ok_result())?
+}
+
+/*
+ *清空定时任务日志
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/jobLog/cleanJobLog",
+    tag = "定时任务日志",
+    summary = "清空定时任务日志",
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn clean_sys_job_log(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    info!("clean sys_job_log");
+    let rb = &state.batis;
+
+    clean_job_log(rb).await.map(|_| ok_result())?
+}
+
+/*
+ *查询定时任务日志详情
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/jobLog/queryJobLogDetail",
+    tag = "定时任务日志",
+    summary = "查询定时任务日志详情",
+    request_body = QueryJobLogDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<JobLogResp>))
+)]
+pub async fn query_sys_job_log_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryJobLogDetailReq>) -> impl IntoResponse {
+    info!("query sys_job_log_detail params: {:?}", &item);
+    let rb = &state.batis;
+
+    JobLog::select_by_id(rb, &item.id).await?.map_or_else(
+        || Err(AppError::BusinessError(ErrorCode::JobLogNotFound)),
+        |x| {
+            let data: JobLogResp = x.into();
+            ok_result_data(data)
+        },
+    )
+}
+
+/*
+ *查询定时任务日志列表
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/jobLog/queryJobLogList",
+    tag = "定时任务日志",
+    summary = "查询定时任务日志列表",
+    request_body = QueryJobLogListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<JobLogResp>>))
+)]
+pub async fn query_sys_job_log_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryJobLogListReq>) -> impl IntoResponse {
+    info!("query sys_job_log_list params: {:?}", &item);
+    let rb = &state.batis;
+
+    item.page.sort(&JOB_LOG_SORT)?;
+    let page = &item.page.page_request();
+
+    JobLog::select_job_log_list(rb, page, &item)
+        .await
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<JobLogResp>>(), x.total, &item.page))?
+}
@@ -1,13 +1,14 @@
 use crate::common::error::AppError;
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
-use crate::model::system::sys_login_log_model::{clean_login_log, LoginLog};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_cursor_page, ok_result_data, ok_result_page, BaseResponse, ResponseCursorPage, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::model::system::sys_login_log_model::{clean_login_log, count_login_log_estimate, LoginLog, LOGIN_LOG_SORT};
+use crate::utils::log_archive_util::purge_log;
 use crate::vo::system::sys_login_log_vo::*;
 use crate::AppState;
 use axum::extract::State;
 use axum::response::IntoResponse;
-use axum::Json;
 use log::info;
-use rbatis::plugin::page::PageRequest;
 use rbs::value;
 use std::sync::Arc;
 /*
@@ -15,7 +16,15 @@ use std::sync::Arc;
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn delete_sys_login_log(State(state): State<Arc<AppState>>, Json(item): Json<DeleteLoginLogReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/loginLog/deleteLoginLog",
+    tag = "系统访问记录",
+    summary = "删除系统访问记录",
+    request_body = DeleteLoginLogReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_login_log(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteLoginLogReq>) -> impl IntoResponse {
     info!("delete sys_login_log params: {:?}", &item);
     let rb = &state.batis;
 
@@ -27,6 +36,13 @@ pub async fn delete_sys_login_log(State(state): State<Arc<AppState>>, Json(item)
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
+#[utoipa::path(
+    get,
+    path = "/api/system/loginLog/cleanLoginLog",
+    tag = "系统访问记录",
+    summary = "清空系统登录日志",
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
 pub async fn clean_sys_login_log(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     info!("clean sys_login_log ");
     let rb = &state.batis;
@@ -34,17 +50,47 @@ pub async fn clean_sys_login_log(State(state): State<Arc<AppState>>) -> impl Int
     clean_login_log(rb).await.map(|_| ok_result())?
 }
 
+/*
+ *按时间范围删除系统访问记录(开启归档时先归档)
+ *author：刘飞华
+ *date：2026/10/18 17:20:33
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/loginLog/deleteLoginLogByTime",
+    tag = "系统访问记录",
+    summary = "按时间范围删除系统访问记录(开启归档时先归档)",
+    request_body = DeleteLoginLogByTimeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_login_log_by_time(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteLoginLogByTimeReq>) -> impl IntoResponse {
+    info!("delete sys_login_log_by_time params: {:?}", &item);
+
+    let where_sql = "login_time >= ? and login_time <= ?";
+    let params = vec![value!(item.begin_time), value!(item.end_time)];
+
+    purge_log::<LoginLog>(&state, "sys_login_log", where_sql, params, |x| x.id).await.map(ok_result_data)?
+}
+
 /*
  *查询系统访问记录详情
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_login_log_detail(State(state): State<Arc<AppState>>, Json(item): Json<QueryLoginLogDetailReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/loginLog/queryLoginLogDetail",
+    tag = "系统访问记录",
+    summary = "查询系统访问记录详情",
+    request_body = QueryLoginLogDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<LoginLogResp>))
+)]
+pub async fn query_sys_login_log_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryLoginLogDetailReq>) -> impl IntoResponse {
     info!("query sys_login_log_detail params: {:?}", &item);
     let rb = &state.batis;
 
     LoginLog::select_by_id(rb, &item.id).await?.map_or_else(
-        || Err(AppError::BusinessError("系统访问记录不存在")),
+        || Err(AppError::BusinessError(ErrorCode::LoginLogNotFound)),
         |x| {
             let data: LoginLogResp = x.into();
             ok_result_data(data)
@@ -57,13 +103,47 @@ pub async fn query_sys_login_log_detail(State(state): State<Arc<AppState>>, Json
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_login_log_list(State(state): State<Arc<AppState>>, Json(item): Json<QueryLoginLogListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/loginLog/queryLoginLogList",
+    tag = "系统访问记录",
+    summary = "查询系统访问记录列表",
+    request_body = QueryLoginLogListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<LoginLogResp>>))
+)]
+pub async fn query_sys_login_log_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryLoginLogListReq>) -> impl IntoResponse {
     info!("query sys_login_log_list params: {:?}", &item);
     let rb = &state.batis;
 
-    let page = &PageRequest::new(item.page_no, item.page_size);
+    item.page.sort(&LOGIN_LOG_SORT)?;
+    let page = &item.page.page_request();
 
     LoginLog::select_login_log_list(rb, page, &item)
         .await
-        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<LoginLogResp>>(), x.total))?
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<LoginLogResp>>(), x.total, &item.page))?
+}
+
+/*
+ *游标分页查询系统访问记录列表(不统计总数,适合数据量大时翻页)
+ *author：刘飞华
+ *date：2026/10/19 05:12:40
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/loginLog/queryLoginLogCursorList",
+    tag = "系统访问记录",
+    summary = "游标分页查询系统访问记录列表(不统计总数,适合数据量大时翻页)",
+    request_body = QueryLoginLogCursorListReq,
+    responses((status = 200, description = "操作成功", body = ResponseCursorPage<Vec<LoginLogResp>>))
+)]
+pub async fn query_sys_login_log_cursor_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryLoginLogCursorListReq>) -> impl IntoResponse {
+    info!("query sys_login_log_cursor_list params: {:?}", &item);
+    let rb = &state.batis;
+
+    item.page.decode()?;
+    let list = LoginLog::select_login_log_cursor_list(rb, &item).await?;
+    let (list, next_cursor) = item.page.next_page(list, |x| (x.login_time.as_ref(), x.id));
+
+    let total = if item.page.with_total { Some(count_login_log_estimate(rb).await?) } else { None };
+    ok_result_cursor_page(list.into_iter().map(|x| x.into()).collect::<Vec<LoginLogResp>>(), next_cursor, total)
 }
@@ -1,12 +1,13 @@
 use crate::common::error::AppError;
-use crate::common::result::{ok_result, ok_result_data};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, BaseResponse};
+use crate::common::valid::ValidJson;
 use crate::model::system::sys_menu_model::{select_count_menu_by_parent_id, Menu};
 use crate::model::system::sys_role_menu_model::select_count_menu_by_menu_id;
 use crate::vo::system::sys_menu_vo::*;
 use crate::AppState;
 use axum::extract::State;
 use axum::response::IntoResponse;
-use axum::Json;
 use log::info;
 use rbs::value;
 use std::sync::Arc;
@@ -15,17 +16,25 @@ use std::sync::Arc;
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn add_sys_menu(State(state): State<Arc<AppState>>, Json(mut item): Json<MenuReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/addMenu",
+    tag = "菜单信息",
+    summary = "添加菜单信息",
+    request_body = MenuReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn add_sys_menu(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<MenuReq>) -> impl IntoResponse {
     info!("add sys_menu params: {:?}", &item);
     let rb = &state.batis;
 
     if Menu::select_by_menu_name(rb, &item.menu_name).await?.is_some() {
-        return Err(AppError::BusinessError("菜单名称已存在"));
+        return Err(AppError::BusinessError(ErrorCode::MenuNameExists));
     }
 
     if let Some(url) = item.menu_url.clone() {
         if Menu::select_by_menu_url(rb, &url).await?.is_some() {
-            return Err(AppError::BusinessError("路由路径已存在"));
+            return Err(AppError::BusinessError(ErrorCode::MenuPathExists));
         }
     }
 
@@ -38,18 +47,26 @@ pub async fn add_sys_menu(State(state): State<Arc<AppState>>, Json(mut item): Js
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn delete_sys_menu(State(state): State<Arc<AppState>>, Json(item): Json<DeleteMenuReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/deleteMenu",
+    tag = "菜单信息",
+    summary = "删除菜单信息",
+    request_body = DeleteMenuReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_menu(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteMenuReq>) -> impl IntoResponse {
     info!("delete sys_menu params: {:?}", &item);
     let rb = &state.batis;
 
     //有下级的时候 不能直接删除
 
     if select_count_menu_by_parent_id(rb, &item.id).await? > 0 {
-        return Err(AppError::BusinessError("存在子菜单,不允许删除"));
+        return Err(AppError::BusinessError(ErrorCode::MenuHasChildren));
     }
 
     if select_count_menu_by_menu_id(rb, &item.id).await? > 0 {
-        return Err(AppError::BusinessError("菜单已分配,不允许删除"));
+        return Err(AppError::BusinessError(ErrorCode::MenuInUse));
     }
 
     Menu::delete_by_map(rb, value! {"id": &item.id}).await.map(|_| ok_result())?
@@ -60,25 +77,33 @@ pub async fn delete_sys_menu(State(state): State<Arc<AppState>>, Json(item): Jso
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn update_sys_menu(State(state): State<Arc<AppState>>, Json(item): Json<MenuReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/updateMenu",
+    tag = "菜单信息",
+    summary = "更新菜单信息",
+    request_body = MenuReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_menu(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<MenuReq>) -> impl IntoResponse {
     info!("update sys_menu params: {:?}", &item);
     let rb = &state.batis;
 
     let id = item.id;
     if Menu::select_by_id(rb, &id.unwrap_or_default()).await?.is_none() {
-        return Err(AppError::BusinessError("菜单信息不存在"));
+        return Err(AppError::BusinessError(ErrorCode::MenuNotFound));
     }
 
     if let Some(x) = Menu::select_by_menu_name(rb, &item.menu_name).await? {
         if x.id != item.id {
-            return Err(AppError::BusinessError("菜单名称已存在"));
+            return Err(AppError::BusinessError(ErrorCode::MenuNameExists));
         }
     }
 
     if let Some(url) = item.menu_url.clone() {
         if let Some(x) = Menu::select_by_menu_url(rb, &url).await? {
             if x.id != item.id {
-                return Err(AppError::BusinessError("路由路径已存在"));
+                return Err(AppError::BusinessError(ErrorCode::MenuPathExists));
             }
         }
     }
@@ -91,7 +116,15 @@ pub async fn update_sys_menu(State(state): State<Arc<AppState>>, Json(item): Jso
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn update_sys_menu_status(State(state): State<Arc<AppState>>, Json(item): Json<UpdateMenuStatusReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/updateMenuStatus",
+    tag = "菜单信息",
+    summary = "更新菜单信息状态",
+    request_body = UpdateMenuStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_menu_status(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateMenuStatusReq>) -> impl IntoResponse {
     info!("update sys_menu_status params: {:?}", &item);
     let rb = &state.batis;
 
@@ -108,12 +141,20 @@ pub async fn update_sys_menu_status(State(state): State<Arc<AppState>>, Json(ite
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn query_sys_menu_detail(State(state): State<Arc<AppState>>, Json(item): Json<QueryMenuDetailReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/queryMenuDetail",
+    tag = "菜单信息",
+    summary = "查询菜单信息详情",
+    request_body = QueryMenuDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<MenuResp>))
+)]
+pub async fn query_sys_menu_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryMenuDetailReq>) -> impl IntoResponse {
     info!("query sys_menu_detail params: {:?}", &item);
     let rb = &state.batis;
 
     Menu::select_by_id(rb, &item.id).await?.map_or_else(
-        || Err(AppError::BusinessError("菜单信息不存在")),
+        || Err(AppError::BusinessError(ErrorCode::MenuNotFound)),
         |x| {
             let data: MenuResp = x.into();
             ok_result_data(data)
@@ -126,7 +167,15 @@ pub async fn query_sys_menu_detail(State(state): State<Arc<AppState>>, Json(item
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn query_sys_menu_list(State(state): State<Arc<AppState>>, Json(item): Json<QueryMenuListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/menu/queryMenuList",
+    tag = "菜单信息",
+    summary = "查询菜单信息列表",
+    request_body = QueryMenuListReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<Vec<MenuResp>>))
+)]
+pub async fn query_sys_menu_list(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryMenuListReq>) -> impl IntoResponse {
     info!("query sys_menu_list params: {:?}", &item);
     let rb = &state.batis;
 
@@ -138,6 +187,13 @@ pub async fn query_sys_menu_list(State(state): State<Arc<AppState>>, Json(item):
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
+#[utoipa::path(
+    get,
+    path = "/api/system/menu/queryMenuListSimple",
+    tag = "菜单信息",
+    summary = "查询菜单信息(排除按钮)",
+    responses((status = 200, description = "操作成功", body = BaseResponse<Vec<MenuListSimpleDataResp>>))
+)]
 pub async fn query_sys_menu_list_simple(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let rb = &state.batis;
 
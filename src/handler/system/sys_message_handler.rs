@@ -0,0 +1,59 @@
+use crate::utils::message_util::{PushMessage, EVENT_LOGOUT};
+use crate::AppState;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures::stream::{self, Stream};
+use log::info;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::broadcast::Receiver;
+
+/*
+ *建立消息推送连接(SSE),推送新的通知公告、权限变更和强制下线消息
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/message/stream",
+    tag = "消息推送",
+    summary = "建立消息推送连接(SSE),推送新的通知公告、权限变更和强制下线消息",
+    params(("token" = Option<String>, Query, description = "登录token(浏览器EventSource不能设置请求头时使用)")),
+    responses((status = 200, description = "消息推送(SSE)", content_type = "text/event-stream"))
+)]
+pub async fn message_stream(headers: HeaderMap, State(state): State<Arc<AppState>>) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    info!("message stream connected user_id {:?}", user_id);
+
+    let receiver = state.message.subscribe();
+    let stream = stream::unfold((receiver, user_id, false), next_message);
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+/*
+ *读取下一条推送给该用户的消息,收到强制下线消息后断开连接
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+async fn next_message((mut receiver, user_id, closed): (Receiver<PushMessage>, i64, bool)) -> Option<(Result<Event, Infallible>, (Receiver<PushMessage>, i64, bool))> {
+    if closed {
+        info!("message stream closed user_id {:?}", user_id);
+        return None;
+    }
+
+    loop {
+        match receiver.recv().await {
+            Ok(message) if message.is_for(user_id) => {
+                let logout = message.event == EVENT_LOGOUT;
+                let event = Event::default().event(&message.event).data(message.data.to_string());
+                return Some((Ok(event), (receiver, user_id, logout)));
+            }
+            Ok(_) => continue,
+            Err(RecvError::Lagged(n)) => log::warn!("message stream user_id {:?} lagged {} messages", user_id, n),
+            Err(RecvError::Closed) => return None,
+        }
+    }
+}
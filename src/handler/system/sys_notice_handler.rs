@@ -1,13 +1,20 @@
 use crate::common::error::AppError;
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
-use crate::model::system::sys_notice_model::Notice;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::model::system::sys_notice_model::{
+    count_notice_target, count_user_notice, count_user_unread_by_type, select_notice_target_user_ids, select_user_notice_list, Notice, MY_NOTICE_SORT, NOTICE_SORT,
+};
+use crate::model::system::sys_notice_read_model::{count_notice_read, count_notice_read_user, read_user_notice, select_notice_read_user_list, NoticeRead, NOTICE_READ_USER_SORT};
+use crate::utils::message_util::{send_message, EVENT_NOTICE};
 use crate::vo::system::sys_notice_vo::*;
 use crate::AppState;
 use axum::extract::State;
+use axum::http::HeaderMap;
 use axum::response::IntoResponse;
-use axum::Json;
 use log::info;
-use rbatis::plugin::page::PageRequest;
+use rbatis::rbatis_codegen::ops::AsProxy;
+use rbatis::rbdc::DateTime;
 use rbs::value;
 use std::sync::Arc;
 /*
@@ -15,16 +22,32 @@ use std::sync::Arc;
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn add_sys_notice(State(state): State<Arc<AppState>>, Json(mut item): Json<NoticeReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/addNotice",
+    tag = "通知公告",
+    summary = "添加通知公告表",
+    request_body = NoticeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn add_sys_notice(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<NoticeReq>) -> impl IntoResponse {
     info!("add sys_notice params: {:?}", &item);
     let rb = &state.batis;
 
     if Notice::select_by_title(rb, &item.notice_title).await?.is_some() {
-        return Err(AppError::BusinessError("公告标题已存在"));
+        return Err(AppError::BusinessError(ErrorCode::NoticeTitleExists));
     };
 
+    check_notice_time(&item)?;
+
     item.id = None;
-    Notice::insert(rb, &Notice::from(item)).await.map(|_| ok_result())?
+    let notice = build_notice(item);
+    let id = Notice::insert(rb, &notice).await?.last_insert_id;
+
+    if notice.status == 1 {
+        push_notice(&state, id.i64()).await;
+    }
+    ok_result()
 }
 
 /*
@@ -32,10 +55,19 @@ pub async fn add_sys_notice(State(state): State<Arc<AppState>>, Json(mut item):
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn delete_sys_notice(State(state): State<Arc<AppState>>, Json(item): Json<DeleteNoticeReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/deleteNotice",
+    tag = "通知公告",
+    summary = "删除通知公告表",
+    request_body = DeleteNoticeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_notice(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteNoticeReq>) -> impl IntoResponse {
     info!("delete sys_notice params: {:?}", &item);
     let rb = &state.batis;
 
+    NoticeRead::delete_by_map(rb, value! {"notice_id": &item.ids}).await?;
     Notice::delete_by_map(rb, value! {"id": &item.ids}).await.map(|_| ok_result())?
 }
 
@@ -44,22 +76,32 @@ pub async fn delete_sys_notice(State(state): State<Arc<AppState>>, Json(item): J
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn update_sys_notice(State(state): State<Arc<AppState>>, Json(item): Json<NoticeReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/updateNotice",
+    tag = "通知公告",
+    summary = "更新通知公告表",
+    request_body = NoticeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_notice(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<NoticeReq>) -> impl IntoResponse {
     info!("update sys_notice params: {:?}", &item);
     let rb = &state.batis;
 
     let id = item.id;
     if Notice::select_by_id(rb, &id.unwrap_or_default()).await?.is_none() {
-        return Err(AppError::BusinessError("通知公告表不存在"));
+        return Err(AppError::BusinessError(ErrorCode::NoticeNotFound));
     }
 
     if let Some(x) = Notice::select_by_title(rb, &item.notice_title).await? {
         if x.id != id {
-            return Err(AppError::BusinessError("公告标题已存在"));
+            return Err(AppError::BusinessError(ErrorCode::NoticeTitleExists));
         }
     }
 
-    Notice::update_by_map(rb, &Notice::from(item), value! {"id": &id}).await.map(|_| ok_result())?
+    check_notice_time(&item)?;
+
+    Notice::update_by_map(rb, &build_notice(item), value! {"id": &id}).await.map(|_| ok_result())?
 }
 
 /*
@@ -67,16 +109,35 @@ pub async fn update_sys_notice(State(state): State<Arc<AppState>>, Json(item): J
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn update_sys_notice_status(State(state): State<Arc<AppState>>, Json(item): Json<UpdateNoticeStatusReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/updateNoticeStatus",
+    tag = "通知公告",
+    summary = "更新通知公告表状态",
+    request_body = UpdateNoticeStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_notice_status(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateNoticeStatusReq>) -> impl IntoResponse {
     info!("update sys_notice_status params: {:?}", &item);
     let rb = &state.batis;
 
-    let update_sql = format!("update sys_notice set status = ? where id in ({})", item.ids.iter().map(|_| "?").collect::<Vec<&str>>().join(", "));
+    // 手动修改状态后不再等待定时发布
+    let update_sql = format!(
+        "update sys_notice set status = ?, scheduled = 0 where id in ({})",
+        item.ids.iter().map(|_| "?").collect::<Vec<&str>>().join(", ")
+    );
 
     let mut param = vec![value!(item.status)];
     param.extend(item.ids.iter().map(|&id| value!(id)));
 
-    rb.exec(&update_sql, param).await.map(|_| ok_result())?
+    rb.exec(&update_sql, param).await?;
+
+    if item.status == 1 {
+        for id in item.ids {
+            push_notice(&state, id).await;
+        }
+    }
+    ok_result()
 }
 
 /*
@@ -84,12 +145,20 @@ pub async fn update_sys_notice_status(State(state): State<Arc<AppState>>, Json(i
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_notice_detail(State(state): State<Arc<AppState>>, Json(item): Json<QueryNoticeDetailReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/queryNoticeDetail",
+    tag = "通知公告",
+    summary = "查询通知公告表详情",
+    request_body = QueryNoticeDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<NoticeResp>))
+)]
+pub async fn query_sys_notice_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryNoticeDetailReq>) -> impl IntoResponse {
     info!("query sys_notice_detail params: {:?}", &item);
     let rb = &state.batis;
 
     Notice::select_by_id(rb, &item.id).await?.map_or_else(
-        || Err(AppError::BusinessError("通知公告表不存在")),
+        || Err(AppError::BusinessError(ErrorCode::NoticeNotFound)),
         |x| {
             let notice: NoticeResp = x.into();
             ok_result_data(notice)
@@ -102,13 +171,271 @@ pub async fn query_sys_notice_detail(State(state): State<Arc<AppState>>, Json(it
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_notice_list(State(state): State<Arc<AppState>>, Json(item): Json<QueryNoticeListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/queryNoticeList",
+    tag = "通知公告",
+    summary = "查询通知公告表列表",
+    request_body = QueryNoticeListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<NoticeResp>>))
+)]
+pub async fn query_sys_notice_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryNoticeListReq>) -> impl IntoResponse {
     info!("query sys_notice_list params: {:?}", &item);
     let rb = &state.batis;
 
-    let page = &PageRequest::new(item.page_no, item.page_size);
+    item.page.sort(&NOTICE_SORT)?;
+    let page = &item.page.page_request();
 
     Notice::select_sys_notice_list(rb, page, &item)
         .await
-        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<NoticeResp>>(), x.total))?
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<NoticeResp>>(), x.total, &item.page))?
+}
+
+/*
+ *校验通知公告过期时间(开启时过期时间必须晚于当前时间)
+ *author：刘飞华
+ *date：2026/10/18 16:05:42
+ */
+fn check_notice_time(item: &NoticeReq) -> Result<(), AppError> {
+    if item.status == 1 && item.expire_time.as_ref().is_some_and(|x| !x.after(&DateTime::now())) {
+        return Err(AppError::BusinessError(ErrorCode::ExpireTimeInvalid));
+    }
+    Ok(())
+}
+
+/*
+ *构建通知公告,发布时间晚于当前时间时先保存为关闭状态,由定时任务到期发布
+ *author：刘飞华
+ *date：2026/10/18 16:05:42
+ */
+fn build_notice(item: NoticeReq) -> Notice {
+    let mut notice = Notice::from(item);
+    if notice.status == 1 && notice.publish_time.as_ref().is_some_and(|x| x.after(&DateTime::now())) {
+        notice.status = 0;
+        notice.scheduled = 1;
+    }
+    notice
+}
+
+/*
+ *推送新的通知公告给接收用户
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+pub async fn push_notice(state: &AppState, notice_id: i64) {
+    let rb = &state.batis;
+
+    let notice = match Notice::select_by_id(rb, &notice_id).await {
+        Ok(Some(x)) => x,
+        Ok(None) => return,
+        Err(e) => {
+            log::error!("push notice error notice_id: {}, error message: {:?}", notice_id, e);
+            return;
+        }
+    };
+
+    // 全部用户时广播,否则只推送给接收范围内的用户
+    let user_ids = if notice.target_type == 0 {
+        Vec::new()
+    } else {
+        match select_notice_target_user_ids(rb, notice_id).await {
+            Ok(ids) if !ids.is_empty() => ids,
+            Ok(_) => return,
+            Err(e) => {
+                log::error!("push notice error notice_id: {}, error message: {:?}", notice_id, e);
+                return;
+            }
+        }
+    };
+
+    let data = serde_json::json!({
+        "id": notice.id,
+        "noticeTitle": notice.notice_title,
+        "noticeType": notice.notice_type,
+        "createTime": notice.create_time,
+    });
+    send_message(state, EVENT_NOTICE, user_ids, data);
+}
+
+/*
+ *发送系统通知(如登录安全提醒),发送失败时只记录日志
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+pub async fn send_system_notice(state: &AppState, title: &str, content: &str, target_type: i8, target_ids: &[i64]) {
+    let notice = Notice {
+        id: None,                                                                                //公告ID
+        notice_title: title.chars().take(50).collect(),                                          //公告标题
+        notice_type: 1,                                                                          //公告类型（1:通知,2:公告）
+        notice_content: content.chars().take(255).collect(),                                     //公告内容
+        status: 1,                                                                               //公告状态（0:关闭,1:正常 ）
+        target_type,                                                                             //发送范围（0:全部用户,1:指定部门,2:指定角色,3:指定用户）
+        target_ids: target_ids.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(","), //发送对象id(逗号分隔)
+        publish_time: None,                                                                      //定时发布时间
+        expire_time: None,                                                                       //过期时间(到期后自动关闭)
+        scheduled: 0,                                                                            //是否等待定时发布(0:否,1:是)
+        remark: Some("系统通知".to_string()),                                                    //备注
+        create_time: Some(DateTime::now()),                                                      //创建时间
+        update_time: None,                                                                       //修改时间
+    };
+
+    match Notice::insert(&state.batis, &notice).await {
+        Ok(x) => push_notice(state, x.last_insert_id.i64()).await,
+        Err(e) => log::error!("send system notice error params: {:?}, error message: {:?}", notice, e),
+    }
+}
+
+/*
+ *查询我的通知公告列表
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/queryMyNoticeList",
+    tag = "通知公告",
+    summary = "查询我的通知公告列表",
+    request_body = QueryMyNoticeListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<MyNoticeResp>>))
+)]
+pub async fn query_my_notice_list(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryMyNoticeListReq>) -> impl IntoResponse {
+    info!("query my_notice_list params: {:?}", &item);
+    let rb = &state.batis;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    item.page.sort(&MY_NOTICE_SORT)?;
+
+    let list = select_user_notice_list(rb, user_id, &item).await?;
+    let total = count_user_notice(rb, user_id, &item).await?;
+
+    ok_result_page(list.into_iter().map(|x| x.into()).collect::<Vec<MyNoticeResp>>(), total, &item.page)
+}
+
+/*
+ *查询我的未读通知公告数量
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/notice/queryMyUnreadCount",
+    tag = "通知公告",
+    summary = "查询我的未读通知公告数量",
+    responses((status = 200, description = "操作成功", body = BaseResponse<MyUnreadCountResp>))
+)]
+pub async fn query_my_unread_count(headers: HeaderMap, State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    info!("query my_unread_count params user_id {:?}", user_id);
+    let rb = &state.batis;
+
+    let mut resp = MyUnreadCountResp { total: 0, notice: 0, announcement: 0 };
+
+    for x in count_user_unread_by_type(rb, user_id).await? {
+        match x.notice_type {
+            1 => resp.notice += x.count,
+            2 => resp.announcement += x.count,
+            _ => {}
+        }
+        resp.total += x.count;
+    }
+
+    ok_result_data(resp)
+}
+
+/*
+ *标记通知公告为已读
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/readNotice",
+    tag = "通知公告",
+    summary = "标记通知公告为已读",
+    request_body = ReadNoticeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn read_notice(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<ReadNoticeReq>) -> impl IntoResponse {
+    info!("read notice params: {:?}", &item);
+    let rb = &state.batis;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+
+    read_user_notice(rb, user_id, &item.ids).await.map(|_| ok_result())?
+}
+
+/*
+ *标记全部通知公告为已读
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/readAllNotice",
+    tag = "通知公告",
+    summary = "标记全部通知公告为已读",
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn read_all_notice(headers: HeaderMap, State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    info!("read all notice params user_id {:?}", user_id);
+    let rb = &state.batis;
+
+    read_user_notice(rb, user_id, &[]).await.map(|_| ok_result())?
+}
+
+/*
+ *查询通知公告阅读统计
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/queryNoticeReadStats",
+    tag = "通知公告",
+    summary = "查询通知公告阅读统计",
+    request_body = QueryNoticeReadStatsReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<NoticeReadStatsResp>))
+)]
+pub async fn query_notice_read_stats(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryNoticeReadStatsReq>) -> impl IntoResponse {
+    info!("query notice_read_stats params: {:?}", &item);
+    let rb = &state.batis;
+
+    if Notice::select_by_id(rb, &item.id).await?.is_none() {
+        return Err(AppError::BusinessError(ErrorCode::NoticeNotFound));
+    }
+
+    let target_count = count_notice_target(rb, item.id).await?;
+    let read_count = count_notice_read(rb, item.id).await?;
+
+    ok_result_data(NoticeReadStatsResp {
+        notice_id: item.id,
+        target_count,
+        read_count,
+        unread_count: target_count.saturating_sub(read_count),
+    })
+}
+
+/*
+ *查询通知公告已读用户列表
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/notice/queryNoticeReadUserList",
+    tag = "通知公告",
+    summary = "查询通知公告已读用户列表",
+    request_body = QueryNoticeReadUserListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<NoticeReadUserResp>>))
+)]
+pub async fn query_notice_read_user_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryNoticeReadUserListReq>) -> impl IntoResponse {
+    info!("query notice_read_user_list params: {:?}", &item);
+    let rb = &state.batis;
+
+    item.page.sort(&NOTICE_READ_USER_SORT)?;
+    let list = select_notice_read_user_list(rb, &item).await?;
+    let total = count_notice_read_user(rb, &item).await?;
+
+    ok_result_page(list.into_iter().map(|x| x.into()).collect::<Vec<NoticeReadUserResp>>(), total, &item.page)
 }
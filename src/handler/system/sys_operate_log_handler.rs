@@ -1,13 +1,14 @@
 use crate::common::error::AppError;
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
-use crate::model::system::sys_operate_log_model::{clean_operate_log, OperateLog};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_cursor_page, ok_result_data, ok_result_page, BaseResponse, ResponseCursorPage, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::model::system::sys_operate_log_model::{clean_operate_log, count_operate_log_estimate, OperateLog, OPERATE_LOG_SORT};
+use crate::utils::log_archive_util::purge_log;
 use crate::vo::system::sys_operate_log_vo::*;
 use crate::AppState;
 use axum::extract::State;
 use axum::response::IntoResponse;
-use axum::Json;
 use log::info;
-use rbatis::plugin::page::PageRequest;
 use rbs::value;
 use std::sync::Arc;
 /*
@@ -15,7 +16,15 @@ use std::sync::Arc;
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn delete_sys_operate_log(State(state): State<Arc<AppState>>, Json(item): Json<DeleteOperateLogReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/operateLog/deleteOperateLog",
+    tag = "操作日志记录",
+    summary = "删除操作日志记录",
+    request_body = DeleteOperateLogReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_operate_log(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteOperateLogReq>) -> impl IntoResponse {
     info!("delete sys_operate_log params: {:?}", &item);
     let rb = &state.batis;
 
@@ -27,6 +36,13 @@ pub async fn delete_sys_operate_log(State(state): State<Arc<AppState>>, Json(ite
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
+#[utoipa::path(
+    get,
+    path = "/api/system/operateLog/cleanOperateLog",
+    tag = "操作日志记录",
+    summary = "清空操作日志记录",
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
 pub async fn clean_sys_operate_log(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     info!("clean sys_operate_log");
     let rb = &state.batis;
@@ -34,17 +50,47 @@ pub async fn clean_sys_operate_log(State(state): State<Arc<AppState>>) -> impl I
     clean_operate_log(rb).await.map(|_| ok_result())?
 }
 
+/*
+ *按时间范围删除操作日志记录(开启归档时先归档)
+ *author：刘飞华
+ *date：2026/10/18 17:20:33
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/operateLog/deleteOperateLogByTime",
+    tag = "操作日志记录",
+    summary = "按时间范围删除操作日志记录(开启归档时先归档)",
+    request_body = DeleteOperateLogByTimeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_operate_log_by_time(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteOperateLogByTimeReq>) -> impl IntoResponse {
+    info!("delete sys_operate_log_by_time params: {:?}", &item);
+
+    let where_sql = "operate_time >= ? and operate_time <= ?";
+    let params = vec![value!(item.begin_time), value!(item.end_time)];
+
+    purge_log::<OperateLog>(&state, "sys_operate_log", where_sql, params, |x| x.id).await.map(ok_result_data)?
+}
+
 /*
  *查询操作日志记录详情
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_operate_log_detail(State(state): State<Arc<AppState>>, Json(item): Json<QueryOperateLogDetailReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/operateLog/queryOperateLogDetail",
+    tag = "操作日志记录",
+    summary = "查询操作日志记录详情",
+    request_body = QueryOperateLogDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<OperateLogResp>))
+)]
+pub async fn query_sys_operate_log_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryOperateLogDetailReq>) -> impl IntoResponse {
     info!("query sys_operate_log_detail params: {:?}", &item);
     let rb = &state.batis;
 
     OperateLog::select_by_id(rb, &item.id).await?.map_or_else(
-        || Err(AppError::BusinessError("操作日志不存在")),
+        || Err(AppError::BusinessError(ErrorCode::OperateLogNotFound)),
         |x| {
             let data: OperateLogResp = x.into();
             ok_result_data(data)
@@ -56,12 +102,46 @@ pub async fn query_sys_operate_log_detail(State(state): State<Arc<AppState>>, Js
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_operate_log_list(State(state): State<Arc<AppState>>, Json(item): Json<QueryOperateLogListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/operateLog/queryOperateLogList",
+    tag = "操作日志记录",
+    summary = "查询操作日志记录列表",
+    request_body = QueryOperateLogListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<OperateLogResp>>))
+)]
+pub async fn query_sys_operate_log_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryOperateLogListReq>) -> impl IntoResponse {
     info!("query sys_operate_log_list params: {:?}", &item);
     let rb = &state.batis;
 
-    let page = &PageRequest::new(item.page_no, item.page_size);
+    item.page.sort(&OPERATE_LOG_SORT)?;
+    let page = &item.page.page_request();
     OperateLog::select_page_by_name(rb, page, &item)
         .await
-        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<OperateLogResp>>(), x.total))?
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<OperateLogResp>>(), x.total, &item.page))?
+}
+
+/*
+ *游标分页查询操作日志记录列表(不统计总数,适合数据量大时翻页)
+ *author：刘飞华
+ *date：2026/10/19 05:12:40
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/operateLog/queryOperateLogCursorList",
+    tag = "操作日志记录",
+    summary = "游标分页查询操作日志记录列表(不统计总数,适合数据量大时翻页)",
+    request_body = QueryOperateLogCursorListReq,
+    responses((status = 200, description = "操作成功", body = ResponseCursorPage<Vec<OperateLogResp>>))
+)]
+pub async fn query_sys_operate_log_cursor_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryOperateLogCursorListReq>) -> impl IntoResponse {
+    info!("query sys_operate_log_cursor_list params: {:?}", &item);
+    let rb = &state.batis;
+
+    item.page.decode()?;
+    let list = OperateLog::select_operate_log_cursor_list(rb, &item).await?;
+    let (list, next_cursor) = item.page.next_page(list, |x| (x.operate_time.as_ref(), x.id));
+
+    let total = if item.page.with_total { Some(count_operate_log_estimate(rb).await?) } else { None };
+    ok_result_cursor_page(list.into_iter().map(|x| x.into()).collect::<Vec<OperateLogResp>>(), next_cursor, total)
 }
@@ -1,14 +1,14 @@
 use crate::common::error::AppError;
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
-use crate::model::system::sys_post_model::Post;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::model::system::sys_post_model::{Post, POST_SORT};
 use crate::model::system::sys_user_post_model::count_user_post_by_id;
 use crate::vo::system::sys_post_vo::*;
 use crate::AppState;
 use axum::extract::State;
 use axum::response::IntoResponse;
-use axum::Json;
 use log::info;
-use rbatis::plugin::page::PageRequest;
 use rbs::value;
 use std::sync::Arc;
 /*
@@ -16,16 +16,24 @@ use std::sync::Arc;
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn add_sys_post(State(state): State<Arc<AppState>>, Json(mut item): Json<PostReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/post/addPost",
+    tag = "岗位信息",
+    summary = "添加岗位信息表",
+    request_body = PostReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn add_sys_post(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<PostReq>) -> impl IntoResponse {
     info!("add sys_post params: {:?}", &item);
     let rb = &state.batis;
 
     if Post::select_by_name(rb, &item.post_name).await?.is_some() {
-        return Err(AppError::BusinessError("岗位名称已存在"));
+        return Err(AppError::BusinessError(ErrorCode::PostNameExists));
     }
 
     if Post::select_by_code(rb, &item.post_code).await?.is_some() {
-        return Err(AppError::BusinessError("岗位编码已存在"));
+        return Err(AppError::BusinessError(ErrorCode::PostCodeExists));
     }
 
     item.id = None;
@@ -37,14 +45,22 @@ pub async fn add_sys_post(State(state): State<Arc<AppState>>, Json(mut item): Js
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn delete_sys_post(State(state): State<Arc<AppState>>, Json(item): Json<DeletePostReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/post/deletePost",
+    tag = "岗位信息",
+    summary = "删除岗位信息表",
+    request_body = DeletePostReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_post(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeletePostReq>) -> impl IntoResponse {
     info!("delete sys_post params: {:?}", &item);
     let rb = &state.batis;
 
     let ids = item.ids.clone();
     for id in ids {
         if count_user_post_by_id(rb, id).await? > 0 {
-            return Err(AppError::BusinessError("已分配,不能删除"));
+            return Err(AppError::BusinessError(ErrorCode::PostInUse));
         }
     }
 
@@ -56,25 +72,33 @@ pub async fn delete_sys_post(State(state): State<Arc<AppState>>, Json(item): Jso
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn update_sys_post(State(state): State<Arc<AppState>>, Json(item): Json<PostReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/post/updatePost",
+    tag = "岗位信息",
+    summary = "更新岗位信息表",
+    request_body = PostReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_post(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<PostReq>) -> impl IntoResponse {
     info!("update sys_post params: {:?}", &item);
     let rb = &state.batis;
 
     let id = item.id;
 
     if Post::select_by_id(rb, &id.unwrap_or_default()).await?.is_none() {
-        return Err(AppError::BusinessError("岗位不存在"));
+        return Err(AppError::BusinessError(ErrorCode::PostNotFound));
     }
 
     if let Some(x) = Post::select_by_name(rb, &item.post_name).await? {
         if x.id != id {
-            return Err(AppError::BusinessError("岗位名称已存在"));
+            return Err(AppError::BusinessError(ErrorCode::PostNameExists));
         }
     }
 
     if let Some(x) = Post::select_by_code(rb, &item.post_code).await? {
         if x.id != id {
-            return Err(AppError::BusinessError("岗位编码已存在"));
+            return Err(AppError::BusinessError(ErrorCode::PostCodeExists));
         }
     }
 
@@ -86,7 +110,15 @@ pub async fn update_sys_post(State(state): State<Arc<AppState>>, Json(item): Jso
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn update_sys_post_status(State(state): State<Arc<AppState>>, Json(item): Json<UpdatePostStatusReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/post/updatePostStatus",
+    tag = "岗位信息",
+    summary = "更新岗位信息表状态",
+    request_body = UpdatePostStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_post_status(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdatePostStatusReq>) -> impl IntoResponse {
     info!("update sys_post_status params: {:?}", &item);
     let rb = &state.batis;
 
@@ -102,12 +134,20 @@ pub async fn update_sys_post_status(State(state): State<Arc<AppState>>, Json(ite
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_post_detail(State(state): State<Arc<AppState>>, Json(item): Json<QueryPostDetailReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/post/queryPostDetail",
+    tag = "岗位信息",
+    summary = "查询岗位信息表详情",
+    request_body = QueryPostDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<PostResp>))
+)]
+pub async fn query_sys_post_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryPostDetailReq>) -> impl IntoResponse {
     info!("query sys_post_detail params: {:?}", &item);
     let rb = &state.batis;
 
     Post::select_by_id(rb, &item.id).await?.map_or_else(
-        || Err(AppError::BusinessError("岗位不存在")),
+        || Err(AppError::BusinessError(ErrorCode::PostNotFound)),
         |x| {
             let data: PostResp = x.into();
             ok_result_data(data)
@@ -120,13 +160,22 @@ pub async fn query_sys_post_detail(State(state): State<Arc<AppState>>, Json(item
  *author：刘飞华
  *date：2024/12/25 11:36:48
  */
-pub async fn query_sys_post_list(State(state): State<Arc<AppState>>, Json(item): Json<QueryPostListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/post/queryPostList",
+    tag = "岗位信息",
+    summary = "查询岗位信息表列表",
+    request_body = QueryPostListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<PostResp>>))
+)]
+pub async fn query_sys_post_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryPostListReq>) -> impl IntoResponse {
     info!("query sys_post_list params: {:?}", &item);
     let rb = &state.batis;
 
-    let page = &PageRequest::new(item.page_no, item.page_size);
+    item.page.sort(&POST_SORT)?;
+    let page = &item.page.page_request();
 
     Post::select_post_list(rb, page, &item)
         .await
-        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<PostResp>>(), x.total))?
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<PostResp>>(), x.total, &item.page))?
 }
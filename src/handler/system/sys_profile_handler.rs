@@ -0,0 +1,293 @@
+use crate::common::error::AppError;
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, BaseResponse};
+use crate::common::valid::ValidJson;
+use crate::i18n::Locale;
+use crate::model::system::sys_dept_model::Dept;
+use crate::model::system::sys_post_model::Post;
+use crate::model::system::sys_role_model::Role;
+use crate::model::system::sys_user_model::User;
+use crate::model::system::sys_user_post_model::UserPost;
+use crate::model::system::sys_user_role_model::UserRole;
+use crate::vo::system::sys_post_vo::PostResp;
+use crate::vo::system::sys_role_vo::RoleResp;
+use crate::vo::system::sys_user_vo::*;
+use crate::AppState;
+use axum::extract::{Multipart, Path, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use log::info;
+use rbatis::rbdc::DateTime;
+use rbs::value;
+use redis::Commands;
+use std::sync::Arc;
+
+// 头像访问地址前缀(头像文件名是随机的,不需要登录就可以访问)
+pub const AVATAR_PATH: &str = "/system/user/profile/avatar/";
+// 头像在文件存储中的目录
+const AVATAR_DIR: &str = "avatar";
+// 头像文件最大2M
+pub const AVATAR_MAX_SIZE: usize = 2 * 1024 * 1024;
+
+/*
+ *查询个人信息(包含部门、岗位和角色)
+ *author：刘飞华
+ *date：2026/10/18 20:12:40
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/user/profile",
+    tag = "个人中心",
+    summary = "查询个人信息(包含部门、岗位和角色)",
+    responses((status = 200, description = "操作成功", body = BaseResponse<ProfileResp>))
+)]
+pub async fn query_profile(headers: HeaderMap, State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let rb = &state.batis;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    info!("query profile params: {:?}", user_id);
+
+    let mut user: UserResp = match User::select_by_id(rb, user_id).await? {
+        None => return Err(AppError::BusinessError(ErrorCode::UserNotFound)),
+        Some(x) => x.into(),
+    };
+
+    user.dept_info = Dept::select_by_id(rb, &user.dept_id).await?.map(|x| x.into());
+
+    let post_ids = UserPost::select_by_map(rb, value! {"user_id": user_id}).await?.iter().map(|x| x.post_id).collect::<Vec<i64>>();
+    let post_list = if post_ids.is_empty() {
+        Vec::new()
+    } else {
+        Post::select_by_map(rb, value! {"id": &post_ids}).await?.into_iter().map(|x| x.into()).collect::<Vec<PostResp>>()
+    };
+    user.post_ids = Some(post_ids);
+
+    let role_ids = UserRole::select_by_map(rb, value! {"user_id": user_id}).await?.iter().map(|x| x.role_id).collect::<Vec<i64>>();
+    let role_list = if role_ids.is_empty() {
+        Vec::new()
+    } else {
+        Role::select_by_map(rb, value! {"id": &role_ids}).await?.into_iter().map(|x| x.into()).collect::<Vec<RoleResp>>()
+    };
+
+    ok_result_data(ProfileResp { user, post_list, role_list })
+}
+
+/*
+ *修改个人信息(昵称、邮箱、手机号码)
+ *author：刘飞华
+ *date：2026/10/18 20:12:40
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/profile/updateProfile",
+    tag = "个人中心",
+    summary = "修改个人信息(昵称、邮箱、手机号码)",
+    request_body = UpdateProfileReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_profile(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateProfileReq>) -> impl IntoResponse {
+    info!("update profile params: {:?}", &item);
+    let rb = &state.batis;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+
+    let mut user = match User::select_by_id(rb, user_id).await? {
+        None => return Err(AppError::BusinessError(ErrorCode::UserNotFound)),
+        Some(x) => x,
+    };
+
+    if item.nick_name.trim().is_empty() {
+        return Err(AppError::BusinessError(ErrorCode::NickNameRequired));
+    }
+
+    if let Some(x) = User::select_by_mobile(rb, &item.mobile).await? {
+        if !item.mobile.is_empty() && x.id != user.id {
+            return Err(AppError::BusinessError(ErrorCode::UserMobileExists));
+        }
+    }
+
+    if let Some(x) = User::select_by_email(rb, &item.email).await? {
+        if !item.email.is_empty() && x.id != user.id {
+            return Err(AppError::BusinessError(ErrorCode::UserEmailExists));
+        }
+    }
+
+    user.nick_name = item.nick_name;
+    user.email = item.email;
+    user.mobile = item.mobile;
+    user.update_time = Some(DateTime::now());
+
+    User::update_by_map(rb, &user, value! {"id": &user.id}).await.map(|_| ok_result())?
+}
+
+/*
+ *修改语言偏好(为空时根据请求头Accept-Language)
+ *author：刘飞华
+ *date：2026/10/19 02:48:30
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/profile/updateLocale",
+    tag = "个人中心",
+    summary = "修改语言偏好(为空时根据请求头Accept-Language)",
+    request_body = UpdateLocaleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_locale(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateLocaleReq>) -> impl IntoResponse {
+    info!("update locale params: {:?}", &item);
+    let rb = &state.batis;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+
+    let locale = match item.locale.trim() {
+        "" => "",
+        x => match Locale::parse(x) {
+            Some(locale) => locale.tag(),
+            None => return Err(AppError::BusinessError(ErrorCode::LocaleUnsupported)),
+        },
+    };
+
+    let mut user = match User::select_by_id(rb, user_id).await? {
+        None => return Err(AppError::BusinessError(ErrorCode::UserNotFound)),
+        Some(x) => x,
+    };
+    user.locale = locale.to_string();
+    user.update_time = Some(DateTime::now());
+    User::update_by_map(rb, &user, value! {"id": &user.id}).await?;
+
+    // 同步到登录信息,下一个请求生效
+    let mut conn = state.redis.get_connection()?;
+    conn.hset::<_, _, _, ()>(format!("axum:admin:user:info:{}", user_id), "locale", locale)?;
+
+    ok_result()
+}
+
+/*
+ *上传头像(multipart表单字段: avatar,支持png、jpg、gif、webp格式)
+ *author：刘飞华
+ *date：2026/10/18 20:12:40
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/profile/uploadAvatar",
+    tag = "个人中心",
+    summary = "上传头像",
+    request_body(content_type = "multipart/form-data", description = "头像图片(表单字段: avatar)"),
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn upload_avatar(headers: HeaderMap, State(state): State<Arc<AppState>>, mut multipart: Multipart) -> impl IntoResponse {
+    let rb = &state.batis;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    info!("upload avatar params: {:?}", user_id);
+
+    let mut data = None;
+    while let Some(field) = multipart.next_field().await.map_err(|e| AppError::ValidationError(e.to_string()))? {
+        if field.name() == Some("avatar") {
+            data = Some(field.bytes().await.map_err(|e| AppError::ValidationError(e.to_string()))?);
+            break;
+        }
+    }
+
+    let data = match data {
+        None => return Err(AppError::BusinessError(ErrorCode::AvatarRequired)),
+        Some(x) => x,
+    };
+    if data.len() > AVATAR_MAX_SIZE {
+        return Err(AppError::BusinessError(ErrorCode::AvatarTooLarge));
+    }
+
+    // 根据文件内容判断格式,不信任文件名
+    let ext = match image_ext(&data) {
+        None => return Err(AppError::BusinessError(ErrorCode::AvatarTypeUnsupported)),
+        Some(x) => x,
+    };
+
+    let mut user = match User::select_by_id(rb, user_id).await? {
+        None => return Err(AppError::BusinessError(ErrorCode::UserNotFound)),
+        Some(x) => x,
+    };
+
+    let file_name = format!("{}_{:016x}.{}", user_id, rand::random::<u64>(), ext);
+    state.storage.put(&format!("{}/{}", AVATAR_DIR, file_name), data, image_content_type(ext)).await?;
+
+    let old_avatar = user.avatar.replace(format!("/api{}{}", AVATAR_PATH, file_name));
+    user.update_time = Some(DateTime::now());
+    User::update_by_map(rb, &user, value! {"id": &user.id}).await?;
+
+    // 删除旧的头像文件
+    if let Some(old_file) = old_avatar.as_deref().and_then(|x| x.strip_prefix("/api")).and_then(|x| x.strip_prefix(AVATAR_PATH)) {
+        if is_valid_file_name(old_file) {
+            if let Err(e) = state.storage.delete(&format!("{}/{}", AVATAR_DIR, old_file)).await {
+                log::warn!("remove old avatar error file: {}, error message: {:?}", old_file, e);
+            }
+        }
+    }
+
+    ok_result_data(user.avatar.unwrap_or_default())
+}
+
+/*
+ *查看头像
+ *author：刘飞华
+ *date：2026/10/18 20:12:40
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/user/profile/avatar/{file_name}",
+    tag = "个人中心",
+    summary = "查看头像",
+    security(()),
+    params(("file_name" = String, Path, description = "头像文件名")),
+    responses((status = 200, description = "头像图片", content_type = "image/*"))
+)]
+pub async fn query_avatar(State(state): State<Arc<AppState>>, Path(file_name): Path<String>) -> Response {
+    if !is_valid_file_name(&file_name) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    let content_type = match file_name.rsplit_once('.').map(|(_, ext)| image_content_type(ext)) {
+        Some(x) if !x.is_empty() => x,
+        _ => return StatusCode::NOT_FOUND.into_response(),
+    };
+
+    match state.storage.get(&format!("{}/{}", AVATAR_DIR, file_name), None).await {
+        Ok(body) => Response::builder()
+            .header(header::CONTENT_TYPE, content_type)
+            .header(header::CACHE_CONTROL, "public, max-age=86400")
+            .body(body)
+            .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response()),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+// 根据文件头判断图片格式
+fn image_ext(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("png")
+    } else if data.starts_with(&[0xff, 0xd8, 0xff]) {
+        Some("jpg")
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some("gif")
+    } else if data.len() > 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        Some("webp")
+    } else {
+        None
+    }
+}
+
+// 图片格式对应的Content-Type
+fn image_content_type(ext: &str) -> &'static str {
+    match ext {
+        "png" => "image/png",
+        "jpg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        _ => "",
+    }
+}
+
+// 文件名只允许字母、数字、下划线和点,防止访问头像目录以外的文件
+fn is_valid_file_name(file_name: &str) -> bool {
+    !file_name.is_empty() && !file_name.starts_with('.') && !file_name.contains("..") && file_name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
@@ -1,19 +1,20 @@
 use crate::common::error::AppError;
-use crate::common::result::{ok_result, ok_result_data, ok_result_page};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::handler::system::sys_user_handler::refresh_user_permission;
 use crate::model::system::sys_menu_model::Menu;
 use crate::model::system::sys_role_dept_model::RoleDept;
 use crate::model::system::sys_role_menu_model::{query_menu_by_role, RoleMenu};
-use crate::model::system::sys_role_model::Role;
-use crate::model::system::sys_user_model::{count_allocated_list, count_unallocated_list, select_allocated_list, select_unallocated_list};
+use crate::model::system::sys_role_model::{Role, ROLE_SORT};
+use crate::model::system::sys_user_model::{count_allocated_list, count_unallocated_list, select_allocated_list, select_unallocated_list, ROLE_USER_SORT};
 use crate::model::system::sys_user_role_model::{count_user_role_by_role_id, delete_user_role_by_role_id_user_id, UserRole};
 use crate::vo::system::sys_role_vo::*;
 use crate::vo::system::sys_user_vo::UserResp;
 use crate::AppState;
 use axum::extract::State;
 use axum::response::IntoResponse;
-use axum::Json;
 use log::info;
-use rbatis::plugin::page::PageRequest;
 use rbatis::rbdc::DateTime;
 use rbs::value;
 use std::sync::Arc;
@@ -22,16 +23,24 @@ use std::sync::Arc;
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn add_sys_role(State(state): State<Arc<AppState>>, Json(mut item): Json<RoleReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/addRole",
+    tag = "角色信息",
+    summary = "添加角色信息",
+    request_body = RoleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn add_sys_role(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<RoleReq>) -> impl IntoResponse {
     info!("add sys_role params: {:?}", &item);
     let rb = &state.batis;
 
     if Role::select_by_role_name(rb, &item.role_name).await?.is_some() {
-        return Err(AppError::BusinessError("角色名称已存在"));
+        return Err(AppError::BusinessError(ErrorCode::RoleNameExists));
     }
 
     if Role::select_by_role_key(rb, &item.role_key).await?.is_some() {
-        return Err(AppError::BusinessError("角色权限已存在"));
+        return Err(AppError::BusinessError(ErrorCode::RoleKeyExists));
     }
 
     item.id = None;
@@ -43,23 +52,31 @@ pub async fn add_sys_role(State(state): State<Arc<AppState>>, Json(mut item): Js
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn delete_sys_role(State(state): State<Arc<AppState>>, Json(item): Json<DeleteRoleReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/deleteRole",
+    tag = "角色信息",
+    summary = "删除角色信息",
+    request_body = DeleteRoleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_role(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteRoleReq>) -> impl IntoResponse {
     info!("delete sys_role params: {:?}", &item);
     let rb = &state.batis;
 
     let ids = item.ids.clone();
 
     if ids.contains(&1) {
-        return Err(AppError::BusinessError("不允许操作超级管理员角色"));
+        return Err(AppError::BusinessError(ErrorCode::AdminRoleForbidden));
     }
 
     for id in ids {
         if let None = Role::select_by_id(rb, &id).await? {
-            return Err(AppError::BusinessError("角色不存在,不能删除"));
+            return Err(AppError::BusinessError(ErrorCode::RoleNotFound));
         }
 
         if count_user_role_by_role_id(rb, id).await? > 0 {
-            return Err(AppError::BusinessError("分配,不能删除"));
+            return Err(AppError::BusinessError(ErrorCode::RoleInUse));
         }
     }
 
@@ -73,28 +90,36 @@ pub async fn delete_sys_role(State(state): State<Arc<AppState>>, Json(item): Jso
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn update_sys_role(State(state): State<Arc<AppState>>, Json(item): Json<RoleReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/updateRole",
+    tag = "角色信息",
+    summary = "更新角色信息",
+    request_body = RoleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_role(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<RoleReq>) -> impl IntoResponse {
     info!("update sys_role params: {:?}", &item);
     let rb = &state.batis;
 
     let id = item.id;
     if id == Some(1) {
-        return Err(AppError::BusinessError("不允许操作超级管理员角色"));
+        return Err(AppError::BusinessError(ErrorCode::AdminRoleForbidden));
     }
 
     if Role::select_by_id(rb, &id.unwrap_or_default()).await?.is_none() {
-        return Err(AppError::BusinessError("角色不存在"));
+        return Err(AppError::BusinessError(ErrorCode::RoleNotFound));
     }
 
     if let Some(x) = Role::select_by_role_name(rb, &item.role_name).await? {
         if x.id != id {
-            return Err(AppError::BusinessError("角色名称已存在"));
+            return Err(AppError::BusinessError(ErrorCode::RoleNameExists));
         }
     }
 
     if let Some(x) = Role::select_by_role_key(rb, &item.role_key).await? {
         if x.id != id {
-            return Err(AppError::BusinessError("角色权限已存在"));
+            return Err(AppError::BusinessError(ErrorCode::RoleKeyExists));
         }
     }
 
@@ -106,12 +131,20 @@ pub async fn update_sys_role(State(state): State<Arc<AppState>>, Json(item): Jso
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn update_sys_role_status(State(state): State<Arc<AppState>>, Json(item): Json<UpdateRoleStatusReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/updateRoleStatus",
+    tag = "角色信息",
+    summary = "更新角色信息状态",
+    request_body = UpdateRoleStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_role_status(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateRoleStatusReq>) -> impl IntoResponse {
     info!("update sys_role_status params: {:?}", &item);
     let rb = &state.batis;
 
     if item.ids.contains(&1) {
-        return Err(AppError::BusinessError("不允许操作超级管理员角色"));
+        return Err(AppError::BusinessError(ErrorCode::AdminRoleForbidden));
     }
 
     let update_sql = format!("update sys_role set status = ? where id in ({})", item.ids.iter().map(|_| "?").collect::<Vec<&str>>().join(", "));
@@ -126,12 +159,20 @@ pub async fn update_sys_role_status(State(state): State<Arc<AppState>>, Json(ite
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn query_sys_role_detail(State(state): State<Arc<AppState>>, Json(item): Json<QueryRoleDetailReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/queryRoleDetail",
+    tag = "角色信息",
+    summary = "查询角色信息详情",
+    request_body = QueryRoleDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<RoleResp>))
+)]
+pub async fn query_sys_role_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryRoleDetailReq>) -> impl IntoResponse {
     info!("query sys_role_detail params: {:?}", &item);
     let rb = &state.batis;
 
     Role::select_by_id(rb, &item.id).await?.map_or_else(
-        || Err(AppError::BusinessError("角色不存在")),
+        || Err(AppError::BusinessError(ErrorCode::RoleNotFound)),
         |x| {
             let data: RoleResp = x.into();
             ok_result_data(data)
@@ -144,15 +185,24 @@ pub async fn query_sys_role_detail(State(state): State<Arc<AppState>>, Json(item
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn query_sys_role_list(State(state): State<Arc<AppState>>, Json(item): Json<QueryRoleListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/queryRoleList",
+    tag = "角色信息",
+    summary = "查询角色信息列表",
+    request_body = QueryRoleListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<RoleResp>>))
+)]
+pub async fn query_sys_role_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryRoleListReq>) -> impl IntoResponse {
     info!("query sys_role_list params: {:?}", &item);
     let rb = &state.batis;
 
-    let page = &PageRequest::new(item.page_no, item.page_size);
+    item.page.sort(&ROLE_SORT)?;
+    let page = &item.page.page_request();
 
     Role::select_sys_role_list(rb, page, &item)
         .await
-        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<RoleResp>>(), x.total))?
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<RoleResp>>(), x.total, &item.page))?
 }
 
 /*
@@ -160,7 +210,15 @@ pub async fn query_sys_role_list(State(state): State<Arc<AppState>>, Json(item):
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn query_role_menu(State(state): State<Arc<AppState>>, Json(item): Json<QueryRoleMenuReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/queryRoleMenu",
+    tag = "角色信息",
+    summary = "查询角色关联的菜单",
+    request_body = QueryRoleMenuReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryRoleMenuData>))
+)]
+pub async fn query_role_menu(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryRoleMenuReq>) -> impl IntoResponse {
     info!("query role_menu params: {:?}", &item);
     let rb = &state.batis;
 
@@ -202,12 +260,20 @@ pub async fn query_role_menu(State(state): State<Arc<AppState>>, Json(item): Jso
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn update_role_menu(State(state): State<Arc<AppState>>, Json(item): Json<UpdateRoleMenuReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/updateRoleMenu",
+    tag = "角色信息",
+    summary = "更新角色关联的菜单",
+    request_body = UpdateRoleMenuReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_role_menu(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateRoleMenuReq>) -> impl IntoResponse {
     info!("update role_menu params: {:?}", &item);
     let role_id = item.role_id;
 
     if role_id == 1 {
-        return Err(AppError::BusinessError("不允许操作超级管理员角色"));
+        return Err(AppError::BusinessError(ErrorCode::AdminRoleForbidden));
     }
 
     let rb = &state.batis;
@@ -226,7 +292,10 @@ pub async fn update_role_menu(State(state): State<Arc<AppState>>, Json(item): Js
         })
     }
 
-    RoleMenu::insert_batch(rb, &role_menu, item.menu_ids.len() as u64).await.map(|_| ok_result())?
+    RoleMenu::insert_batch(rb, &role_menu, item.menu_ids.len() as u64).await?;
+
+    let user_ids = UserRole::select_by_map(rb, value! {"role_id": &role_id}).await?.into_iter().map(|x| x.user_id).collect::<Vec<i64>>();
+    refresh_user_permission(&state, &user_ids).await.map(|_| ok_result())?
 }
 
 /*
@@ -234,27 +303,23 @@ pub async fn update_role_menu(State(state): State<Arc<AppState>>, Json(item): Js
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn query_allocated_list(State(state): State<Arc<AppState>>, Json(item): Json<AllocatedListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/queryAllocatedList",
+    tag = "角色信息",
+    summary = "查询已分配用户角色列表",
+    request_body = AllocatedListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<UserResp>>))
+)]
+pub async fn query_allocated_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<AllocatedListReq>) -> impl IntoResponse {
     info!("query_allocated_list params: {:?}", &item);
-
     let rb = &state.batis;
 
-    let page_no = item.page_no;
-    let page_size = item.page_size;
-    let role_id = item.role_id;
-    let mobile = item.mobile.as_deref().unwrap_or_default();
-    let user_name = item.user_name.as_deref().unwrap_or_default();
-
-    let page_no = (page_no - 1) * page_size;
-    let p = select_allocated_list(rb, role_id, user_name, mobile, page_no, page_size).await?;
+    item.page.sort(&ROLE_USER_SORT)?;
+    let list = select_allocated_list(rb, &item, item.page.offset()).await?;
+    let total = count_allocated_list(rb, &item).await?;
 
-    let mut list: Vec<UserResp> = Vec::new();
-    for x in p {
-        list.push(x.into())
-    }
-
-    let total = count_allocated_list(rb, role_id, user_name, mobile).await?;
-    ok_result_page(list, total)
+    ok_result_page(list.into_iter().map(|x| x.into()).collect::<Vec<UserResp>>(), total, &item.page)
 }
 
 /*
@@ -262,27 +327,23 @@ pub async fn query_allocated_list(State(state): State<Arc<AppState>>, Json(item)
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn query_unallocated_list(State(state): State<Arc<AppState>>, Json(item): Json<UnallocatedListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/queryUnallocatedList",
+    tag = "角色信息",
+    summary = "查询未分配用户角色列表",
+    request_body = UnallocatedListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<UserResp>>))
+)]
+pub async fn query_unallocated_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<UnallocatedListReq>) -> impl IntoResponse {
     info!("query_unallocated_list params: {:?}", &item);
-
     let rb = &state.batis;
 
-    let page_no = item.page_no;
-    let page_size = item.page_size;
-    let role_id = item.role_id;
-    let mobile = item.mobile.as_deref().unwrap_or_default();
-    let user_name = item.user_name.as_deref().unwrap_or_default();
+    item.page.sort(&ROLE_USER_SORT)?;
+    let list = select_unallocated_list(rb, &item, item.page.offset()).await?;
+    let total = count_unallocated_list(rb, &item).await?;
 
-    let page_no = (page_no - 1) * page_size;
-    let d = select_unallocated_list(rb, role_id, user_name, mobile, page_no, page_size).await?;
-
-    let mut list: Vec<UserResp> = Vec::new();
-    for x in d {
-        list.push(x.into())
-    }
-
-    let total = count_unallocated_list(rb, role_id, user_name, mobile).await?;
-    ok_result_page(list, total)
+    ok_result_page(list.into_iter().map(|x| x.into()).collect::<Vec<UserResp>>(), total, &item.page)
 }
 
 /*
@@ -290,12 +351,22 @@ pub async fn query_unallocated_list(State(state): State<Arc<AppState>>, Json(ite
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn cancel_auth_user(State(state): State<Arc<AppState>>, Json(item): Json<CancelAuthUserReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/cancelAuthUser",
+    tag = "角色信息",
+    summary = "取消授权用户",
+    request_body = CancelAuthUserReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn cancel_auth_user(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<CancelAuthUserReq>) -> impl IntoResponse {
     info!("cancel_auth_user params: {:?}", &item);
 
     let rb = &state.batis;
 
-    delete_user_role_by_role_id_user_id(rb, item.role_id, item.user_id).await.map(|_| ok_result())?
+    delete_user_role_by_role_id_user_id(rb, item.role_id, item.user_id).await?;
+
+    refresh_user_permission(&state, &[item.user_id]).await.map(|_| ok_result())?
 }
 
 /*
@@ -303,7 +374,15 @@ pub async fn cancel_auth_user(State(state): State<Arc<AppState>>, Json(item): Js
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn batch_cancel_auth_user(State(state): State<Arc<AppState>>, Json(item): Json<CancelAuthUserAllReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/batchCancelAuthUser",
+    tag = "角色信息",
+    summary = "批量取消授权用户",
+    request_body = CancelAuthUserAllReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn batch_cancel_auth_user(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<CancelAuthUserAllReq>) -> impl IntoResponse {
     info!("cancel auth_user_all params: {:?}", &item);
 
     let rb = &state.batis;
@@ -315,7 +394,9 @@ pub async fn batch_cancel_auth_user(State(state): State<Arc<AppState>>, Json(ite
 
     let mut param = vec![value!(item.role_id)];
     param.extend(item.user_ids.iter().map(|&id| value!(id)));
-    rb.exec(&update_sql, param).await.map(|_| ok_result())?
+    rb.exec(&update_sql, param).await?;
+
+    refresh_user_permission(&state, &item.user_ids).await.map(|_| ok_result())?
 }
 
 /*
@@ -323,7 +404,15 @@ pub async fn batch_cancel_auth_user(State(state): State<Arc<AppState>>, Json(ite
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn batch_auth_user(State(state): State<Arc<AppState>>, Json(item): Json<SelectAuthUserAllReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/role/batchAuthUser",
+    tag = "角色信息",
+    summary = "批量选择用户授权",
+    request_body = SelectAuthUserAllReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn batch_auth_user(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<SelectAuthUserAllReq>) -> impl IntoResponse {
     info!("batch_auth_user params: {:?}", &item);
     let role_id = item.role_id;
 
@@ -341,5 +430,7 @@ pub async fn batch_auth_user(State(state): State<Arc<AppState>>, Json(item): Jso
         })
     }
 
-    UserRole::insert_batch(rb, &user_role, item.user_ids.len() as u64).await.map(|_| ok_result())?
+    UserRole::insert_batch(rb, &user_role, item.user_ids.len() as u64).await?;
+
+    refresh_user_permission(&state, &item.user_ids).await.map(|_| ok_result())?
 }
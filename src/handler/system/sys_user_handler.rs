@@ -1,56 +1,130 @@
-use crate::common::error::AppError;
-use crate::common::result::{err_result_msg, ok_result, ok_result_data, ok_result_page};
+use crate::auth::{create_external_user, detect_account_type, grant_mapped_roles, AuthResult, ExternalUser};
+use crate::common::error::{AppError, AppResult};
+use crate::common::error_code::ErrorCode;
+use crate::common::result::{ok_result, ok_result_data, ok_result_msg, ok_result_page, BaseResponse, ResponsePage};
+use crate::common::valid::ValidJson;
+use crate::handler::system::sys_notice_handler::send_system_notice;
+use crate::i18n::{current_locale, t, translate, Locale};
+use crate::mail::send_mail_background;
+use crate::model::system::sys_api_key_model::ApiKey;
 use crate::model::system::sys_dept_model::Dept;
 use crate::model::system::sys_login_log_model::LoginLog;
 use crate::model::system::sys_menu_model::Menu;
 use crate::model::system::sys_role_model::Role;
-use crate::model::system::sys_user_model::User;
+use crate::model::system::sys_user_identity_model::UserIdentity;
+use crate::model::system::sys_user_model::{User, USER_SORT};
 use crate::model::system::sys_user_post_model::UserPost;
+use crate::model::system::sys_user_pwd_history_model::UserPwdHistory;
 use crate::model::system::sys_user_role_model::{is_admin, UserRole};
-use crate::utils::jwt_util::JwtToken;
+use crate::oidc::{random_token, OidcUser};
+use crate::sms::{is_valid_mobile, send_code_background};
+use crate::utils::config_util::{get_config_i64, get_config_str, LOGIN_ACCOUNT_TYPES, LOGIN_VERIFY_RISK_LEVEL, USER_INIT_PASSWORD};
+use crate::utils::ip_util::get_client_ip;
+use crate::utils::jwt_util::{JwtToken, SCOPE_PWD_CHANGE};
+use crate::utils::login_risk_util::{check_login_risk, risk_level_key, LoginRisk};
+use crate::utils::message_util::{send_message, EVENT_LOGOUT, EVENT_PERMISSION};
+use crate::utils::password_util::{check_password, save_password_history, PasswordPolicy};
+use crate::utils::redis_util::check_rate_limit;
 use crate::utils::user_agent_util::UserAgentUtil;
 use crate::vo::system::sys_dept_vo::DeptResp;
 use crate::vo::system::sys_role_vo::RoleResp;
 use crate::vo::system::sys_user_vo::*;
 use crate::AppState;
-use axum::extract::State;
+use axum::extract::{ConnectInfo, State};
+use axum::http::header::USER_AGENT;
 use axum::http::HeaderMap;
 use axum::response::IntoResponse;
 use axum::Json;
 use chrono::Local;
 use log::info;
-use rbatis::plugin::page::PageRequest;
+use rand::Rng;
 use rbatis::rbatis_codegen::ops::AsProxy;
 use rbatis::rbdc::DateTime;
 use rbatis::RBatis;
 use rbs::value;
 use redis::Commands;
 use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
 use std::sync::Arc;
+
+// 登录二次验证(redis hash, 保存用户id、验证码和风险信息)
+const LOGIN_VERIFY_KEY: &str = "axum:admin:login:verify:";
+const LOGIN_VERIFY_SECONDS: i64 = 300;
+const LOGIN_VERIFY_MAX_ATTEMPTS: i64 = 5;
+// 需要二次验证时登录接口返回的code
+const LOGIN_VERIFY_CODE: i32 = 2;
+// 密码过期需要修改密码(redis hash, 保存用户id和风险信息)
+const LOGIN_PWD_KEY: &str = "axum:admin:login:pwd:";
+const LOGIN_PWD_SECONDS: i64 = 600;
+// 密码过期时登录接口返回的code
+const LOGIN_PWD_EXPIRED_CODE: i32 = 3;
+// 需要修改密码(新增用户或者重置密码后首次登录)时登录接口返回的code,data为受限token
+const LOGIN_PWD_CHANGE_CODE: i32 = 4;
+// 找回密码验证码(redis hash, 保存用户id、验证码和错误次数)
+const PWD_RESET_KEY: &str = "axum:admin:pwd:reset:";
+const PWD_RESET_SECONDS: i64 = 900;
+const PWD_RESET_MAX_ATTEMPTS: i64 = 5;
+// 找回密码限流(同一邮箱60秒内1次,同一IP每小时10次)
+const PWD_RESET_LIMIT_KEY: &str = "axum:admin:pwd:limit:";
+// 短信登录验证码(redis hash, 保存验证码和错误次数)
+const LOGIN_SMS_KEY: &str = "axum:admin:login:sms:";
+const LOGIN_SMS_SECONDS: i64 = 300;
+const LOGIN_SMS_MAX_ATTEMPTS: i64 = 5;
+// 短信验证码限流(同一手机号码60秒内1次、每天10次,同一IP每小时20次)
+const LOGIN_SMS_LIMIT_KEY: &str = "axum:admin:login:smsLimit:";
+// 单点登录状态(redis hash, 保存PKCE的code_verifier和nonce)
+const LOGIN_OIDC_KEY: &str = "axum:admin:login:oidc:";
+const LOGIN_OIDC_SECONDS: i64 = 600;
+// 超级管理员角色id
+const ADMIN_ROLE_ID: i64 = 1;
+
 /*
  *添加用户信息
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn add_sys_user(State(state): State<Arc<AppState>>, Json(mut item): Json<UserReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/addUser",
+    tag = "用户信息",
+    summary = "添加用户信息",
+    request_body = UserReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn add_sys_user(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<UserReq>) -> impl IntoResponse {
     info!("add sys_user params: {:?}", &item);
     let rb = &state.batis;
 
     if User::select_by_user_name(rb, &item.user_name).await?.is_some() {
-        return Err(AppError::BusinessError("登录账号已存在"));
+        return Err(AppError::BusinessError(ErrorCode::UserNameExists));
     }
 
-    if User::select_by_mobile(rb, &item.mobile).await?.is_some() {
-        return Err(AppError::BusinessError("手机号码已存在"));
+    if !item.mobile.is_empty() && User::select_by_mobile(rb, &item.mobile).await?.is_some() {
+        return Err(AppError::BusinessError(ErrorCode::UserMobileExists));
     }
 
-    if User::select_by_email(rb, &item.email).await?.is_some() {
-        return Err(AppError::BusinessError("邮箱账号已存在"));
+    if !item.email.is_empty() && User::select_by_email(rb, &item.email).await?.is_some() {
+        return Err(AppError::BusinessError(ErrorCode::UserEmailExists));
+    }
+
+    let init_password = item.password.as_deref().unwrap_or_default().is_empty();
+    if init_password {
+        item.password = Some(get_config_str(&state, USER_INIT_PASSWORD, "123456").await);
     }
 
     let post_ids = item.post_ids.clone();
     item.id = None;
-    let id = User::insert(rb, &User::from(item)).await?.last_insert_id;
+    let mut user = User::from(item);
+    // 使用参数配置的初始密码时不校验密码策略
+    if !init_password {
+        check_password(&state, &user, &user.password).await?;
+    }
+    user.pwd_update_date = Some(DateTime::now());
+    // 管理员设置的密码,首次登录时需要修改
+    user.pwd_must_change = 1;
+
+    let id = User::insert(rb, &user).await?.last_insert_id;
+    save_password_history(&state, id.i64(), &user.password).await?;
 
     let mut user_post_list: Vec<UserPost> = Vec::new();
     for post_id in post_ids {
@@ -65,7 +139,15 @@ pub async fn add_sys_user(State(state): State<Arc<AppState>>, Json(mut item): Js
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn delete_sys_user(headers: HeaderMap, State(state): State<Arc<AppState>>, Json(item): Json<DeleteUserReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/deleteUser",
+    tag = "用户信息",
+    summary = "删除用户信息",
+    request_body = DeleteUserReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn delete_sys_user(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<DeleteUserReq>) -> impl IntoResponse {
     info!("delete sys_user params: {:?}", &item);
     let rb = &state.batis;
     let mut conn = state.redis.get_connection()?;
@@ -74,7 +156,7 @@ pub async fn delete_sys_user(headers: HeaderMap, State(state): State<Arc<AppStat
 
     let ids = item.ids.clone();
     if ids.contains(&user_id) {
-        return Err(AppError::BusinessError("当前用户不能删除"));
+        return Err(AppError::BusinessError(ErrorCode::CurrentUserDeleteForbidden));
     }
 
     for id in ids.clone() {
@@ -82,13 +164,18 @@ pub async fn delete_sys_user(headers: HeaderMap, State(state): State<Arc<AppStat
         let is_admin: bool = conn.hget(&key, "isAdmin").unwrap_or_default();
 
         if is_admin {
-            return Err(AppError::BusinessError("不允许操作超级管理员用户"));
+            return Err(AppError::BusinessError(ErrorCode::AdminUserForbidden));
         }
     }
 
     UserRole::delete_by_map(rb, value! {"user_id": &ids}).await?;
     UserPost::delete_by_map(rb, value! {"user_id": &ids}).await?;
-    User::delete_by_map(rb, value! {"id": &item.ids}).await.map(|_| ok_result())?
+    UserPwdHistory::delete_by_map(rb, value! {"user_id": &ids}).await?;
+    UserIdentity::delete_by_map(rb, value! {"user_id": &ids}).await?;
+    ApiKey::delete_by_map(rb, value! {"user_id": &ids}).await?;
+    User::delete_by_map(rb, value! {"id": &item.ids}).await?;
+
+    force_logout_user(&state, &ids, &t("logout.deleted")).map(|_| ok_result())?
 }
 
 /*
@@ -96,7 +183,15 @@ pub async fn delete_sys_user(headers: HeaderMap, State(state): State<Arc<AppStat
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn update_sys_user(State(state): State<Arc<AppState>>, Json(item): Json<UserReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/updateUser",
+    tag = "用户信息",
+    summary = "更新用户信息",
+    request_body = UserReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_user(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UserReq>) -> impl IntoResponse {
     info!("update sys_user params: {:?}", &item);
     let rb = &state.batis;
 
@@ -106,29 +201,29 @@ pub async fn update_sys_user(State(state): State<Arc<AppState>>, Json(item): Jso
     let is_admin: bool = conn.hget(&key, "isAdmin").unwrap_or_default();
 
     if is_admin {
-        return Err(AppError::BusinessError("不允许操作超级管理员用户"));
+        return Err(AppError::BusinessError(ErrorCode::AdminUserForbidden));
     }
 
     let user = match User::select_by_id(rb, id.unwrap_or_default()).await? {
-        None => return Err(AppError::BusinessError("用户不存在")),
+        None => return Err(AppError::BusinessError(ErrorCode::UserNotFound)),
         Some(x) => x,
     };
 
     if let Some(x) = User::select_by_user_name(rb, &item.user_name).await? {
         if x.id != id {
-            return Err(AppError::BusinessError("登录账号已存在"));
+            return Err(AppError::BusinessError(ErrorCode::UserNameExists));
         }
     }
 
     if let Some(x) = User::select_by_mobile(rb, &item.mobile).await? {
-        if x.id != id {
-            return Err(AppError::BusinessError("手机号码已存在"));
+        if !item.mobile.is_empty() && x.id != id {
+            return Err(AppError::BusinessError(ErrorCode::UserMobileExists));
         }
     }
 
     if let Some(x) = User::select_by_email(rb, &item.email).await? {
-        if x.id != id {
-            return Err(AppError::BusinessError("邮箱账号已存在"));
+        if !item.email.is_empty() && x.id != id {
+            return Err(AppError::BusinessError(ErrorCode::UserEmailExists));
         }
     }
 
@@ -144,7 +239,13 @@ pub async fn update_sys_user(State(state): State<Arc<AppState>>, Json(item): Jso
     UserPost::delete_by_map(rb, value! {"user_id": &item.id}).await?;
     UserPost::insert_batch(rb, &user_post_list, user_post_list.len() as u64).await?;
 
-    User::update_by_map(rb, &User::from(item), value! {"id": &id}).await.map(|_| ok_result())?
+    // 密码只能通过重置密码和修改密码接口修改
+    let mut model = User::from(item);
+    model.password = user.password;
+    model.pwd_update_date = user.pwd_update_date;
+    model.pwd_must_change = user.pwd_must_change;
+    model.locale = user.locale;
+    User::update_by_map(rb, &model, value! {"id": &id}).await.map(|_| ok_result())?
 }
 
 /*
@@ -152,7 +253,15 @@ pub async fn update_sys_user(State(state): State<Arc<AppState>>, Json(item): Jso
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn update_sys_user_status(State(state): State<Arc<AppState>>, Json(item): Json<UpdateUserStatusReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/updateUserStatus",
+    tag = "用户信息",
+    summary = "更新用户信息状态",
+    request_body = UpdateUserStatusReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_user_status(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateUserStatusReq>) -> impl IntoResponse {
     info!("update sys_user_status params: {:?}", &item);
     let rb = &state.batis;
 
@@ -164,7 +273,7 @@ pub async fn update_sys_user_status(State(state): State<Arc<AppState>>, Json(ite
         let is_admin: bool = conn.hget(&key, "isAdmin").unwrap_or_default();
 
         if is_admin {
-            return Err(AppError::BusinessError("不允许操作超级管理员用户"));
+            return Err(AppError::BusinessError(ErrorCode::AdminUserForbidden));
         }
     }
 
@@ -172,7 +281,96 @@ pub async fn update_sys_user_status(State(state): State<Arc<AppState>>, Json(ite
 
     let mut param = vec![value!(item.status)];
     param.extend(item.ids.iter().map(|&id| value!(id)));
-    rb.exec(&update_sql, param).await.map(|_| ok_result())?
+    rb.exec(&update_sql, param).await?;
+
+    if item.status == 0 {
+        force_logout_user(&state, &item.ids, &t("logout.disabled"))?;
+    }
+    ok_result()
+}
+
+/*
+ *强制用户下线
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/forceLogout",
+    tag = "用户信息",
+    summary = "强制用户下线",
+    request_body = ForceLogoutReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn force_logout(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<ForceLogoutReq>) -> impl IntoResponse {
+    info!("force logout params: {:?}", &item);
+    let mut conn = state.redis.get_connection()?;
+
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+
+    if item.ids.contains(&user_id) {
+        return Err(AppError::BusinessError(ErrorCode::CurrentUserKickoutForbidden));
+    }
+
+    for id in item.ids.clone() {
+        let key = format!("axum:admin:user:info:{}", id);
+        let is_admin: bool = conn.hget(&key, "isAdmin").unwrap_or_default();
+
+        if is_admin {
+            return Err(AppError::BusinessError(ErrorCode::AdminUserForbidden));
+        }
+    }
+
+    force_logout_user(&state, &item.ids, &t("logout.kicked")).map(|_| ok_result())?
+}
+
+/*
+ *使用户的登录token失效,并推送强制下线消息
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+pub fn force_logout_user(state: &AppState, user_ids: &[i64], reason: &str) -> Result<(), AppError> {
+    if user_ids.is_empty() {
+        return Ok(());
+    }
+    let mut conn = state.redis.get_connection()?;
+
+    for id in user_ids {
+        let key = format!("axum:admin:user:info:{}", id);
+        conn.hdel::<_, _, ()>(&key, "token")?;
+    }
+
+    send_message(state, EVENT_LOGOUT, user_ids.to_vec(), serde_json::json!({ "reason": reason }));
+    Ok(())
+}
+
+/*
+ *重新加载在线用户的按钮权限,并推送权限变更消息
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+pub async fn refresh_user_permission(state: &AppState, user_ids: &[i64]) -> Result<(), AppError> {
+    let rb = &state.batis;
+    let mut conn = state.redis.get_connection()?;
+
+    let mut online_ids: Vec<i64> = Vec::new();
+    for id in user_ids {
+        let key = format!("axum:admin:user:info:{}", id);
+        // 只处理已登录的用户,未登录的用户会在下次登录时加载
+        if !conn.hexists::<_, _, bool>(&key, "token")? {
+            continue;
+        }
+
+        let (btn_menu, is_super) = query_btn_menu(id, rb.clone()).await;
+        conn.hset::<_, _, _, ()>(&key, "permissions", &btn_menu.join(","))?;
+        conn.hset::<_, _, _, ()>(&key, "isAdmin", is_super)?;
+        online_ids.push(*id);
+    }
+
+    if !online_ids.is_empty() {
+        send_message(state, EVENT_PERMISSION, online_ids, serde_json::Value::Null);
+    }
+    Ok(())
 }
 
 /*
@@ -180,7 +378,15 @@ pub async fn update_sys_user_status(State(state): State<Arc<AppState>>, Json(ite
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn reset_sys_user_password(State(state): State<Arc<AppState>>, Json(item): Json<ResetUserPwdReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/reset_sys_user_password",
+    tag = "用户信息",
+    summary = "重置用户密码",
+    request_body = ResetUserPwdReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn reset_sys_user_password(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<ResetUserPwdReq>) -> impl IntoResponse {
     info!("update sys_user_password params: {:?}", &item);
 
     let rb = &state.batis;
@@ -190,17 +396,23 @@ pub async fn reset_sys_user_password(State(state): State<Arc<AppState>>, Json(it
     let is_admin: bool = conn.hget(&key, "isAdmin").unwrap_or_default();
 
     if is_admin {
-        return Err(AppError::BusinessError("不允许操作超级管理员用户"));
+        return Err(AppError::BusinessError(ErrorCode::AdminUserForbidden));
     }
 
     let sys_user_result = User::select_by_id(rb, item.id).await?;
 
     match sys_user_result {
-        None => Err(AppError::BusinessError("用户不存在")),
+        None => Err(AppError::BusinessError(ErrorCode::UserNotFound)),
         Some(x) => {
             let mut user = x;
+            check_password(&state, &user, &item.password).await?;
             user.password = item.password;
-            User::update_by_map(rb, &user, value! {"id": &user.id}).await.map(|_| ok_result())?
+            user.pwd_update_date = Some(DateTime::now());
+            user.pwd_must_change = 1;
+            User::update_by_map(rb, &user, value! {"id": &user.id}).await?;
+            save_password_history(&state, item.id, &user.password).await?;
+
+            force_logout_user(&state, &[item.id], &t("logout.password_reset")).map(|_| ok_result())?
         }
     }
 }
@@ -210,7 +422,15 @@ pub async fn reset_sys_user_password(State(state): State<Arc<AppState>>, Json(it
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn update_sys_user_password(headers: HeaderMap, State(state): State<Arc<AppState>>, Json(item): Json<UpdateUserPwdReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/updateUserPassword",
+    tag = "用户信息",
+    summary = "用户修改自己的密码",
+    request_body = UpdateUserPwdReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_sys_user_password(headers: HeaderMap, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateUserPwdReq>) -> impl IntoResponse {
     info!("update sys_user_password params: {:?}", &item);
 
     let rb = &state.batis;
@@ -218,14 +438,27 @@ pub async fn update_sys_user_password(headers: HeaderMap, State(state): State<Ar
     let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
 
     match User::select_by_id(rb, user_id).await? {
-        None => Err(AppError::BusinessError("用户不存在")),
+        None => Err(AppError::BusinessError(ErrorCode::UserNotFound)),
         Some(x) => {
             let mut user = x;
             if user.password != item.pwd {
-                return Err(AppError::BusinessError("旧密码不正确"));
+                return Err(AppError::BusinessError(ErrorCode::OldPasswordIncorrect));
             }
+            check_password(&state, &user, &item.re_pwd).await?;
+            let must_change = user.pwd_must_change == 1;
             user.password = item.re_pwd;
-            User::update_by_map(rb, &user, value! {"id": &user.id}).await.map(|_| ok_result())?
+            user.pwd_update_date = Some(DateTime::now());
+            user.pwd_must_change = 0;
+            User::update_by_map(rb, &user, value! {"id": &user.id}).await?;
+            save_password_history(&state, user_id, &user.password).await?;
+
+            // 受限token不能继续使用,需要用新密码重新登录
+            if must_change {
+                let mut conn = state.redis.get_connection()?;
+                conn.hdel::<_, _, ()>(format!("axum:admin:user:info:{}", user_id), "token")?;
+                return ok_result_msg(&t("password.changed"));
+            }
+            ok_result()
         }
     }
 }
@@ -235,12 +468,20 @@ pub async fn update_sys_user_password(headers: HeaderMap, State(state): State<Ar
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn query_sys_user_detail(State(state): State<Arc<AppState>>, Json(item): Json<QueryUserDetailReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/queryUserDetail",
+    tag = "用户信息",
+    summary = "查询用户信息详情",
+    request_body = QueryUserDetailReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<UserResp>))
+)]
+pub async fn query_sys_user_detail(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryUserDetailReq>) -> impl IntoResponse {
     info!("query sys_user_detail params: {:?}", &item);
     let rb = &state.batis;
 
     let mut x = match User::select_by_id(rb, item.id).await? {
-        None => return Err(AppError::BusinessError("用户不存在")),
+        None => return Err(AppError::BusinessError(ErrorCode::UserNotFound)),
         Some(user) => {
             let a: UserResp = user.into();
             a
@@ -248,7 +489,7 @@ pub async fn query_sys_user_detail(State(state): State<Arc<AppState>>, Json(item
     };
 
     let dept = match Dept::select_by_id(rb, &x.dept_id).await? {
-        None => return Err(AppError::BusinessError("部门不存在")),
+        None => return Err(AppError::BusinessError(ErrorCode::DeptNotFound)),
 
         Some(y) => {
             let a: DeptResp = y.into();
@@ -269,15 +510,24 @@ pub async fn query_sys_user_detail(State(state): State<Arc<AppState>>, Json(item
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn query_sys_user_list(State(state): State<Arc<AppState>>, Json(item): Json<QueryUserListReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/queryUserList",
+    tag = "用户信息",
+    summary = "查询用户信息列表",
+    request_body = QueryUserListReq,
+    responses((status = 200, description = "操作成功", body = ResponsePage<Vec<UserResp>>))
+)]
+pub async fn query_sys_user_list(State(state): State<Arc<AppState>>, ValidJson(mut item): ValidJson<QueryUserListReq>) -> impl IntoResponse {
     info!("query sys_user_list params: {:?}", &item);
     let rb = &state.batis;
 
-    let page = &PageRequest::new(item.page_no, item.page_size);
+    item.page.sort(&USER_SORT)?;
+    let page = &item.page.page_request();
 
     User::select_sys_user_list(rb, page, &item)
         .await
-        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<UserResp>>(), x.total))?
+        .map(|x| ok_result_page(x.records.into_iter().map(|x| x.into()).collect::<Vec<UserResp>>(), x.total, &item.page))?
 }
 
 /*
@@ -285,63 +535,860 @@ pub async fn query_sys_user_list(State(state): State<Arc<AppState>>, Json(item):
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn login(headers: HeaderMap, State(state): State<Arc<AppState>>, Json(item): Json<UserLoginReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/login",
+    tag = "用户信息",
+    summary = "用户登录",
+    security(()),
+    request_body = UserLoginReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn login(headers: HeaderMap, ConnectInfo(addr): ConnectInfo<SocketAddr>, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UserLoginReq>) -> impl IntoResponse {
     info!("user login params: {:?}, {:?}", &item, state.batis);
     let rb = &state.batis;
+
+    let client = LoginClient::new(&state, &headers, addr);
+    info!("login client: {:?}", client);
+
+    let account = item.account.trim();
+    if account.is_empty() {
+        return Err(AppError::BusinessError(ErrorCode::AccountRequired));
+    }
+    let account_type = match item.account_type.as_deref().map(str::trim) {
+        Some(x) if !x.is_empty() => x,
+        _ => detect_account_type(account),
+    };
+
+    let allowed_types = get_config_str(&state, LOGIN_ACCOUNT_TYPES, "mobile,username,email").await;
+    if !allowed_types.split(',').any(|x| x.trim() == account_type) {
+        add_login_log(rb, account, 0, "不支持该登录方式", &client, &LoginRisk::default()).await;
+        return Err(AppError::BusinessError(ErrorCode::LoginTypeUnsupported));
+    }
+
+    // 按配置的顺序依次认证,第一个认证成功的结果生效
+    let mut auth_result = AuthResult::UserNotFound;
+    let mut local_password = true;
+    let mut auth_error = None;
+    for provider in state.auth_providers.iter() {
+        match provider.authenticate(&state, account, account_type, &item.password).await {
+            Ok(AuthResult::Success(user)) => {
+                info!("user login by {} auth provider, user_id: {:?}", provider.kind(), user.id);
+                auth_result = AuthResult::Success(user);
+                local_password = provider.local_password();
+                break;
+            }
+            Ok(AuthResult::BadPassword) => auth_result = AuthResult::BadPassword,
+            Ok(AuthResult::UserNotFound) => {}
+            Err(e) => {
+                log::error!("{} auth provider error, account: {}, error message: {:?}", provider.kind(), account, e);
+                auth_error = Some(e);
+            }
+        }
+    }
+
+    let user = match auth_result {
+        AuthResult::Success(user) => *user,
+        AuthResult::BadPassword => {
+            add_login_log(rb, account, 0, "密码不正确", &client, &LoginRisk::default()).await;
+            return Err(AppError::BusinessError(ErrorCode::PasswordIncorrect));
+        }
+        AuthResult::UserNotFound => {
+            if let Some(e) = auth_error {
+                add_login_log(rb, account, 0, "认证服务异常", &client, &LoginRisk::default()).await;
+                return Err(e);
+            }
+            add_login_log(rb, account, 0, "用户不存在", &client, &LoginRisk::default()).await;
+            return Err(AppError::BusinessError(ErrorCode::UserNotFound));
+        }
+    };
+    // 禁用的用户即使密码正确也不能登录
+    if let Err(code) = check_user_status(&user) {
+        add_login_log(rb, account, 0, "用户已被禁用", &client, &LoginRisk::default()).await;
+        return Err(AppError::BusinessError(code));
+    }
+    let id = user.id.unwrap();
+
+    let (btn_menu, is_super) = query_btn_menu(&id, rb.clone()).await;
+
+    if btn_menu.len() == 0 {
+        add_login_log(rb, account, 0, "用户没有分配角色或者菜单,不能登录", &client, &LoginRisk::default()).await;
+        return Err(AppError::BusinessError(ErrorCode::UserNoPermission));
+    }
+
+    // 登录风险检测(新设备、新国家/城市、短时间内跨国登录、非允许时段登录)
+    let risk = check_login_risk(&state, &client.login_log(account, 1, "", &LoginRisk::default()), &user).await;
+    if risk.is_risky() {
+        info!("login risk user_id: {}, risk: {:?}", id, risk);
+        tokio::spawn(send_login_risk_notice(state.clone(), user.clone(), client.clone(), risk.clone()));
+
+        let verify_level = get_config_i64(&state, LOGIN_VERIFY_RISK_LEVEL, 0).await;
+        if verify_level > 0 && risk.level as i64 >= verify_level {
+            let verify_token = create_login_verify(&state, &user, account, &risk, local_password)?;
+            add_login_log(rb, account, 0, "登录存在风险,等待二次验证", &client, &risk).await;
+            return Ok(Json(BaseResponse {
+                msg: t("login.risk_verify"),
+                code: LOGIN_VERIFY_CODE,
+                data: Some(verify_token),
+            }));
+        }
+    }
+
+    finish_login(&state, user, (btn_menu, is_super), &client, account, &risk, local_password).await
+}
+
+/*
+ *发送短信登录验证码
+ *author：刘飞华
+ *date：2026/10/18 22:52:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/sendLoginCode",
+    tag = "用户信息",
+    summary = "发送短信登录验证码",
+    security(()),
+    request_body = SendLoginCodeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn send_login_code(headers: HeaderMap, ConnectInfo(addr): ConnectInfo<SocketAddr>, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<SendLoginCodeReq>) -> impl IntoResponse {
+    info!("send login code params: {:?}", &item);
+    let rb = &state.batis;
+    let mut conn = state.redis.get_connection()?;
+
+    let mobile = item.mobile.trim();
+    if !is_valid_mobile(mobile) {
+        return Err(AppError::BusinessError(ErrorCode::MobileInvalid));
+    }
+
+    let client_ip = get_client_ip(&headers, addr.ip(), &state.trusted_proxies);
+    if !check_rate_limit(&mut conn, &format!("{}ip:{}", LOGIN_SMS_LIMIT_KEY, client_ip), 20, 3600)?
+        || !check_rate_limit(&mut conn, &format!("{}mobile:{}", LOGIN_SMS_LIMIT_KEY, mobile), 1, 60)?
+        || !check_rate_limit(&mut conn, &format!("{}day:{}", LOGIN_SMS_LIMIT_KEY, mobile), 10, 86400)?
+    {
+        return Err(AppError::BusinessError(ErrorCode::TooManyRequests));
+    }
+
+    // 不管手机号码是否存在都返回相同的结果,防止探测账号
+    if let Some(user) = User::select_by_mobile(rb, mobile).await? {
+        if user.status == 1 {
+            let code = format!("{:06}", rand::thread_rng().gen_range(0..1000000));
+            let key = format!("{}{}", LOGIN_SMS_KEY, mobile);
+
+            conn.del::<_, ()>(&key)?;
+            conn.hset::<_, _, _, ()>(&key, "code", &code)?;
+            conn.expire::<_, ()>(&key, LOGIN_SMS_SECONDS)?;
+
+            send_code_background(state.sms.clone(), mobile.to_string(), code);
+        }
+    }
+
+    ok_result_msg(&t("login.code_sent"))
+}
+
+/*
+ *短信验证码登录
+ *author：刘飞华
+ *date：2026/10/18 22:52:18
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/loginByCode",
+    tag = "用户信息",
+    summary = "短信验证码登录",
+    security(()),
+    request_body = LoginByCodeReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn login_by_code(headers: HeaderMap, ConnectInfo(addr): ConnectInfo<SocketAddr>, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<LoginByCodeReq>) -> impl IntoResponse {
+    info!("user login by code params: {:?}", &item.mobile);
+    let rb = &state.batis;
     let mut conn = state.redis.get_connection()?;
 
-    let user_agent = headers.get("User-Agent").unwrap().to_str().unwrap();
-    info!("user agent: {:?}", user_agent);
-    let agent = UserAgentUtil::new(user_agent);
+    let client = LoginClient::new(&state, &headers, addr);
+    let mobile = item.mobile.trim();
 
-    let user_result = User::select_by_mobile(rb, &item.mobile).await?;
-    info!("query user by mobile: {:?}", user_result);
+    let key = format!("{}{}", LOGIN_SMS_KEY, mobile);
+    let code: Option<String> = conn.hget(&key, "code")?;
+    let Some(code) = code else {
+        add_login_log(rb, mobile, 0, "短信验证码已过期", &client, &LoginRisk::default()).await;
+        return Err(AppError::BusinessError(ErrorCode::VerifyCodeExpired));
+    };
 
-    match user_result {
+    if code.ne(item.code.trim()) {
+        let attempts: i64 = conn.hincr(&key, "attempts", 1)?;
+        if attempts >= LOGIN_SMS_MAX_ATTEMPTS {
+            conn.del::<_, ()>(&key)?;
+            add_login_log(rb, mobile, 0, "短信验证码错误次数过多", &client, &LoginRisk::default()).await;
+            return Err(AppError::BusinessError(ErrorCode::VerifyCodeTooManyAttempts));
+        }
+        add_login_log(rb, mobile, 0, "短信验证码不正确", &client, &LoginRisk::default()).await;
+        return Err(AppError::BusinessError(ErrorCode::VerifyCodeIncorrect));
+    }
+    conn.del::<_, ()>(&key)?;
+
+    let user = match User::select_by_mobile(rb, mobile).await? {
         None => {
-            add_login_log(rb, item.mobile, 0, "用户不存在", agent).await;
-            Err(AppError::BusinessError("用户不存在"))
+            add_login_log(rb, mobile, 0, "用户不存在", &client, &LoginRisk::default()).await;
+            return Err(AppError::BusinessError(ErrorCode::UserNotFound));
         }
-        Some(user) => {
-            let mut s_user = user.clone();
-            let id = user.id.unwrap();
-            let username = user.user_name;
-            let password = user.password;
-
-            if password.ne(&item.password) {
-                add_login_log(rb, item.mobile, 0, "密码不正确", agent).await;
-                return err_result_msg("密码不正确");
-            }
+        Some(x) => x,
+    };
+    if user.status != 1 {
+        add_login_log(rb, mobile, 0, "用户已被禁用", &client, &LoginRisk::default()).await;
+        return Err(AppError::BusinessError(ErrorCode::UserDisabled));
+    }
 
-            let (btn_menu, is_super) = query_btn_menu(&id, rb.clone()).await;
+    let id = user.id.unwrap_or_default();
+    let (btn_menu, is_super) = query_btn_menu(&id, rb.clone()).await;
+    if btn_menu.is_empty() {
+        add_login_log(rb, mobile, 0, "用户没有分配角色或者菜单,不能登录", &client, &LoginRisk::default()).await;
+        return Err(AppError::BusinessError(ErrorCode::UserNoPermission));
+    }
 
-            if btn_menu.len() == 0 {
-                add_login_log(rb, item.mobile, 0, "用户没有分配角色或者菜单,不能登录", agent).await;
-                return Err(AppError::BusinessError("用户没有分配角色或者菜单,不能登录"));
-            }
+    // 短信验证码已经验证了手机,存在风险时只通知,不需要二次验证
+    let risk = check_login_risk(&state, &client.login_log(mobile, 1, "", &LoginRisk::default()), &user).await;
+    if risk.is_risky() {
+        info!("login risk user_id: {}, risk: {:?}", id, risk);
+        tokio::spawn(send_login_risk_notice(state.clone(), user.clone(), client.clone(), risk.clone()));
+    }
+
+    finish_login(&state, user, (btn_menu, is_super), &client, mobile, &risk, true).await
+}
+
+/*
+ *单点登录(返回身份提供方的登录地址,前端跳转到该地址登录)
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+#[utoipa::path(
+    get,
+    path = "/api/system/user/oidcAuthorize",
+    tag = "用户信息",
+    summary = "单点登录(返回身份提供方的登录地址,前端跳转到该地址登录)",
+    security(()),
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn oidc_authorize(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    info!("oidc authorize");
+    if !state.oidc.config().enabled {
+        return Err(AppError::BusinessError(ErrorCode::OidcDisabled));
+    }
+    let mut conn = state.redis.get_connection()?;
+
+    let (oidc_state, nonce, code_verifier) = (random_token(), random_token(), random_token());
+    let url = state.oidc.authorize_url(&oidc_state, &nonce, &code_verifier).await?;
+
+    let key = format!("{}{}", LOGIN_OIDC_KEY, oidc_state);
+    conn.hset_multiple::<_, _, _, ()>(&key, &[("code_verifier", code_verifier.as_str()), ("nonce", nonce.as_str())])?;
+    conn.expire::<_, ()>(&key, LOGIN_OIDC_SECONDS)?;
+
+    ok_result_data(url)
+}
+
+/*
+ *单点登录回调(使用授权码换取ID Token,验证后登录,用户不存在时按邮箱关联或者自动创建)
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/oidcCallback",
+    tag = "用户信息",
+    summary = "单点登录回调",
+    security(()),
+    request_body = OidcCallbackReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn oidc_callback(headers: HeaderMap, ConnectInfo(addr): ConnectInfo<SocketAddr>, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<OidcCallbackReq>) -> impl IntoResponse {
+    info!("oidc callback state: {:?}", &item.state);
+    if !state.oidc.config().enabled {
+        return Err(AppError::BusinessError(ErrorCode::OidcDisabled));
+    }
+    let rb = &state.batis;
+    let mut conn = state.redis.get_connection()?;
+
+    let client = LoginClient::new(&state, &headers, addr);
+
+    // state只能使用一次
+    let key = format!("{}{}", LOGIN_OIDC_KEY, item.state);
+    let ticket: HashMap<String, String> = conn.hgetall(&key)?;
+    conn.del::<_, ()>(&key)?;
+    let (Some(code_verifier), Some(nonce)) = (ticket.get("code_verifier"), ticket.get("nonce")) else {
+        return Err(AppError::BusinessError(ErrorCode::OidcStateExpired));
+    };
+
+    let id_token = state.oidc.exchange_code(&item.code, code_verifier).await?;
+    let oidc_user = state.oidc.verify_id_token(&id_token, nonce).await?;
+    info!("oidc user: {:?}", oidc_user);
+
+    let login_name = if oidc_user.email.is_empty() { oidc_user.subject.clone() } else { oidc_user.email.clone() };
+
+    let Some(user) = query_oidc_user(&state, &oidc_user).await? else {
+        add_login_log(rb, &login_name, 0, "单点登录用户不存在", &client, &LoginRisk::default()).await;
+        return Err(AppError::BusinessError(ErrorCode::OidcUserNotFound));
+    };
+
+    if user.status != 1 {
+        add_login_log(rb, &login_name, 0, "用户已被禁用", &client, &LoginRisk::default()).await;
+        return Err(AppError::BusinessError(ErrorCode::UserDisabled));
+    }
 
-            let token = JwtToken::new(id, &username).create_token("123")?;
-
-            let key = format!("axum:admin:user:info:{:?}", s_user.id.unwrap_or_default());
-            // 存储用户权限信息
-            conn.hset::<_, _, _, ()>(&key, "permissions", &btn_menu.join(","))?;
-            // 存储用户名
-            conn.hset::<_, _, _, ()>(&key, "user_name", &s_user.user_name)?;
-            // 存储是否是超级管理员
-            conn.hset::<_, _, _, ()>(&key, "isAdmin", is_super)?;
-            // 存储token
-            conn.hset::<_, _, _, ()>(&key, "token", &token)?;
-            // 存储登录时间
-            conn.hset::<_, _, _, ()>(&key, "last_login", Local::now().format("%Y-%m-%d %H:%M:%S").to_string())?;
-
-            add_login_log(rb, item.mobile, 1, "登录成功", agent.clone()).await;
-            s_user.login_os = agent.os;
-            s_user.login_browser = agent.browser;
-            s_user.login_date = Some(DateTime::now());
-            User::update_by_map(rb, &s_user, value! {"id": &s_user.id}).await?;
-            ok_result_data(token)
+    let id = user.id.unwrap_or_default();
+    grant_mapped_roles(rb, id, &oidc_user.groups, &state.oidc.config().role_mapping).await?;
+
+    let (btn_menu, is_super) = query_btn_menu(&id, rb.clone()).await;
+    if btn_menu.is_empty() {
+        add_login_log(rb, &login_name, 0, "用户没有分配角色或者菜单,不能登录", &client, &LoginRisk::default()).await;
+        return Err(AppError::BusinessError(ErrorCode::UserNoPermission));
+    }
+
+    // 身份提供方已经完成认证,存在风险时只通知,不需要二次验证
+    let risk = check_login_risk(&state, &client.login_log(&login_name, 1, "", &LoginRisk::default()), &user).await;
+    if risk.is_risky() {
+        info!("login risk user_id: {}, risk: {:?}", id, risk);
+        tokio::spawn(send_login_risk_notice(state.clone(), user.clone(), client.clone(), risk.clone()));
+    }
+
+    finish_login(&state, user, (btn_menu, is_super), &client, &login_name, &risk, false).await
+}
+
+/*
+ *登录二次验证(登录存在风险时,输入验证码完成登录)
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/loginVerify",
+    tag = "用户信息",
+    summary = "登录二次验证(登录存在风险时,输入验证码完成登录)",
+    security(()),
+    request_body = LoginVerifyReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn login_verify(headers: HeaderMap, ConnectInfo(addr): ConnectInfo<SocketAddr>, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<LoginVerifyReq>) -> impl IntoResponse {
+    info!("user login verify params: {:?}", &item);
+    let rb = &state.batis;
+    let mut conn = state.redis.get_connection()?;
+
+    let client = LoginClient::new(&state, &headers, addr);
+
+    let key = format!("{}{}", LOGIN_VERIFY_KEY, item.verify_token);
+    let verify: HashMap<String, String> = conn.hgetall(&key)?;
+    let (Some(user_id), Some(login_name), Some(code)) = (verify.get("user_id"), verify.get("login_name"), verify.get("code")) else {
+        return Err(AppError::BusinessError(ErrorCode::LoginVerifyExpired));
+    };
+
+    let risk = LoginRisk {
+        level: verify.get("risk_level").and_then(|x| x.parse().ok()).unwrap_or_default(),
+        reasons: verify.get("risk_reason").into_iter().cloned().collect(),
+    };
+
+    if code.ne(&item.code) {
+        let attempts: i64 = conn.hincr(&key, "attempts", 1)?;
+        if attempts >= LOGIN_VERIFY_MAX_ATTEMPTS {
+            conn.del::<_, ()>(&key)?;
+            add_login_log(rb, login_name, 0, "二次验证失败次数过多", &client, &risk).await;
+            return Err(AppError::BusinessError(ErrorCode::LoginVerifyTooManyAttempts));
+        }
+        return Err(AppError::BusinessError(ErrorCode::VerifyCodeIncorrect));
+    }
+    conn.del::<_, ()>(&key)?;
+
+    let user_id = user_id.parse::<i64>().unwrap_or_default();
+    let user = match User::select_by_id(rb, user_id).await? {
+        None => return Err(AppError::BusinessError(ErrorCode::UserNotFound)),
+        Some(x) => x,
+    };
+    // 等待二次验证期间用户可能已被禁用
+    if user.status != 1 {
+        add_login_log(rb, login_name, 0, "用户已被禁用", &client, &risk).await;
+        return Err(AppError::BusinessError(ErrorCode::UserDisabled));
+    }
+
+    let (btn_menu, is_super) = query_btn_menu(&user_id, rb.clone()).await;
+    if btn_menu.is_empty() {
+        add_login_log(rb, login_name, 0, "用户没有分配角色或者菜单,不能登录", &client, &risk).await;
+        return Err(AppError::BusinessError(ErrorCode::UserNoPermission));
+    }
+
+    let local_password = verify.get("local_password").is_none_or(|x| x != "0");
+    finish_login(&state, user, (btn_menu, is_super), &client, login_name, &risk, local_password).await
+}
+
+/*
+ *登录时修改过期的密码(修改成功后完成登录)
+ *author：刘飞华
+ *date：2026/10/18 21:26:40
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/loginUpdatePassword",
+    tag = "用户信息",
+    summary = "登录时修改过期的密码(修改成功后完成登录)",
+    security(()),
+    request_body = LoginUpdatePwdReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn login_update_password(
+    headers: HeaderMap,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<Arc<AppState>>,
+    ValidJson(item): ValidJson<LoginUpdatePwdReq>,
+) -> impl IntoResponse {
+    info!("user login update password params: {:?}", &item.pwd_token);
+    let rb = &state.batis;
+    let mut conn = state.redis.get_connection()?;
+
+    let client = LoginClient::new(&state, &headers, addr);
+
+    let key = format!("{}{}", LOGIN_PWD_KEY, item.pwd_token);
+    let ticket: HashMap<String, String> = conn.hgetall(&key)?;
+    let (Some(user_id), Some(login_name)) = (ticket.get("user_id"), ticket.get("login_name")) else {
+        return Err(AppError::BusinessError(ErrorCode::PasswordChangeExpired));
+    };
+
+    let risk = LoginRisk {
+        level: ticket.get("risk_level").and_then(|x| x.parse().ok()).unwrap_or_default(),
+        reasons: ticket.get("risk_reason").into_iter().filter(|x| !x.is_empty()).cloned().collect(),
+    };
+
+    let user_id = user_id.parse::<i64>().unwrap_or_default();
+    let mut user = match User::select_by_id(rb, user_id).await? {
+        None => return Err(AppError::BusinessError(ErrorCode::UserNotFound)),
+        Some(x) => x,
+    };
+    // 等待修改密码期间用户可能已被禁用
+    if user.status != 1 {
+        conn.del::<_, ()>(&key)?;
+        add_login_log(rb, login_name, 0, "用户已被禁用", &client, &risk).await;
+        return Err(AppError::BusinessError(ErrorCode::UserDisabled));
+    }
+
+    check_password(&state, &user, &item.password).await?;
+    user.password = item.password;
+    user.pwd_update_date = Some(DateTime::now());
+    user.pwd_must_change = 0;
+    User::update_by_map(rb, &user, value! {"id": &user.id}).await?;
+    save_password_history(&state, user_id, &user.password).await?;
+    conn.del::<_, ()>(&key)?;
+
+    let (btn_menu, is_super) = query_btn_menu(&user_id, rb.clone()).await;
+    if btn_menu.is_empty() {
+        add_login_log(rb, login_name, 0, "用户没有分配角色或者菜单,不能登录", &client, &risk).await;
+        return Err(AppError::BusinessError(ErrorCode::UserNoPermission));
+    }
+
+    finish_login(&state, user, (btn_menu, is_super), &client, login_name, &risk, true).await
+}
+
+/*
+ *找回密码: 发送验证码到用户邮箱
+ *author：刘飞华
+ *date：2026/10/18 22:20:36
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/forgotPassword",
+    tag = "用户信息",
+    summary = "找回密码: 发送验证码到用户邮箱",
+    security(()),
+    request_body = ForgotPwdReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn forgot_password(headers: HeaderMap, ConnectInfo(addr): ConnectInfo<SocketAddr>, State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<ForgotPwdReq>) -> impl IntoResponse {
+    info!("forgot password params: {:?}", &item);
+    let rb = &state.batis;
+    let mut conn = state.redis.get_connection()?;
+
+    let email = item.email.trim();
+    if email.is_empty() {
+        return Err(AppError::BusinessError(ErrorCode::EmailRequired));
+    }
+
+    let client_ip = get_client_ip(&headers, addr.ip(), &state.trusted_proxies);
+    if !check_rate_limit(&mut conn, &format!("{}ip:{}", PWD_RESET_LIMIT_KEY, client_ip), 10, 3600)?
+        || !check_rate_limit(&mut conn, &format!("{}email:{}", PWD_RESET_LIMIT_KEY, email.to_lowercase()), 1, 60)?
+    {
+        return Err(AppError::BusinessError(ErrorCode::TooManyRequests));
+    }
+
+    // 不管邮箱是否存在都返回相同的结果,防止探测账号
+    if let Some(user) = User::select_by_email(rb, email).await? {
+        if user.status == 1 {
+            let code = format!("{:06}", rand::thread_rng().gen_range(0..1000000));
+            let key = format!("{}{}", PWD_RESET_KEY, email.to_lowercase());
+            let user_id = user.id.unwrap_or_default().to_string();
+
+            conn.del::<_, ()>(&key)?;
+            conn.hset_multiple::<_, _, _, ()>(&key, &[("user_id", user_id.as_str()), ("code", code.as_str())])?;
+            conn.expire::<_, ()>(&key, PWD_RESET_SECONDS)?;
+
+            let locale = user_locale(&user);
+            let minutes = (PWD_RESET_SECONDS / 60).to_string();
+            let content = translate(locale, "mail.reset_code_body", &[("user_name", &user.user_name), ("code", &code), ("minutes", &minutes)]);
+            send_mail_background(state.mailer.clone(), user.email, translate(locale, "mail.reset_code_subject", &[]), content);
+        }
+    }
+
+    ok_result_msg(&t("password.reset_code_sent"))
+}
+
+/*
+ *找回密码: 校验验证码并设置新密码
+ *author：刘飞华
+ *date：2026/10/18 22:20:36
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/confirmResetPassword",
+    tag = "用户信息",
+    summary = "找回密码: 校验验证码并设置新密码",
+    security(()),
+    request_body = ConfirmResetPwdReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn confirm_reset_password(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<ConfirmResetPwdReq>) -> impl IntoResponse {
+    info!("confirm reset password params: {:?}", &item.email);
+    let rb = &state.batis;
+    let mut conn = state.redis.get_connection()?;
+
+    let key = format!("{}{}", PWD_RESET_KEY, item.email.trim().to_lowercase());
+    let reset: HashMap<String, String> = conn.hgetall(&key)?;
+    let (Some(user_id), Some(code)) = (reset.get("user_id"), reset.get("code")) else {
+        return Err(AppError::BusinessError(ErrorCode::VerifyCodeExpired));
+    };
+
+    if code.ne(&item.code) {
+        let attempts: i64 = conn.hincr(&key, "attempts", 1)?;
+        if attempts >= PWD_RESET_MAX_ATTEMPTS {
+            conn.del::<_, ()>(&key)?;
+            return Err(AppError::BusinessError(ErrorCode::VerifyCodeTooManyAttempts));
         }
+        return Err(AppError::BusinessError(ErrorCode::VerifyCodeIncorrect));
     }
+
+    let user_id = user_id.parse::<i64>().unwrap_or_default();
+    let mut user = match User::select_by_id(rb, user_id).await? {
+        None => return Err(AppError::BusinessError(ErrorCode::UserNotFound)),
+        Some(x) => x,
+    };
+
+    check_password(&state, &user, &item.password).await?;
+    user.password = item.password;
+    user.pwd_update_date = Some(DateTime::now());
+    user.pwd_must_change = 0;
+    User::update_by_map(rb, &user, value! {"id": &user.id}).await?;
+    save_password_history(&state, user_id, &user.password).await?;
+    conn.del::<_, ()>(&key)?;
+
+    force_logout_user(&state, &[user_id], &t("logout.password_reset"))?;
+    ok_result_msg(&t("password.reset_done"))
+}
+
+/*
+ *退出登录(同时断开该用户的消息推送连接)
+ *author：刘飞华
+ *date：2026/10/18 21:58:12
+ */
+#[utoipa::path(
+    post,
+    path = "/api/system/user/logout",
+    tag = "用户信息",
+    summary = "退出登录",
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn logout(headers: HeaderMap, State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
+    info!("user logout params: {:?}", user_id);
+
+    force_logout_user(&state, &[user_id], &t("logout.logged_out")).map(|_| ok_result())?
+}
+
+/*
+ *登录客户端信息(IP、登录地点、User-Agent)
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+#[derive(Clone, Debug)]
+struct LoginClient {
+    agent: UserAgentUtil,
+    ip: String,
+    location: String,
+}
+
+impl LoginClient {
+    fn new(state: &AppState, headers: &HeaderMap, addr: SocketAddr) -> Self {
+        let user_agent = headers.get(USER_AGENT).and_then(|x| x.to_str().ok()).unwrap_or_default();
+        let client_ip = get_client_ip(headers, addr.ip(), &state.trusted_proxies);
+        LoginClient {
+            agent: UserAgentUtil::new(user_agent),
+            ip: client_ip.to_string(),
+            location: state.ip_region.get_location(&client_ip),
+        }
+    }
+
+    fn login_log(&self, name: &str, status: i8, msg: &str, risk: &LoginRisk) -> LoginLog {
+        let agent = self.agent.clone();
+        LoginLog {
+            id: None,                              //访问ID
+            login_name: name.to_string(),          //登录账号
+            ipaddr: self.ip.clone(),               //登录IP地址
+            login_location: self.location.clone(), //登录地点
+            platform: agent.platform,              //平台信息
+            browser: agent.browser,                //浏览器类型
+            version: agent.version,                //浏览器版本
+            os: agent.os,                          //操作系统
+            arch: agent.arch,                      //体系结构信息
+            engine: agent.engine,                  //渲染引擎信息
+            engine_details: agent.engine_details,  //渲染引擎详细信息
+            extra: agent.extra,                    //其他信息（可选）
+            client_type: agent.client_type,        //客户端类型(human:用户,bot:爬虫,api:接口调用)
+            status,                                //登录状态(0:失败,1:成功)
+            msg: msg.to_string(),                  //提示消息
+            risk_level: risk.level,                //风险等级(0:无,1:低,2:中,3:高)
+            risk_reason: risk.reason(),            //风险原因
+            login_time: Some(DateTime::now()),     //访问时间
+        }
+    }
+}
+
+/*
+ *完成登录(生成token,缓存用户权限,记录登录信息)
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+async fn finish_login(
+    state: &AppState,
+    user: User,
+    menu: (Vec<String>, bool),
+    client: &LoginClient,
+    login_name: &str,
+    risk: &LoginRisk,
+    password_login: bool,
+) -> AppResult<Json<BaseResponse<String>>> {
+    let rb = &state.batis;
+    let mut conn = state.redis.get_connection()?;
+    let (btn_menu, is_super) = menu;
+
+    // 密码已过期,需要修改密码后才能登录(单点登录不使用本地密码,不检查)
+    if password_login && PasswordPolicy::load(state).await.is_expired(&user, &DateTime::now()) {
+        let pwd_token = create_login_pwd_token(state, &user, login_name, risk)?;
+        add_login_log(rb, login_name, 0, "密码已过期,等待修改密码", client, risk).await;
+        return Ok(Json(BaseResponse {
+            msg: t("login.password_expired"),
+            code: LOGIN_PWD_EXPIRED_CODE,
+            data: Some(pwd_token),
+        }));
+    }
+
+    let mut s_user = user.clone();
+    let id = user.id.unwrap();
+    // 需要修改密码时生成受限token,只能访问修改密码和退出登录接口
+    let must_change = password_login && user.pwd_must_change == 1;
+    let token = if must_change {
+        JwtToken::new(id, &user.user_name).with_scope(SCOPE_PWD_CHANGE).create_token("123")?
+    } else {
+        JwtToken::new(id, &user.user_name).create_token("123")?
+    };
+
+    let key = format!("axum:admin:user:info:{:?}", id);
+    let old_token: Option<String> = conn.hget(&key, "token")?;
+    // 存储用户权限信息
+    conn.hset::<_, _, _, ()>(&key, "permissions", &btn_menu.join(","))?;
+    // 存储用户名
+    conn.hset::<_, _, _, ()>(&key, "user_name", &s_user.user_name)?;
+    // 存储是否是超级管理员
+    conn.hset::<_, _, _, ()>(&key, "isAdmin", is_super)?;
+    // 存储token
+    conn.hset::<_, _, _, ()>(&key, "token", &token)?;
+    // 原来的token已失效,断开原来的消息推送连接
+    if old_token.is_some_and(|x| !x.is_empty() && x != token) {
+        send_message(state, EVENT_LOGOUT, vec![id], serde_json::json!({ "reason": t("logout.replaced") }));
+    }
+    // 存储语言偏好
+    conn.hset::<_, _, _, ()>(&key, "locale", &s_user.locale)?;
+    // 存储登录时间
+    conn.hset::<_, _, _, ()>(&key, "last_login", Local::now().format("%Y-%m-%d %H:%M:%S").to_string())?;
+
+    add_login_log(rb, login_name, 1, "登录成功", client, risk).await;
+    s_user.login_os = client.agent.os.clone();
+    s_user.login_browser = client.agent.browser.clone();
+    s_user.login_ip = client.ip.clone();
+    s_user.login_date = Some(DateTime::now());
+    User::update_by_map(rb, &s_user, value! {"id": &s_user.id}).await?;
+
+    if must_change {
+        return Ok(Json(BaseResponse {
+            msg: t("login.password_must_change"),
+            code: LOGIN_PWD_CHANGE_CODE,
+            data: Some(token),
+        }));
+    }
+    ok_result_data(token)
+}
+
+/*
+ *生成登录二次验证码(验证码发送到用户邮箱)
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+fn create_login_verify(state: &AppState, user: &User, login_name: &str, risk: &LoginRisk, local_password: bool) -> AppResult<String> {
+    if user.email.is_empty() {
+        return Err(AppError::BusinessError(ErrorCode::LoginVerifyEmailMissing));
+    }
+    let mut conn = state.redis.get_connection()?;
+
+    let verify_token = format!("{:032x}", rand::random::<u128>());
+    let code = format!("{:06}", rand::thread_rng().gen_range(0..1000000));
+
+    let key = format!("{}{}", LOGIN_VERIFY_KEY, verify_token);
+    let user_id = user.id.unwrap_or_default().to_string();
+    let risk_level = risk.level.to_string();
+    let risk_reason = risk.reason();
+    let fields = [
+        ("user_id", user_id.as_str()),
+        ("login_name", login_name),
+        ("code", code.as_str()),
+        ("risk_level", risk_level.as_str()),
+        ("risk_reason", risk_reason.as_str()),
+        ("local_password", if local_password { "1" } else { "0" }),
+    ];
+    conn.hset_multiple::<_, _, _, ()>(&key, &fields)?;
+    conn.expire::<_, ()>(&key, LOGIN_VERIFY_SECONDS)?;
+
+    let locale = user_locale(user);
+    let minutes = (LOGIN_VERIFY_SECONDS / 60).to_string();
+    let content = translate(locale, "mail.login_code_body", &[("code", &code), ("minutes", &minutes)]);
+    send_mail_background(state.mailer.clone(), user.email.clone(), translate(locale, "mail.login_code_subject", &[]), content);
+    Ok(verify_token)
+}
+
+/*
+ *生成修改过期密码的登录凭证
+ *author：刘飞华
+ *date：2026/10/18 21:26:40
+ */
+fn create_login_pwd_token(state: &AppState, user: &User, login_name: &str, risk: &LoginRisk) -> AppResult<String> {
+    let mut conn = state.redis.get_connection()?;
+
+    let pwd_token = format!("{:032x}", rand::random::<u128>());
+
+    let key = format!("{}{}", LOGIN_PWD_KEY, pwd_token);
+    let user_id = user.id.unwrap_or_default().to_string();
+    let risk_level = risk.level.to_string();
+    let risk_reason = risk.reason();
+    let fields = [
+        ("user_id", user_id.as_str()),
+        ("login_name", login_name),
+        ("risk_level", risk_level.as_str()),
+        ("risk_reason", risk_reason.as_str()),
+    ];
+    conn.hset_multiple::<_, _, _, ()>(&key, &fields)?;
+    conn.expire::<_, ()>(&key, LOGIN_PWD_SECONDS)?;
+
+    Ok(pwd_token)
+}
+
+/*
+ *发送登录风险通知(通知用户本人和管理员)
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+async fn send_login_risk_notice(state: Arc<AppState>, user: User, client: LoginClient, risk: LoginRisk) {
+    let time = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let device = format!("{} {} {}", client.agent.platform, client.agent.os, client.agent.browser);
+    let reason = risk.reason();
+
+    // 通知用户本人使用用户的语言偏好,通知管理员使用默认语言
+    let locale = user_locale(&user);
+    let level = translate(locale, risk_level_key(risk.level), &[]);
+    let args = [
+        ("time", time.as_str()),
+        ("location", &client.location),
+        ("ip", &client.ip),
+        ("device", &device),
+        ("level", &level),
+        ("reason", &reason),
+    ];
+    let title = translate(locale, "notice.login_risk_title", &[]);
+    send_system_notice(&state, &title, &translate(locale, "notice.login_risk_content", &args), 3, &[user.id.unwrap_or_default()]).await;
+
+    let locale = Locale::default();
+    let level = translate(locale, risk_level_key(risk.level), &[]);
+    let args = [
+        ("user_name", user.user_name.as_str()),
+        ("time", &time),
+        ("location", &client.location),
+        ("ip", &client.ip),
+        ("level", &level),
+        ("reason", &reason),
+    ];
+    let title = translate(locale, "notice.login_risk_admin_title", &[]);
+    send_system_notice(&state, &title, &translate(locale, "notice.login_risk_admin_content", &args), 2, &[ADMIN_ROLE_ID]).await;
+}
+
+/*
+ *查询单点登录的用户(已关联时直接返回,否则按已验证的邮箱关联已有用户或者自动创建用户)
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+async fn query_oidc_user(state: &AppState, oidc_user: &OidcUser) -> AppResult<Option<User>> {
+    let rb = &state.batis;
+    let config = state.oidc.config();
+    let provider = config.issuer.trim_end_matches('/');
+
+    if let Some(x) = UserIdentity::select_by_subject(rb, provider, &oidc_user.subject).await? {
+        return Ok(User::select_by_id(rb, x.user_id).await?);
+    }
+
+    let linked = match oidc_user.email_verified && !oidc_user.email.is_empty() {
+        true => User::select_by_email(rb, &oidc_user.email).await?,
+        false => None,
+    };
+    let user = match linked {
+        Some(x) => x,
+        None if config.auto_create => {
+            let external = ExternalUser {
+                user_name: [&oidc_user.preferred_username, &oidc_user.email, &oidc_user.subject]
+                    .into_iter()
+                    .find(|x| !x.is_empty())
+                    .cloned()
+                    .unwrap_or_default(),
+                nick_name: oidc_user.name.clone(),
+                email: oidc_user.email.clone(),
+                mobile: oidc_user.phone_number.clone(),
+            };
+            create_external_user(rb, &external, config.dept_id, "单点登录自动创建").await?
+        }
+        None => return Ok(None),
+    };
+
+    let identity = UserIdentity {
+        id: None,
+        user_id: user.id.unwrap_or_default(),
+        provider: provider.to_string(),
+        subject: oidc_user.subject.clone(),
+        create_time: Some(DateTime::now()),
+    };
+    UserIdentity::insert(rb, &identity).await?;
+    info!("link oidc user subject: {}, user_id: {:?}", oidc_user.subject, user.id);
+
+    Ok(Some(user))
+}
+
+// 检查用户状态(禁用的用户不能登录)
+fn check_user_status(user: &User) -> Result<(), ErrorCode> {
+    match user.status {
+        1 => Ok(()),
+        _ => Err(ErrorCode::UserDisabled),
+    }
+}
+
+// 用户的语言偏好(没有设置时使用当前请求的语言)
+fn user_locale(user: &User) -> Locale {
+    Locale::parse(&user.locale).unwrap_or_else(current_locale)
 }
 
 /*
@@ -349,24 +1396,8 @@ pub async fn login(headers: HeaderMap, State(state): State<Arc<AppState>>, Json(
  *author：刘飞华
  *date：2025/01/02 17:01:13
  */
-async fn add_login_log(rb: &RBatis, name: String, status: i8, msg: &str, agent: UserAgentUtil) {
-    let sys_login_log = LoginLog {
-        id: None,                             //访问ID
-        login_name: name,                     //登录账号
-        ipaddr: "todo".to_string(),           //登录IP地址
-        login_location: "todo".to_string(),   //登录地点
-        platform: agent.platform,             //平台信息
-        browser: agent.browser,               //浏览器类型
-        version: agent.version,               //浏览器版本
-        os: agent.os,                         //操作系统
-        arch: agent.arch,                     //体系结构信息
-        engine: agent.engine,                 //渲染引擎信息
-        engine_details: agent.engine_details, //渲染引擎详细信息
-        extra: agent.extra,                   //其他信息（可选）
-        status,                               //登录状态(0:失败,1:成功)
-        msg: msg.to_string(),                 //提示消息
-        login_time: None,                     //访问时间
-    };
+async fn add_login_log(rb: &RBatis, name: &str, status: i8, msg: &str, client: &LoginClient, risk: &LoginRisk) {
+    let sys_login_log = client.login_log(name, status, msg, risk);
 
     match LoginLog::insert(rb, &sys_login_log).await {
         Ok(_u) => info!("add_login_log success: {:?}", sys_login_log),
@@ -379,7 +1410,7 @@ async fn add_login_log(rb: &RBatis, name: String, status: i8, msg: &str, agent:
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-async fn query_btn_menu(id: &i64, rb: RBatis) -> (Vec<String>, bool) {
+pub async fn query_btn_menu(id: &i64, rb: RBatis) -> (Vec<String>, bool) {
     let count = is_admin(&rb, id).await.unwrap_or_default();
     let mut btn_menu: Vec<String> = Vec::new();
     if count == 1 {
@@ -413,7 +1444,15 @@ async fn query_btn_menu(id: &i64, rb: RBatis) -> (Vec<String>, bool) {
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
-pub async fn query_user_role(State(state): State<Arc<AppState>>, Json(item): Json<QueryUserRoleReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/queryUserRole",
+    tag = "用户信息",
+    summary = "查询用户角色",
+    request_body = QueryUserRoleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryUserRoleResp>))
+)]
+pub async fn query_user_role(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<QueryUserRoleReq>) -> impl IntoResponse {
     info!("query user_role params: {:?}", item);
     let rb = &state.batis;
 
@@ -430,7 +1469,15 @@ pub async fn query_user_role(State(state): State<Arc<AppState>>, Json(item): Jso
 }
 
 // 更新用户角色
-pub async fn update_user_role(State(state): State<Arc<AppState>>, Json(item): Json<UpdateUserRoleReq>) -> impl IntoResponse {
+#[utoipa::path(
+    post,
+    path = "/api/system/user/updateUserRole",
+    tag = "用户信息",
+    summary = "更新用户角色",
+    request_body = UpdateUserRoleReq,
+    responses((status = 200, description = "操作成功", body = BaseResponse<String>))
+)]
+pub async fn update_user_role(State(state): State<Arc<AppState>>, ValidJson(item): ValidJson<UpdateUserRoleReq>) -> impl IntoResponse {
     info!("update_user_role params: {:?}", item);
     let rb = &state.batis;
     let mut conn = state.redis.get_connection()?;
@@ -443,7 +1490,7 @@ pub async fn update_user_role(State(state): State<Arc<AppState>>, Json(item): Js
     let is_admin: bool = conn.hget(&key, "isAdmin").unwrap_or_default();
 
     if is_admin {
-        return Err(AppError::BusinessError("不允许操作超级管理员用户"));
+        return Err(AppError::BusinessError(ErrorCode::AdminUserForbidden));
     }
 
     UserRole::delete_by_map(rb, value! {"user_id": user_id}).await?;
@@ -459,10 +1506,19 @@ pub async fn update_user_role(State(state): State<Arc<AppState>>, Json(item): Js
         })
     }
 
-    UserRole::insert_batch(rb, &list, len as u64).await.map(|_| ok_result())?
+    UserRole::insert_batch(rb, &list, len as u64).await?;
+
+    refresh_user_permission(&state, &[user_id]).await.map(|_| ok_result())?
 }
 
 // 查询用户菜单
+#[utoipa::path(
+    get,
+    path = "/api/system/user/queryUserMenu",
+    tag = "用户信息",
+    summary = "查询用户菜单",
+    responses((status = 200, description = "操作成功", body = BaseResponse<QueryUserMenuResp>))
+)]
 pub async fn query_user_menu(headers: HeaderMap, State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let user_id = headers.get("user_id").unwrap().to_str().unwrap().parse::<i64>().unwrap();
     info!("query user menu params user_id {:?}", user_id);
@@ -472,7 +1528,7 @@ pub async fn query_user_menu(headers: HeaderMap, State(state): State<Arc<AppStat
 
     //根据id查询用户
     match User::select_by_id(rb, user_id).await? {
-        None => Err(AppError::BusinessError("用户不存在")),
+        None => Err(AppError::BusinessError(ErrorCode::UserNotFound)),
         Some(user) => {
             let key = format!("axum:admin:user:info:{}", user_id);
             let is_admin: bool = conn.hget(&key, "isAdmin").unwrap_or_default();
@@ -534,3 +1590,34 @@ pub async fn query_user_menu(headers: HeaderMap, State(state): State<Arc<AppStat
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::handler::system::sys_user_handler::*;
+
+    #[test]
+    fn test_user_login_req() {
+        let item: UserLoginReq = serde_json::from_str(r#"{"mobile":"18613030111","password":"123456"}"#).unwrap();
+        assert_eq!(item.account, "18613030111");
+        assert_eq!(item.account_type, None);
+
+        let item: UserLoginReq = serde_json::from_str(r#"{"account":"admin","password":"123456","accountType":"username"}"#).unwrap();
+        assert_eq!(item.account, "admin");
+        assert_eq!(item.account_type.as_deref(), Some("username"));
+    }
+
+    #[test]
+    fn test_check_user_status() {
+        let mut user: User = serde_json::from_value(serde_json::json!({
+            "id": 2, "mobile": "18613030111", "user_name": "test", "nick_name": "test", "user_type": "01", "email": "", "avatar": null,
+            "password": "123456", "status": 1, "dept_id": 1, "login_ip": "", "login_date": null, "login_browser": "", "login_os": "",
+            "pwd_update_date": null, "pwd_must_change": 0, "locale": "", "remark": null, "del_flag": 1, "create_time": null, "update_time": null
+        }))
+        .unwrap();
+        assert_eq!(check_user_status(&user), Ok(()));
+
+        // 密码正确但是用户已被禁用
+        user.status = 0;
+        assert_eq!(check_user_status(&user), Err(ErrorCode::UserDisabled));
+    }
+}
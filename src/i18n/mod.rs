@@ -0,0 +1,173 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::LazyLock;
+
+/*
+ *支持的语言(默认简体中文)
+ *author：刘飞华
+ *date：2026/10/19 02:48:30
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    ZhCn, //简体中文
+    EnUs, //英语
+}
+
+impl Locale {
+    // 语言标签(BCP 47)
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::ZhCn => "zh-CN",
+            Locale::EnUs => "en-US",
+        }
+    }
+
+    // 解析语言标签(不区分大小写,只匹配主语言,如zh-TW、en-GB)
+    pub fn parse(tag: &str) -> Option<Locale> {
+        let tag = tag.trim().to_ascii_lowercase().replace('_', "-");
+        match tag.split('-').next().unwrap_or_default() {
+            "zh" => Some(Locale::ZhCn),
+            "en" => Some(Locale::EnUs),
+            _ => None,
+        }
+    }
+}
+
+// 语言包(编译时嵌入,key为消息key或者错误码)
+static BUNDLES: LazyLock<HashMap<Locale, HashMap<String, String>>> = LazyLock::new(|| {
+    HashMap::from([
+        (Locale::ZhCn, serde_yaml::from_str(include_str!("locales/zh-CN.yaml")).unwrap()),
+        (Locale::EnUs, serde_yaml::from_str(include_str!("locales/en-US.yaml")).unwrap()),
+    ])
+});
+
+tokio::task_local! {
+    // 当前请求的语言(由i18n中间件设置)
+    static LOCALE: Cell<Locale>;
+}
+
+/*
+ *根据Accept-Language协商语言(按q值从高到低选择第一个支持的语言,没有支持的语言时使用简体中文)
+ *author：刘飞华
+ *date：2026/10/19 02:48:30
+ */
+pub fn negotiate(accept_language: &str) -> Locale {
+    let mut list = accept_language
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+            let tag = parts.next()?.trim();
+            let q = parts.find_map(|x| x.trim().strip_prefix("q=")).and_then(|x| x.parse::<f32>().ok()).unwrap_or(1.0);
+            Some((tag, q))
+        })
+        .filter(|(_, q)| *q > 0.0)
+        .collect::<Vec<(&str, f32)>>();
+    // 稳定排序,q值相同时保持请求头中的顺序
+    list.sort_by(|a, b| b.1.total_cmp(&a.1));
+    list.iter().find_map(|(tag, _)| Locale::parse(tag)).unwrap_or_default()
+}
+
+/*
+ *在指定语言下执行(返回执行结果和最终使用的语言,执行过程中可以通过set_locale修改)
+ *author：刘飞华
+ *date：2026/10/19 02:48:30
+ */
+pub async fn scope<F: Future>(locale: Locale, f: F) -> (F::Output, Locale) {
+    LOCALE
+        .scope(Cell::new(locale), async move {
+            let output = f.await;
+            (output, current_locale())
+        })
+        .await
+}
+
+// 修改当前请求的语言(用户设置了语言偏好时使用)
+pub fn set_locale(locale: Locale) {
+    let _ = LOCALE.try_with(|x| x.set(locale));
+}
+
+// 当前请求的语言(不在请求中时为简体中文)
+pub fn current_locale() -> Locale {
+    LOCALE.try_with(|x| x.get()).unwrap_or_default()
+}
+
+// 翻译消息(当前语言)
+pub fn t(key: &str) -> String {
+    translate(current_locale(), key, &[])
+}
+
+// 翻译消息并替换参数{name}(当前语言)
+pub fn tr(key: &str, args: &[(&str, &str)]) -> String {
+    translate(current_locale(), key, args)
+}
+
+// 消息是否存在
+pub fn contains_key(key: &str) -> bool {
+    BUNDLES[&Locale::ZhCn].contains_key(key)
+}
+
+/*
+ *翻译消息: 当前语言没有时使用简体中文,都没有时返回key
+ *author：刘飞华
+ *date：2026/10/19 02:48:30
+ */
+pub fn translate(locale: Locale, key: &str, args: &[(&str, &str)]) -> String {
+    let message = BUNDLES[&locale].get(key).or_else(|| BUNDLES[&Locale::ZhCn].get(key)).map(|x| x.as_str()).unwrap_or(key);
+    args.iter().fold(message.to_string(), |message, (name, value)| message.replace(&format!("{{{}}}", name), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::error_code::ErrorCode;
+    use crate::i18n::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(negotiate(""), Locale::ZhCn);
+        assert_eq!(negotiate("en-US,en;q=0.9"), Locale::EnUs);
+        assert_eq!(negotiate("fr-FR,en;q=0.8,zh-CN;q=0.9"), Locale::ZhCn);
+        assert_eq!(negotiate("fr-FR,en-GB;q=0.5"), Locale::EnUs);
+        assert_eq!(negotiate("ja,de;q=0.5"), Locale::ZhCn);
+        assert_eq!(negotiate("en;q=0,zh-TW"), Locale::ZhCn);
+        assert_eq!(Locale::parse("EN_us"), Some(Locale::EnUs));
+        assert_eq!(Locale::parse("fr"), None);
+    }
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(translate(Locale::ZhCn, "USER_NOT_FOUND", &[]), "用户不存在");
+        assert_eq!(translate(Locale::EnUs, "USER_NOT_FOUND", &[]), "User does not exist");
+        assert_eq!(
+            translate(Locale::EnUs, "auth.no_permission", &[("url", "/system/user/addUser")]),
+            "No permission for url: /system/user/addUser"
+        );
+        // 没有的key返回key本身
+        assert_eq!(translate(Locale::EnUs, "unknown.key", &[]), "unknown.key");
+        assert_eq!(t("common.success"), "操作成功");
+    }
+
+    #[test]
+    fn test_bundles() {
+        let zh = BUNDLES[&Locale::ZhCn].keys().collect::<BTreeSet<&String>>();
+        let en = BUNDLES[&Locale::EnUs].keys().collect::<BTreeSet<&String>>();
+        assert_eq!(zh, en);
+        for x in ErrorCode::ALL {
+            assert!(contains_key(x.code()), "missing message: {}", x.code());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scope() {
+        let (message, locale) = scope(Locale::EnUs, async {
+            let before = t("common.success");
+            set_locale(Locale::ZhCn);
+            (before, t("common.success"))
+        })
+        .await;
+        assert_eq!(message, ("Success".to_string(), "操作成功".to_string()));
+        assert_eq!(locale, Locale::ZhCn);
+    }
+}
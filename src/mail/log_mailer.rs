@@ -0,0 +1,18 @@
+use crate::common::error::AppResult;
+use crate::mail::Mailer;
+use async_trait::async_trait;
+
+/*
+ *邮件输出到日志(开发环境使用,不实际发送)
+ *author：刘飞华
+ *date：2026/10/18 22:20:36
+ */
+pub struct LogMailer;
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, to: &str, subject: &str, body: &str) -> AppResult<()> {
+        log::info!("send mail to: {}, subject: {}, body: {}", to, subject, body);
+        Ok(())
+    }
+}
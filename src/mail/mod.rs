@@ -0,0 +1,108 @@
+pub mod log_mailer;
+pub mod smtp_mailer;
+
+use crate::common::error::AppResult;
+use crate::mail::log_mailer::LogMailer;
+use crate::mail::smtp_mailer::SmtpMailer;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/*
+ *邮件发送(SMTP、开发环境输出到日志)
+ *author：刘飞华
+ *date：2026/10/18 22:20:36
+ */
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    // 发送纯文本邮件
+    async fn send(&self, to: &str, subject: &str, body: &str) -> AppResult<()>;
+}
+
+/*
+ *邮件发送配置
+ *author：刘飞华
+ *date：2026/10/18 22:20:36
+ */
+#[derive(Clone, Debug, Deserialize)]
+pub struct MailConfig {
+    #[serde(default = "default_kind")]
+    pub kind: String, //发送方式(log:输出到日志,smtp:SMTP服务器)
+    #[serde(default)]
+    pub host: String, //SMTP服务器地址
+    #[serde(default = "default_port")]
+    pub port: u16, //SMTP服务器端口
+    #[serde(default = "default_tls")]
+    pub tls: String, //加密方式(none:不加密,starttls:STARTTLS,tls:SSL/TLS)
+    #[serde(default)]
+    pub username: String, //登录账号(为空时不登录)
+    #[serde(default)]
+    pub password: String, //登录密码
+    #[serde(default = "default_from")]
+    pub from: String, //发件人
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        MailConfig {
+            kind: default_kind(),
+            host: String::new(),
+            port: default_port(),
+            tls: default_tls(),
+            username: String::new(),
+            password: String::new(),
+            from: default_from(),
+        }
+    }
+}
+
+fn default_kind() -> String {
+    "log".to_string()
+}
+
+fn default_port() -> u16 {
+    587
+}
+
+fn default_tls() -> String {
+    "starttls".to_string()
+}
+
+fn default_from() -> String {
+    "faxum-admin <noreply@localhost>".to_string()
+}
+
+/*
+ *根据配置创建邮件发送
+ *author：刘飞华
+ *date：2026/10/18 22:20:36
+ */
+pub fn build_mailer(config: &MailConfig) -> Arc<dyn Mailer> {
+    match config.kind.as_str() {
+        "smtp" => match SmtpMailer::new(config) {
+            Ok(x) => Arc::new(x),
+            Err(e) => {
+                log::error!("init smtp mailer error: {:?}, use log mailer", e);
+                Arc::new(LogMailer)
+            }
+        },
+        "log" => Arc::new(LogMailer),
+        x => {
+            log::error!("unknown mail kind: {}, use log mailer", x);
+            Arc::new(LogMailer)
+        }
+    }
+}
+
+/*
+ *后台发送邮件(不等待发送结果,发送失败时记录日志)
+ *author：刘飞华
+ *date：2026/10/18 22:20:36
+ */
+pub fn send_mail_background(mailer: Arc<dyn Mailer>, to: String, subject: String, body: String) {
+    tokio::spawn(async move {
+        if let Err(e) = mailer.send(&to, &subject, &body).await {
+            log::error!("send mail error to: {}, subject: {}, error message: {:?}", to, subject, e);
+        }
+    });
+}
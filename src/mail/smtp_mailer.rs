@@ -0,0 +1,124 @@
+use crate::common::error::{AppError, AppResult};
+use crate::mail::{MailConfig, Mailer};
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::time::Duration;
+
+/*
+ *通过SMTP服务器发送邮件
+ *author：刘飞华
+ *date：2026/10/18 22:20:36
+ */
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(config: &MailConfig) -> AppResult<Self> {
+        let builder = match config.tls.as_str() {
+            "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host).map_err(|e| AppError::MailError(e.to_string()))?,
+            "starttls" => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host).map_err(|e| AppError::MailError(e.to_string()))?,
+            _ => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+        };
+
+        let mut builder = builder.port(config.port).timeout(Some(Duration::from_secs(10)));
+        if !config.username.is_empty() {
+            builder = builder.credentials(Credentials::new(config.username.clone(), config.password.clone()));
+        }
+
+        Ok(SmtpMailer {
+            transport: builder.build(),
+            from: config.from.parse().map_err(|e| AppError::MailError(format!("发件人格式不正确: {}", e)))?,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, to: &str, subject: &str, body: &str) -> AppResult<()> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to.parse().map_err(|e| AppError::MailError(format!("收件人格式不正确: {}", e)))?)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body.to_string())
+            .map_err(|e| AppError::MailError(e.to_string()))?;
+
+        self.transport.send(message).await.map(|_| ()).map_err(|e| AppError::MailError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mail::smtp_mailer::*;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    // 本地SMTP接收服务: 只实现发送邮件需要的命令,把收到的邮件内容保存下来
+    async fn smtp_sink(listener: TcpListener, received: Arc<Mutex<Vec<String>>>) {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+
+        writer.write_all(b"220 localhost ESMTP sink\r\n").await.unwrap();
+        let mut data: Option<Vec<String>> = None;
+        while let Some(line) = lines.next_line().await.unwrap() {
+            if let Some(body) = data.as_mut() {
+                if line == "." {
+                    received.lock().unwrap().push(body.join("\n"));
+                    data = None;
+                    writer.write_all(b"250 OK\r\n").await.unwrap();
+                } else {
+                    body.push(line);
+                }
+                continue;
+            }
+
+            let command = line.to_ascii_uppercase();
+            let reply: &[u8] = if command.starts_with("EHLO") || command.starts_with("HELO") {
+                b"250 localhost\r\n"
+            } else if command.starts_with("DATA") {
+                data = Some(Vec::new());
+                b"354 End data with <CR><LF>.<CR><LF>\r\n"
+            } else if command.starts_with("QUIT") {
+                writer.write_all(b"221 Bye\r\n").await.unwrap();
+                break;
+            } else {
+                b"250 OK\r\n"
+            };
+            writer.write_all(reply).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_smtp_mailer() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = tokio::spawn(smtp_sink(listener, received.clone()));
+
+        let config = MailConfig {
+            kind: "smtp".to_string(),
+            host: "127.0.0.1".to_string(),
+            port,
+            tls: "none".to_string(),
+            ..Default::default()
+        };
+        let mailer = SmtpMailer::new(&config).unwrap();
+        mailer.send("user@example.com", "找回密码", "您的验证码是: 123456").await.unwrap();
+        drop(mailer);
+        sink.await.unwrap();
+
+        let received = received.lock().unwrap().clone();
+        assert_eq!(received.len(), 1);
+        assert!(received[0].contains("To: user@example.com"));
+        assert!(received[0].contains("From: faxum-admin <noreply@localhost>"));
+
+        assert!(SmtpMailer::new(&config).unwrap().send("not an email", "subject", "body").await.is_err());
+    }
+}
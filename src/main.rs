@@ -1,39 +1,80 @@
 #[macro_use]
 extern crate rbatis;
 
+pub mod auth;
 pub mod common;
 pub mod handler;
+pub mod i18n;
+pub mod mail;
 pub mod middleware;
 pub mod model;
+pub mod oidc;
+pub mod openapi;
 pub mod route;
+pub mod sms;
+pub mod storage;
+pub mod task;
 pub mod utils;
 pub mod vo;
 
 use axum::{middleware as md, Router};
 
+use crate::auth::{build_auth_providers, AuthConfig, AuthProvider};
+use crate::mail::{build_mailer, MailConfig, Mailer};
+use crate::oidc::{OidcClient, OidcConfig};
+use crate::openapi::{build_openapi_route, OpenApiConfig};
+use crate::route::system::sys_api_key_route::build_sys_api_key_route;
+use crate::route::system::sys_config_route::build_sys_config_route;
 use crate::route::system::sys_dept_route::build_sys_dept_route;
 use crate::route::system::sys_dict_data_route::build_sys_dict_data_route;
 use crate::route::system::sys_dict_type_route::build_sys_dict_type_route;
+use crate::route::system::sys_file_route::build_sys_file_route;
+use crate::route::system::sys_job_log_route::build_sys_job_log_route;
+use crate::route::system::sys_job_route::build_sys_job_route;
 use crate::route::system::sys_login_log_route::build_sys_login_log_route;
+use crate::route::system::sys_message_route::build_sys_message_route;
 use crate::route::system::sys_notice_route::build_sys_notice_route;
 use crate::route::system::sys_operate_log_route::build_sys_operate_log_route;
 use crate::route::system::sys_post_route::build_sys_post_route;
+use crate::route::system::sys_profile_route::build_sys_profile_route;
+use crate::sms::{build_sms_provider, SmsConfig, SmsProvider};
+use crate::storage::{build_storage, Storage, StorageConfig};
+use crate::task::build_job_scheduler;
+use crate::task::notice_task::start_notice_task;
+use crate::task::scheduler::JobScheduler;
+use crate::utils::ip_region_util::IpRegion;
+use crate::utils::ip_util::{parse_trusted_proxies, IpNet};
+use crate::utils::message_util::{start_message_subscriber, PushMessage};
 use crate::utils::redis_util::init_redis;
+use common::error::set_legacy_status;
 use config::{Config, File};
 use middleware::auth::auth;
+use middleware::i18n::i18n;
 use rbatis::RBatis;
 use redis::Client;
 use route::system::sys_menu_route::build_sys_menu_route;
 use route::system::sys_role_route::build_sys_role_route;
 use route::system::sys_user_route::build_sys_user_route;
 use serde::Deserialize;
+use std::net::SocketAddr;
 use std::sync::Arc;
+use tokio::sync::broadcast;
 use utils::db::init_db;
 
 // 定义应用状态结构体，包含数据库连接池
 pub struct AppState {
     pub batis: RBatis,
     pub redis: Client,
+    pub message: broadcast::Sender<PushMessage>,    //消息推送广播通道
+    pub scheduler: JobScheduler,                    //定时任务调度器
+    pub trusted_proxies: Vec<IpNet>,                //可信代理(只信任来自这些地址的X-Forwarded-For/X-Real-IP)
+    pub ip_region: IpRegion,                        //离线IP地址库
+    pub storage: Box<dyn Storage>,                  //文件存储
+    pub storage_config: StorageConfig,              //文件存储配置(大小和类型限制)
+    pub mailer: Arc<dyn Mailer>,                    //邮件发送
+    pub sms: Arc<dyn SmsProvider>,                  //短信发送
+    pub oidc: OidcClient,                           //单点登录
+    pub auth_providers: Vec<Box<dyn AuthProvider>>, //账号密码认证方式(按顺序认证)
 }
 
 // 配置结构体，包含服务器和数据库配置
@@ -42,12 +83,28 @@ struct Config1 {
     server: ServerConfig,
     db: DbConfig,
     redis: RedisConfig,
+    #[serde(default)]
+    ip: IpConfig,
+    #[serde(default)]
+    storage: StorageConfig,
+    #[serde(default)]
+    mail: MailConfig,
+    #[serde(default)]
+    sms: SmsConfig,
+    #[serde(default)]
+    oidc: OidcConfig,
+    #[serde(default)]
+    auth: AuthConfig,
+    #[serde(default)]
+    openapi: OpenApiConfig,
 }
 
 // 服务器配置结构体，包含服务器地址
 #[derive(Debug, Deserialize)]
 struct ServerConfig {
     addr: String,
+    #[serde(default)]
+    legacy_status: bool, //兼容旧客户端,所有错误都返回HTTP 200
 }
 
 // 数据库配置结构体，包含数据库URL
@@ -60,6 +117,15 @@ struct DbConfig {
 struct RedisConfig {
     url: String,
 }
+
+// 客户端IP配置结构体，包含可信代理列表和离线IP地址库文件
+#[derive(Debug, Default, Deserialize)]
+struct IpConfig {
+    #[serde(default)]
+    trusted_proxies: Vec<String>,
+    #[serde(default)]
+    region_db: String,
+}
 // 主函数，使用tokio异步运行时
 #[tokio::main]
 async fn main() {
@@ -68,6 +134,7 @@ async fn main() {
 
     // 加载和解析配置文件
     let config = Config::builder().add_source(File::with_name("config.toml")).build().unwrap().try_deserialize::<Config1>().unwrap();
+    set_legacy_status(config.server.legacy_status);
     println!("Config: {:?}", config);
 
     // 初始化数据库连接
@@ -75,25 +142,56 @@ async fn main() {
     let rd = init_redis(config.redis.url.as_str()).await;
 
     // 创建共享应用状态，包含数据库连接池
-    let shared_state = Arc::new(AppState { batis: rb, redis: rd });
+    // 订阅redis消息频道,推送给当前实例的在线用户
+    let (message, _) = broadcast::channel(1024);
+    start_message_subscriber(rd.clone(), message.clone());
+
+    let shared_state = Arc::new(AppState {
+        batis: rb,
+        redis: rd,
+        message,
+        scheduler: build_job_scheduler(),
+        trusted_proxies: parse_trusted_proxies(&config.ip.trusted_proxies),
+        ip_region: IpRegion::load(&config.ip.region_db),
+        storage: build_storage(&config.storage),
+        storage_config: config.storage,
+        mailer: build_mailer(&config.mail),
+        sms: build_sms_provider(&config.sms),
+        oidc: OidcClient::new(config.oidc),
+        auth_providers: build_auth_providers(&config.auth),
+    });
+
+    // 启动后台定时任务
+    start_notice_task(Arc::clone(&shared_state));
+    shared_state.scheduler.start(Arc::clone(&shared_state));
 
     // 构建应用路由，并合并多个子路由
-    let app = Router::new().nest(
-        "/api",
-        Router::new()
-            .merge(build_sys_user_route())
-            .merge(build_sys_role_route())
-            .merge(build_sys_menu_route())
-            .merge(build_sys_dept_route())
-            .merge(build_sys_dict_type_route())
-            .merge(build_sys_dict_data_route())
-            .merge(build_sys_post_route())
-            .merge(build_sys_login_log_route())
-            .merge(build_sys_operate_log_route())
-            .merge(build_sys_notice_route())
-            .route_layer(md::from_fn_with_state(Arc::clone(&shared_state), auth)) // 添加认证中间件
-            .with_state(shared_state), // 设置共享状态
-    );
+    let app = Router::new()
+        .merge(build_openapi_route(&config.openapi)) // 接口文档(不需要认证)
+        .nest(
+            "/api",
+            Router::new()
+                .merge(build_sys_user_route())
+                .merge(build_sys_role_route())
+                .merge(build_sys_menu_route())
+                .merge(build_sys_dept_route())
+                .merge(build_sys_dict_type_route())
+                .merge(build_sys_dict_data_route())
+                .merge(build_sys_post_route())
+                .merge(build_sys_login_log_route())
+                .merge(build_sys_operate_log_route())
+                .merge(build_sys_notice_route())
+                .merge(build_sys_config_route())
+                .merge(build_sys_message_route())
+                .merge(build_sys_job_route())
+                .merge(build_sys_job_log_route())
+                .merge(build_sys_profile_route())
+                .merge(build_sys_api_key_route())
+                .merge(build_sys_file_route(shared_state.storage_config.max_size))
+                .route_layer(md::from_fn_with_state(Arc::clone(&shared_state), auth)) // 添加认证中间件
+                .layer(md::from_fn(i18n)) // 添加多语言中间件(在认证中间件之前执行)
+                .with_state(shared_state), // 设置共享状态
+        );
 
     // 以下代码适用于axum 0.6.x版本
     // 定义服务器监听地址
@@ -108,6 +206,6 @@ async fn main() {
     // 以下代码适用于axum 0.7.x版本
     // 创建TCP监听器
     let listener = tokio::net::TcpListener::bind(config.server.addr).await.unwrap();
-    // 使用监听器启动服务器
-    axum::serve(listener, app).await.unwrap();
+    // 使用监听器启动服务器(记录连接的socket地址,用于获取客户端IP)
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await.unwrap();
 }
@@ -1,34 +1,92 @@
 use crate::common::error::AppError;
-use crate::common::result::BaseResponse;
-use crate::utils::jwt_util::JwtToken;
+use crate::handler::system::sys_profile_handler::AVATAR_PATH;
+use crate::i18n::{set_locale, t, tr, Locale};
+use crate::utils::api_key_util::{verify_api_key, ApiKeyAccess, API_KEY_HEADER, API_KEY_PREFIX};
+use crate::utils::ip_util::get_client_ip;
+use crate::utils::jwt_util::{JwtToken, SCOPE_PWD_CHANGE};
 use crate::AppState;
-use axum::extract::{Request, State};
+use axum::extract::{ConnectInfo, Request, State};
 use axum::http::StatusCode;
 use axum::middleware::Next;
 use axum::response::IntoResponse;
-use axum::{http, response, Json};
+use axum::{http, response};
 use redis::{Client, Commands};
+use std::net::SocketAddr;
 use std::sync::Arc;
 
+// 登录用户均可访问的接口(不需要分配菜单权限)
+const COMMON_PATHS: [&str; 17] = [
+    "/system/user/updateUserPassword",
+    "/system/user/logout",
+    "/system/message/stream",
+    "/system/user/profile",
+    "/system/user/profile/updateProfile",
+    "/system/user/profile/updateLocale",
+    "/system/user/profile/uploadAvatar",
+    "/system/notice/queryMyNoticeList",
+    "/system/notice/queryMyUnreadCount",
+    "/system/notice/readNotice",
+    "/system/notice/readAllNotice",
+    "/system/file/upload",
+    "/system/file/download",
+    "/system/apiKey/addApiKey",
+    "/system/apiKey/updateApiKey",
+    "/system/apiKey/revokeApiKey",
+    "/system/apiKey/queryApiKeyList",
+];
+
+// 受限token(需要修改密码)可以访问的接口
+const PWD_CHANGE_PATHS: [&str; 2] = ["/system/user/updateUserPassword", "/system/user/logout"];
+
+// 不需要登录就可以访问的接口
+const PUBLIC_PATHS: [&str; 9] = [
+    "/system/user/login",
+    "/system/user/loginVerify",
+    "/system/user/sendLoginCode",
+    "/system/user/loginByCode",
+    "/system/user/oidcAuthorize",
+    "/system/user/oidcCallback",
+    "/system/user/loginUpdatePassword",
+    "/system/user/forgotPassword",
+    "/system/user/confirmResetPassword",
+];
+
+// API密钥不能访问的接口(修改密码、退出登录、修改个人资料和管理API密钥需要用户登录)
+const API_KEY_DENY_PATHS: [&str; 6] = [
+    "/system/user/updateUserPassword",
+    "/system/user/logout",
+    "/system/apiKey/",
+    "/system/user/profile/updateProfile",
+    "/system/user/profile/updateLocale",
+    "/system/user/profile/uploadAvatar",
+];
+
+// 消息推送连接(浏览器EventSource不能设置请求头,允许通过url参数token传递)
+const MESSAGE_STREAM_PATH: &str = "/system/message/stream";
+
 pub async fn auth(State(state): State<Arc<AppState>>, mut req: Request, next: Next) -> Result<response::Response, StatusCode> {
-    log::info!("req {:?}", req.uri());
-    let path = req.uri().to_string();
-    if path.eq("/system/user/login") {
+    log::info!("req {:?}", req.uri().path()); // 不记录查询参数(消息推送连接的token在查询参数中)
+    let path = req.uri().path().to_string();
+    if PUBLIC_PATHS.contains(&path.as_str()) || path.starts_with(AVATAR_PATH) {
         return Ok(next.run(req).await);
     }
-    let auth_header = req.headers().get(http::header::AUTHORIZATION).and_then(|header| header.to_str().ok());
+    if let Some(api_key) = query_api_key(&req) {
+        return Ok(api_key_auth(&state, req, next, &api_key, &path).await);
+    }
+
+    let auth_header = req.headers().get(http::header::AUTHORIZATION).and_then(|header| header.to_str().ok()).map(|x| x.to_string());
+    let auth_header = if auth_header.is_none() && path == MESSAGE_STREAM_PATH {
+        query_token(req.uri().query().unwrap_or_default())
+    } else {
+        auth_header
+    };
 
     if auth_header.is_none() {
-        let json = Json(BaseResponse {
-            msg: "请求头缺少 Authorization 字段".to_string(),
-            code: 401,
-            data: Some("None".to_string()),
-        });
-        return Ok((StatusCode::OK, json).into_response());
+        return Ok(AppError::Unauthorized(t("auth.missing_authorization")).into_response());
     }
     let authorization = auth_header.unwrap();
 
-    let token = authorization.to_string().replace("Bearer ", "");
+    let token = authorization.replace("Bearer ", "");
     let jwt_token_e = JwtToken::verify("123", &token);
     let jwt_token = match jwt_token_e {
         Ok(data) => data,
@@ -37,62 +95,93 @@ pub async fn auth(State(state): State<Arc<AppState>>, mut req: Request, next: Ne
                 AppError::JwtTokenError(s) => s,
                 _ => "no math error".to_string(),
             };
-            let json = Json(BaseResponse {
-                msg: er,
-                code: 401,
-                data: Some("None".to_string()),
-            });
-            return Ok((StatusCode::OK, json).into_response());
+            return Ok(AppError::Unauthorized(er).into_response());
         }
     };
 
     match validate_and_get_user_info(&state.redis, jwt_token.id).await {
-        Ok((user_id, permissions, token_1, is_admin)) => {
+        Ok((user_id, permissions, token_1, is_admin, locale)) => {
             if token_1 != token {
-                let json = Json(BaseResponse {
-                    msg: "无效的token".to_string(),
-                    code: 401,
-                    data: Some("None".to_string()),
-                });
-                return Ok((StatusCode::OK, json).into_response());
+                return Ok(AppError::Unauthorized(t("auth.invalid_token")).into_response());
             }
-            if is_admin || has_permission(&permissions, &path) {
+            // 用户设置了语言偏好时优先使用
+            if let Some(locale) = Locale::parse(&locale) {
+                set_locale(locale);
+            }
+            if jwt_token.scope == SCOPE_PWD_CHANGE && !PWD_CHANGE_PATHS.contains(&path.as_str()) {
+                return Ok(AppError::Forbidden(t("auth.password_change_required")).into_response());
+            }
+            if is_admin || COMMON_PATHS.contains(&path.as_str()) || has_permission(&permissions, &path) {
                 req.headers_mut().insert("user_id", user_id.to_string().parse().unwrap());
 
                 Ok(next.run(req).await)
             } else {
-                let json = Json(BaseResponse {
-                    msg: format!("用户还没有授权url:{}", path),
-                    code: 401,
-                    data: Some("None".to_string()),
-                });
-                Ok((StatusCode::OK, json).into_response())
+                Ok(AppError::Forbidden(tr("auth.no_permission", &[("url", &path)])).into_response())
             }
         }
-        Err(e) => {
-            let json = Json(BaseResponse {
-                msg: e.to_string(),
-                code: 401,
-                data: Some("None".to_string()),
-            });
-            Ok((StatusCode::OK, json).into_response())
+        Err(e) => Ok(AppError::Unauthorized(e).into_response()),
+    }
+}
+
+// API密钥认证(请求头X-API-Key或者Authorization: Bearer fak_xxx)
+async fn api_key_auth(state: &AppState, mut req: Request, next: Next, api_key: &str, path: &str) -> response::Response {
+    if API_KEY_DENY_PATHS.iter().any(|x| path.starts_with(x)) {
+        return AppError::Forbidden(tr("auth.api_key_denied", &[("url", path)])).into_response();
+    }
+
+    let remote_ip = req.extensions().get::<ConnectInfo<SocketAddr>>().map(|x| x.0.ip()).unwrap_or([0, 0, 0, 0].into());
+    let ip = get_client_ip(req.headers(), remote_ip, &state.trusted_proxies).to_string();
+    match verify_api_key(state, api_key, &ip).await {
+        Ok(access) => {
+            if api_key_allowed(&access, path) {
+                req.headers_mut().insert("user_id", access.user_id.to_string().parse().unwrap());
+                next.run(req).await
+            } else {
+                AppError::Forbidden(tr("auth.api_key_no_permission", &[("url", path)])).into_response()
+            }
         }
+        Err(e) => AppError::Unauthorized(e).into_response(),
     }
 }
 
-async fn validate_and_get_user_info(redis_client: &Client, user_id: i64) -> Result<(i64, Vec<String>, String, bool), String> {
-    let mut conn = redis_client.get_connection().map_err(|_| "Redis连接失败".to_string())?;
+// API密钥是否可以访问接口(限制了接口的密钥不能访问公共接口,只能访问明确授权的接口)
+fn api_key_allowed(access: &ApiKeyAccess, path: &str) -> bool {
+    if API_KEY_DENY_PATHS.iter().any(|x| path.starts_with(x)) {
+        return false;
+    }
+    if access.restricted {
+        return has_permission(&access.permissions, path);
+    }
+    access.is_admin || COMMON_PATHS.contains(&path) || has_permission(&access.permissions, path)
+}
+
+fn query_api_key(req: &Request) -> Option<String> {
+    let headers = req.headers();
+    if let Some(x) = headers.get(API_KEY_HEADER).and_then(|x| x.to_str().ok()) {
+        return Some(x.trim().to_string());
+    }
+    headers
+        .get(http::header::AUTHORIZATION)
+        .and_then(|x| x.to_str().ok())
+        .and_then(|x| x.strip_prefix("Bearer "))
+        .filter(|x| x.starts_with(API_KEY_PREFIX))
+        .map(|x| x.trim().to_string())
+}
+
+async fn validate_and_get_user_info(redis_client: &Client, user_id: i64) -> Result<(i64, Vec<String>, String, bool, String), String> {
+    let mut conn = redis_client.get_connection().map_err(|_| t("auth.redis_unavailable"))?;
 
     let key = format!("axum:admin:user:info:{}", user_id);
     let permissions_str: String = conn.hget(&key, "permissions").unwrap_or_else(|_| "".to_string());
-    let token: String = conn.hget(&key, "token").map_err(|_| "无效的token".to_string())?;
+    let token: String = conn.hget(&key, "token").map_err(|_| t("auth.invalid_token"))?;
     let is_admin: bool = conn.hget(&key, "isAdmin").unwrap_or_default();
+    let locale: String = conn.hget(&key, "locale").unwrap_or_default();
     let permissions: Vec<String> = if permissions_str.is_empty() {
         Vec::new()
     } else {
         permissions_str.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect()
     };
-    Ok((user_id, permissions, token, is_admin))
+    Ok((user_id, permissions, token, is_admin, locale))
 }
 
 fn has_permission(permissions: &[String], path: &str) -> bool {
@@ -108,3 +197,41 @@ fn has_permission(permissions: &[String], path: &str) -> bool {
         }
     })
 }
+
+fn query_token(query: &str) -> Option<String> {
+    query.split('&').find_map(|x| x.strip_prefix("token=")).filter(|x| !x.is_empty()).map(|x| x.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::middleware::auth::*;
+
+    fn api_key_access(permissions: &[&str], is_admin: bool, restricted: bool) -> ApiKeyAccess {
+        ApiKeyAccess {
+            user_id: 1,
+            permissions: permissions.iter().map(|x| x.to_string()).collect(),
+            is_admin,
+            restricted,
+        }
+    }
+
+    #[test]
+    fn test_api_key_allowed() {
+        let restricted = api_key_access(&["/api/system/user/queryUserList", "/api/system/user/profile/updateProfile"], false, true);
+        assert!(api_key_allowed(&restricted, "/system/user/queryUserList"));
+        assert!(!api_key_allowed(&restricted, "/system/user/profile/updateProfile"));
+        assert!(!api_key_allowed(&restricted, "/system/user/profile"));
+        assert!(!api_key_allowed(&restricted, "/system/file/download"));
+
+        let inherited = api_key_access(&["/api/system/user/queryUserList"], false, false);
+        assert!(api_key_allowed(&inherited, "/system/user/profile"));
+        assert!(api_key_allowed(&inherited, "/system/file/download"));
+        assert!(!api_key_allowed(&inherited, "/system/user/profile/updateProfile"));
+        assert!(!api_key_allowed(&inherited, "/system/role/queryRoleList"));
+
+        let admin = api_key_access(&[], true, false);
+        assert!(api_key_allowed(&admin, "/system/role/queryRoleList"));
+        assert!(!api_key_allowed(&admin, "/system/user/profile/updateLocale"));
+        assert!(!api_key_allowed(&admin, "/system/apiKey/addApiKey"));
+    }
+}
@@ -0,0 +1,20 @@
+use crate::i18n::{negotiate, scope};
+use axum::extract::Request;
+use axum::http::header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE};
+use axum::http::HeaderValue;
+use axum::middleware::Next;
+use axum::response::Response;
+
+/*
+ *根据请求头Accept-Language设置当前请求的语言(登录用户设置了语言偏好时由认证中间件覆盖)
+ *响应头Content-Language返回实际使用的语言
+ *author：刘飞华
+ *date：2026/10/19 02:48:30
+ */
+pub async fn i18n(req: Request, next: Next) -> Response {
+    let locale = req.headers().get(ACCEPT_LANGUAGE).and_then(|x| x.to_str().ok()).map(negotiate).unwrap_or_default();
+
+    let (mut response, locale) = scope(locale, next.run(req)).await;
+    response.headers_mut().insert(CONTENT_LANGUAGE, HeaderValue::from_static(locale.tag()));
+    response
+}
@@ -1,14 +1,22 @@
+pub mod sys_api_key_model;
+pub mod sys_config_model;
 pub mod sys_dept_model;
 pub mod sys_dict_data_model;
 pub mod sys_dict_type_model;
+pub mod sys_file_model;
+pub mod sys_job_log_model;
+pub mod sys_job_model;
 pub mod sys_login_log_model;
 pub mod sys_menu_model;
 pub mod sys_notice_model;
+pub mod sys_notice_read_model;
 pub mod sys_operate_log_model;
 pub mod sys_post_model;
 pub mod sys_role_dept_model;
 pub mod sys_role_menu_model;
 pub mod sys_role_model;
+pub mod sys_user_identity_model;
 pub mod sys_user_model;
 pub mod sys_user_post_model;
+pub mod sys_user_pwd_history_model;
 pub mod sys_user_role_model;
@@ -0,0 +1,154 @@
+// author：刘飞华
+// createTime：2026/10/19 01:20:42
+
+use crate::common::page::SortColumns;
+use crate::vo::system::sys_api_key_vo::{ApiKeyResp, QueryApiKeyListReq};
+use rbatis::rbdc::datetime::DateTime;
+use rbatis::RBatis;
+use rbs::value;
+use serde::{Deserialize, Serialize};
+/*
+ *API密钥
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ApiKey {
+    pub id: Option<i64>,                  //主键
+    pub user_id: i64,                     //所属用户id
+    pub key_name: String,                 //密钥名称
+    pub key_prefix: String,               //密钥前缀(用于识别密钥)
+    pub key_hash: String,                 //密钥摘要(sha256)
+    pub permissions: String,              //允许访问的接口(逗号分隔,为空时与所属用户的权限相同)
+    pub expire_time: Option<DateTime>,    //过期时间(为空时不过期)
+    pub status: i8,                       //状态(1:正常,0:已吊销)
+    pub last_used_time: Option<DateTime>, //最后使用时间
+    pub last_used_ip: String,             //最后使用IP
+    pub remark: Option<String>,           //备注
+    pub create_time: Option<DateTime>,    //创建时间
+    pub update_time: Option<DateTime>,    //修改时间
+}
+
+/*
+ *API密钥基本操作
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+rbatis::crud!(ApiKey {}, "sys_api_key");
+
+impl ApiKey {
+    // 允许访问的接口列表
+    pub fn permission_list(&self) -> Vec<String> {
+        self.permissions.split(',').map(|x| x.trim()).filter(|x| !x.is_empty()).map(|x| x.to_string()).collect()
+    }
+}
+
+impl From<ApiKey> for ApiKeyResp {
+    fn from(item: ApiKey) -> Self {
+        ApiKeyResp {
+            permissions: item.permission_list(),
+            id: item.id,                         //主键
+            user_id: item.user_id,               //所属用户id
+            key_name: item.key_name,             //密钥名称
+            key_prefix: item.key_prefix,         //密钥前缀
+            expire_time: item.expire_time,       //过期时间
+            status: item.status,                 //状态(1:正常,0:已吊销)
+            last_used_time: item.last_used_time, //最后使用时间
+            last_used_ip: item.last_used_ip,     //最后使用IP
+            remark: item.remark,                 //备注
+            create_time: item.create_time,       //创建时间
+            update_time: item.update_time,       //修改时间
+        }
+    }
+}
+
+/*
+ *根据id查询API密钥
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+impl_select!(ApiKey{select_by_id(id:i64) -> Option => "`where id = #{id} limit 1`"}, "sys_api_key");
+
+/*
+ *根据密钥摘要查询API密钥
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+impl_select!(ApiKey{select_by_key_hash(key_hash:&str) -> Option => "`where key_hash = #{key_hash} limit 1`"}, "sys_api_key");
+
+// API密钥列表允许排序的字段
+pub const API_KEY_SORT: SortColumns = SortColumns {
+    columns: &[
+        ("id", "id"),
+        ("keyName", "key_name"),
+        ("expireTime", "expire_time"),
+        ("lastUsedTime", "last_used_time"),
+        ("createTime", "create_time"),
+    ],
+    default: "id desc",
+};
+
+/*
+ *根据条件分页查询用户的API密钥
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+impl_select_page!(ApiKey{select_api_key_list(req:&QueryApiKeyListReq) =>"
+    where user_id = #{req.user_id}
+     if req.keyName != null && req.keyName != '':
+      ` and key_name like concat('%', #{req.keyName}, '%') `
+     if req.status != null:
+      ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and create_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and create_time <= #{req.endTime} `
+     if !sql.contains('count'):
+      ` order by ${req.orderBy} `"
+},"sys_api_key");
+
+/*
+ *更新API密钥(过期时间为空时表示不过期,需要更新为null)
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+pub async fn update_api_key(rb: &RBatis, item: &ApiKey) -> rbatis::Result<u64> {
+    let sql = "update sys_api_key set key_name = ?, permissions = ?, expire_time = ?, remark = ? where id = ? and user_id = ?";
+    let param = vec![
+        value!(&item.key_name),
+        value!(&item.permissions),
+        value!(&item.expire_time),
+        value!(&item.remark),
+        value!(item.id),
+        value!(item.user_id),
+    ];
+    rb.exec(sql, param).await.map(|x| x.rows_affected)
+}
+
+/*
+ *吊销用户的API密钥
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+pub async fn revoke_api_key(rb: &RBatis, user_id: i64, ids: &[i64]) -> rbatis::Result<u64> {
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    let sql = format!(
+        "update sys_api_key set status = 0 where user_id = ? and id in ({})",
+        ids.iter().map(|_| "?").collect::<Vec<&str>>().join(", ")
+    );
+    let mut param = vec![value!(user_id)];
+    param.extend(ids.iter().map(|&id| value!(id)));
+    rb.exec(&sql, param).await.map(|x| x.rows_affected)
+}
+
+/*
+ *记录API密钥的最后使用时间和IP
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+pub async fn update_last_used(rb: &RBatis, id: i64, ip: &str) -> rbatis::Result<u64> {
+    let sql = "update sys_api_key set last_used_time = now(), last_used_ip = ? where id = ?";
+    rb.exec(sql, vec![value!(ip), value!(id)]).await.map(|x| x.rows_affected)
+}
@@ -0,0 +1,110 @@
+// author：刘飞华
+// createTime：2026/10/18 10:12:36
+
+use crate::common::page::SortColumns;
+use crate::vo::system::sys_config_vo::{ConfigReq, ConfigResp, QueryConfigListReq};
+use rbatis::rbdc::datetime::DateTime;
+use serde::{Deserialize, Serialize};
+/*
+ *参数配置
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Config {
+    pub id: Option<i64>,               //参数主键
+    pub config_name: String,           //参数名称
+    pub config_key: String,            //参数键名
+    pub config_value: String,          //参数键值
+    pub value_type: i8,                //参数值类型（1:字符串,2:数字,3:布尔,4:JSON）
+    pub is_builtin: i8,                //是否系统内置（0:否,1:是）
+    pub remark: Option<String>,        //备注
+    pub create_time: Option<DateTime>, //创建时间
+    pub update_time: Option<DateTime>, //修改时间
+}
+
+/*
+ *参数配置基本操作
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+rbatis::crud!(Config {}, "sys_config");
+
+impl From<ConfigReq> for Config {
+    fn from(item: ConfigReq) -> Self {
+        let mut model = Config {
+            id: item.id,                     //参数主键
+            config_name: item.config_name,   //参数名称
+            config_key: item.config_key,     //参数键名
+            config_value: item.config_value, //参数键值
+            value_type: item.value_type,     //参数值类型（1:字符串,2:数字,3:布尔,4:JSON）
+            is_builtin: item.is_builtin,     //是否系统内置（0:否,1:是）
+            remark: item.remark,             //备注
+            create_time: None,               //创建时间
+            update_time: None,               //修改时间
+        };
+        if item.id.is_none() {
+            model.create_time = Some(DateTime::now());
+        } else {
+            model.update_time = Some(DateTime::now());
+        }
+        model
+    }
+}
+
+impl From<Config> for ConfigResp {
+    fn from(item: Config) -> Self {
+        ConfigResp {
+            id: item.id,                     //参数主键
+            config_name: item.config_name,   //参数名称
+            config_key: item.config_key,     //参数键名
+            config_value: item.config_value, //参数键值
+            value_type: item.value_type,     //参数值类型（1:字符串,2:数字,3:布尔,4:JSON）
+            is_builtin: item.is_builtin,     //是否系统内置（0:否,1:是）
+            remark: item.remark,             //备注
+            create_time: item.create_time,   //创建时间
+            update_time: item.update_time,   //修改时间
+        }
+    }
+}
+
+/*
+ *根据id查询参数配置
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+impl_select!(Config{select_by_id(id:&i64) -> Option => "`where id = #{id} limit 1`"}, "sys_config");
+
+/*
+ *根据config_key查询参数配置
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+impl_select!(Config{select_by_config_key(config_key:&str) -> Option => "`where config_key = #{config_key} limit 1`"}, "sys_config");
+
+// 参数配置列表允许排序的字段
+pub const CONFIG_SORT: SortColumns = SortColumns {
+    columns: &[("id", "id"), ("configName", "config_name"), ("configKey", "config_key"), ("createTime", "create_time")],
+    default: "create_time desc",
+};
+
+/*
+ *根据条件分页查询参数配置
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+impl_select_page!(Config{select_config_list(req:&QueryConfigListReq) =>"
+    where 1=1
+     if req.configName != null && req.configName != '':
+      ` and config_name like concat('%', #{req.configName}, '%') `
+     if req.configKey != null && req.configKey != '':
+      ` and config_key like concat('%', #{req.configKey}, '%') `
+     if req.isBuiltin != null:
+      ` and is_builtin = #{req.isBuiltin} `
+     if req.beginTime != null:
+      ` and create_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and create_time <= #{req.endTime} `
+     if !sql.contains('count'):
+      ` order by ${req.orderBy} `"
+},"sys_config");
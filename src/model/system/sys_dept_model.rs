@@ -110,7 +110,7 @@ impl_select!(Dept{select_page_dept_list(req:&QueryDeptListReq) =>"
     where 1=1
      if req.deptName != null && req.deptName != '':
       ` and dept_name = #{req.deptName} `
-     if req.status != 2:
+     if req.status != null:
       ` and status = #{req.status} `
      if !sql.contains('count'):
       ` order by sort"
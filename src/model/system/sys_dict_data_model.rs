@@ -2,6 +2,7 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use crate::common::page::SortColumns;
 use crate::vo::system::sys_dict_data_vo::{DictDataReq, DictDataResp, QueryDictDataListReq};
 use rbatis::rbdc::datetime::DateTime;
 use rbatis::RBatis;
@@ -109,6 +110,19 @@ impl_select_page!(DictData{select_page() =>"
        order by create_time desc"
 },"sys_dict_data");
 
+// 字典数据列表允许排序的字段
+pub const DICT_DATA_SORT: SortColumns = SortColumns {
+    columns: &[
+        ("id", "id"),
+        ("dictSort", "dict_sort"),
+        ("dictLabel", "dict_label"),
+        ("dictValue", "dict_value"),
+        ("status", "status"),
+        ("createTime", "create_time"),
+    ],
+    default: "create_time desc",
+};
+
 /*
  *根据条件分页查询字典数据表
  *author：刘飞华
@@ -117,13 +131,19 @@ impl_select_page!(DictData{select_page() =>"
 impl_select_page!(DictData{select_dict_data_list(req:&QueryDictDataListReq) =>"
     where 1=1
      if req.dictLabel != null && req.dictLabel != '':
-      ` and dict_label = like concat('%', #{req.dictLabel}, '%') `
+      ` and dict_label like concat('%', #{req.dictLabel}, '%') `
+     if req.dictValue != null && req.dictValue != '':
+      ` and dict_value like concat('%', #{req.dictValue}, '%') `
      if req.dictType != null && req.dictType != '':
-      ` and dict_type = like concat('%', #{req.dictType}, '%') `
-     if req.status != 2:
+      ` and dict_type like concat('%', #{req.dictType}, '%') `
+     if req.status != null:
       ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and create_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and create_time <= #{req.endTime} `
      if !sql.contains('count'):
-      ` order by create_time desc"
+      ` order by ${req.orderBy} `"
 },"sys_dict_data");
 
 /*
@@ -2,6 +2,7 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use crate::common::page::SortColumns;
 use crate::vo::system::sys_dict_type_vo::{DictTypeReq, DictTypeResp, QueryDictTypeListReq};
 use rbatis::rbdc::datetime::DateTime;
 use serde::{Deserialize, Serialize};
@@ -86,6 +87,12 @@ impl_select_page!(DictType{select_page() =>"
        order by create_time desc"
 },"sys_dict_type");
 
+// 字典类型列表允许排序的字段
+pub const DICT_TYPE_SORT: SortColumns = SortColumns {
+    columns: &[("id", "id"), ("dictName", "dict_name"), ("dictType", "dict_type"), ("status", "status"), ("createTime", "create_time")],
+    default: "create_time desc",
+};
+
 /*
  *根据条件分页查询字典类型
  *author：刘飞华
@@ -94,11 +101,15 @@ impl_select_page!(DictType{select_page() =>"
 impl_select_page!(DictType{select_dict_type_list(req:&QueryDictTypeListReq) =>"
     where 1=1
      if req.dictName != null && req.dictName != '':
-      ` and dict_name = like concat('%', #{req.dictName}, '%') `
+      ` and dict_name like concat('%', #{req.dictName}, '%') `
      if req.dictType != null && req.dictType != '':
-      ` and dict_type = like concat('%', #{req.dictType}, '%') `
-     if req.status != 2:
+      ` and dict_type like concat('%', #{req.dictType}, '%') `
+     if req.status != null:
       ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and create_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and create_time <= #{req.endTime} `
      if !sql.contains('count'):
-      ` order by create_time desc"
+      ` order by ${req.orderBy} `"
 },"sys_dict_type");
@@ -0,0 +1,116 @@
+// author：刘飞华
+// createTime：2026/10/18 20:48:15
+
+use crate::common::page::SortColumns;
+use crate::vo::system::sys_file_vo::{FileResp, QueryFileListReq};
+use rbatis::rbdc::datetime::DateTime;
+use serde::{Deserialize, Serialize};
+/*
+ *文件信息(每次上传一条记录,相同内容的文件共用一个存储文件)
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SysFile {
+    pub id: Option<i64>,               //文件id
+    pub blob_id: i64,                  //存储文件id
+    pub file_name: String,             //原始文件名
+    pub file_key: String,              //存储路径
+    pub storage_type: String,          //存储类型(local:本地文件系统,s3:对象存储)
+    pub file_size: i64,                //文件大小(字节)
+    pub mime_type: String,             //文件类型
+    pub file_hash: String,             //文件内容的sha256
+    pub owner_id: i64,                 //上传者id
+    pub create_time: Option<DateTime>, //创建时间
+    pub update_time: Option<DateTime>, //更新时间
+}
+
+/*
+ *存储文件(相同内容的文件只保存一份)
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SysFileBlob {
+    pub id: Option<i64>,               //存储文件id
+    pub file_key: String,              //存储路径
+    pub storage_type: String,          //存储类型(local:本地文件系统,s3:对象存储)
+    pub file_size: i64,                //文件大小(字节)
+    pub mime_type: String,             //文件类型
+    pub file_hash: String,             //文件内容的sha256
+    pub ref_count: i32,                //引用次数(引用这个存储文件的文件信息数量)
+    pub create_time: Option<DateTime>, //创建时间
+    pub update_time: Option<DateTime>, //更新时间
+}
+
+/*
+ *文件信息基本操作
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+rbatis::crud!(SysFile {}, "sys_file");
+
+/*
+ *存储文件基本操作
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+rbatis::crud!(SysFileBlob {}, "sys_file_blob");
+
+impl From<SysFile> for FileResp {
+    fn from(item: SysFile) -> Self {
+        FileResp {
+            url: format!("/api/system/file/download?id={}", item.id.unwrap_or_default()),
+            id: item.id,                     //文件id
+            file_name: item.file_name,       //原始文件名
+            storage_type: item.storage_type, //存储类型
+            file_size: item.file_size,       //文件大小(字节)
+            mime_type: item.mime_type,       //文件类型
+            file_hash: item.file_hash,       //文件内容的sha256
+            owner_id: item.owner_id,         //上传者id
+            create_time: item.create_time,   //创建时间
+            update_time: item.update_time,   //更新时间
+        }
+    }
+}
+
+/*
+ *根据id查询文件信息
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+impl_select!(SysFile{select_by_id(id:&i64) -> Option => "`where id = #{id} limit 1`"}, "sys_file");
+
+/*
+ *根据文件内容的sha256查询存储文件(用于去重)
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+impl_select!(SysFileBlob{select_by_hash(file_hash:&str, storage_type:&str) -> Option => "`where file_hash = #{file_hash} and storage_type = #{storage_type} limit 1`"}, "sys_file_blob");
+
+// 文件列表允许排序的字段
+pub const FILE_SORT: SortColumns = SortColumns {
+    columns: &[("id", "id"), ("fileName", "file_name"), ("fileSize", "file_size"), ("createTime", "create_time")],
+    default: "id desc",
+};
+
+/*
+ *根据条件分页查询文件信息
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+impl_select_page!(SysFile{select_file_list(req:&QueryFileListReq) =>"
+    where 1=1
+     if req.fileName != null && req.fileName != '':
+      ` and file_name like concat('%', #{req.fileName}, '%') `
+     if req.mimeType != null && req.mimeType != '':
+      ` and mime_type like concat(#{req.mimeType}, '%') `
+     if req.ownerId != null:
+      ` and owner_id = #{req.ownerId} `
+     if req.beginTime != null:
+      ` and create_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and create_time <= #{req.endTime} `
+     if !sql.contains('count'):
+      ` order by ${req.orderBy} `"
+},"sys_file");
@@ -0,0 +1,111 @@
+// author：刘飞华
+// createTime：2026/10/18 16:40:18
+
+use crate::common::page::SortColumns;
+use crate::vo::system::sys_job_log_vo::{JobLogResp, QueryJobLogListReq};
+use rbatis::rbdc::datetime::DateTime;
+use rbatis::RBatis;
+use serde::{Deserialize, Serialize};
+/*
+ *定时任务调度日志
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JobLog {
+    pub id: Option<i64>,              //任务日志ID
+    pub job_id: i64,                  //任务ID
+    pub job_name: String,             //任务名称
+    pub job_group: String,            //任务组名
+    pub invoke_target: String,        //调用目标
+    pub job_params: String,           //任务参数
+    pub trigger_type: i8,             //触发方式（1:定时触发,2:手动执行,3:错过补偿）
+    pub job_message: String,          //日志信息
+    pub status: i8,                   //执行状态（0:失败,1:成功）
+    pub error_msg: String,            //异常信息
+    pub start_time: Option<DateTime>, //开始时间
+    pub end_time: Option<DateTime>,   //结束时间
+    pub duration: i64,                //执行耗时(毫秒)
+}
+
+/*
+ *定时任务调度日志基本操作
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+rbatis::crud!(JobLog {}, "sys_job_log");
+
+impl From<JobLog> for JobLogResp {
+    fn from(item: JobLog) -> Self {
+        JobLogResp {
+            id: item.id,                       //任务日志ID
+            job_id: item.job_id,               //任务ID
+            job_name: item.job_name,           //任务名称
+            job_group: item.job_group,         //任务组名
+            invoke_target: item.invoke_target, //调用目标
+            job_params: item.job_params,       //任务参数
+            trigger_type: item.trigger_type,   //触发方式（1:定时触发,2:手动执行,3:错过补偿）
+            job_message: item.job_message,     //日志信息
+            status: item.status,               //执行状态（0:失败,1:成功）
+            error_msg: item.error_msg,         //异常信息
+            start_time: item.start_time,       //开始时间
+            end_time: item.end_time,           //结束时间
+            duration: item.duration,           //执行耗时(毫秒)
+        }
+    }
+}
+
+/*
+ *根据id查询定时任务日志
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+impl_select!(JobLog{select_by_id(id:&i64) -> Option => "`where id = #{id} limit 1`"}, "sys_job_log");
+
+/*
+ *查询定时任务最近一次定时触发的执行日志(用于计算错过的执行)
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+impl_select!(JobLog{select_last_scheduled_log(job_id:i64) -> Option => "`where job_id = #{job_id} and trigger_type != 2 order by start_time desc limit 1`"}, "sys_job_log");
+
+// 定时任务日志列表允许排序的字段
+pub const JOB_LOG_SORT: SortColumns = SortColumns {
+    columns: &[("id", "id"), ("jobName", "job_name"), ("status", "status"), ("startTime", "start_time"), ("duration", "duration")],
+    default: "start_time desc",
+};
+
+/*
+ *根据条件分页查询定时任务日志
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+impl_select_page!(JobLog{select_job_log_list(req:&QueryJobLogListReq) =>"
+    where 1=1
+     if req.jobId != null:
+      ` and job_id = #{req.jobId} `
+     if req.jobName != null && req.jobName != '':
+      ` and job_name like concat('%', #{req.jobName}, '%') `
+     if req.jobGroup != null && req.jobGroup != '':
+      ` and job_group = #{req.jobGroup} `
+     if req.invokeTarget != null && req.invokeTarget != '':
+      ` and invoke_target like concat('%', #{req.invokeTarget}, '%') `
+     if req.status != null:
+      ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and start_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and start_time <= #{req.endTime} `
+     if !sql.contains('count'):
+      ` order by ${req.orderBy} `"
+},"sys_job_log");
+
+/*
+ *清空定时任务日志
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[sql("truncate table sys_job_log")]
+pub async fn clean_job_log(rb: &RBatis) -> Option<i64> {
+    impled!()
+}
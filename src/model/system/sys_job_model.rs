@@ -0,0 +1,123 @@
+// author：刘飞华
+// createTime：2026/10/18 16:40:18
+
+use crate::common::page::SortColumns;
+use crate::task::scheduler::next_valid_time;
+use crate::vo::system::sys_job_vo::{JobReq, JobResp, QueryJobListReq};
+use rbatis::rbdc::datetime::DateTime;
+use serde::{Deserialize, Serialize};
+/*
+ *定时任务调度
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Job {
+    pub id: Option<i64>,               //任务ID
+    pub job_name: String,              //任务名称
+    pub job_group: String,             //任务组名
+    pub invoke_target: String,         //调用目标(代码中注册的任务名称)
+    pub job_params: String,            //任务参数
+    pub cron_expression: String,       //cron执行表达式
+    pub misfire_policy: i8,            //计划执行错误策略（1:立即执行,2:执行一次,3:放弃执行）
+    pub concurrent: i8,                //是否禁止并发执行（0:允许,1:禁止）
+    pub status: i8,                    //状态（0:暂停,1:正常）
+    pub remark: Option<String>,        //备注
+    pub create_time: Option<DateTime>, //创建时间
+    pub update_time: Option<DateTime>, //修改时间
+}
+
+/*
+ *定时任务调度基本操作
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+rbatis::crud!(Job {}, "sys_job");
+
+impl From<JobReq> for Job {
+    fn from(item: JobReq) -> Self {
+        let mut model = Job {
+            id: item.id,                           //任务ID
+            job_name: item.job_name,               //任务名称
+            job_group: item.job_group,             //任务组名
+            invoke_target: item.invoke_target,     //调用目标(代码中注册的任务名称)
+            job_params: item.job_params,           //任务参数
+            cron_expression: item.cron_expression, //cron执行表达式
+            misfire_policy: item.misfire_policy,   //计划执行错误策略（1:立即执行,2:执行一次,3:放弃执行）
+            concurrent: item.concurrent,           //是否禁止并发执行（0:允许,1:禁止）
+            status: item.status,                   //状态（0:暂停,1:正常）
+            remark: item.remark,                   //备注
+            create_time: None,                     //创建时间
+            update_time: None,                     //修改时间
+        };
+        if item.id.is_none() {
+            model.create_time = Some(DateTime::now());
+        } else {
+            model.update_time = Some(DateTime::now());
+        }
+        model
+    }
+}
+
+impl From<Job> for JobResp {
+    fn from(item: Job) -> Self {
+        JobResp {
+            next_valid_time: if item.status == 1 { next_valid_time(&item.cron_expression) } else { String::new() }, //下次执行时间
+            id: item.id,                                                                                            //任务ID
+            job_name: item.job_name,                                                                                //任务名称
+            job_group: item.job_group,                                                                              //任务组名
+            invoke_target: item.invoke_target,                                                                      //调用目标
+            job_params: item.job_params,                                                                            //任务参数
+            cron_expression: item.cron_expression,                                                                  //cron执行表达式
+            misfire_policy: item.misfire_policy,                                                                    //计划执行错误策略（1:立即执行,2:执行一次,3:放弃执行）
+            concurrent: item.concurrent,                                                                            //是否禁止并发执行（0:允许,1:禁止）
+            status: item.status,                                                                                    //状态（0:暂停,1:正常）
+            remark: item.remark,                                                                                    //备注
+            create_time: item.create_time,                                                                          //创建时间
+            update_time: item.update_time,                                                                          //修改时间
+        }
+    }
+}
+
+/*
+ *根据id查询定时任务
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+impl_select!(Job{select_by_id(id:&i64) -> Option => "`where id = #{id} limit 1`"}, "sys_job");
+
+/*
+ *查询状态正常的定时任务
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+impl_select!(Job{select_enabled_job() => "`where status = 1`"}, "sys_job");
+
+// 定时任务列表允许排序的字段
+pub const JOB_SORT: SortColumns = SortColumns {
+    columns: &[("id", "id"), ("jobName", "job_name"), ("jobGroup", "job_group"), ("status", "status"), ("createTime", "create_time")],
+    default: "create_time desc",
+};
+
+/*
+ *根据条件分页查询定时任务
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+impl_select_page!(Job{select_job_list(req:&QueryJobListReq) =>"
+    where 1=1
+     if req.jobName != null && req.jobName != '':
+      ` and job_name like concat('%', #{req.jobName}, '%') `
+     if req.jobGroup != null && req.jobGroup != '':
+      ` and job_group = #{req.jobGroup} `
+     if req.invokeTarget != null && req.invokeTarget != '':
+      ` and invoke_target like concat('%', #{req.invokeTarget}, '%') `
+     if req.status != null:
+      ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and create_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and create_time <= #{req.endTime} `
+     if !sql.contains('count'):
+      ` order by ${req.orderBy} `"
+},"sys_job");
@@ -2,9 +2,11 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
-use crate::vo::system::sys_login_log_vo::{LoginLogResp, QueryLoginLogListReq};
+use crate::common::page::SortColumns;
+use crate::vo::system::sys_login_log_vo::{LoginLogResp, QueryLoginLogCursorListReq, QueryLoginLogListReq};
 use rbatis::rbdc::datetime::DateTime;
 use rbatis::RBatis;
+use rbs::value;
 use serde::{Deserialize, Serialize};
 /*
  *系统访问记录
@@ -25,8 +27,11 @@ pub struct LoginLog {
     pub engine: String,               //渲染引擎信息
     pub engine_details: String,       //渲染引擎详细信息
     pub extra: String,                //其他信息（可选）
+    pub client_type: String,          //客户端类型(human:用户,bot:爬虫,api:接口调用)
     pub status: i8,                   //登录状态(0:失败,1:成功)
     pub msg: String,                  //提示消息
+    pub risk_level: i8,               //风险等级(0:无,1:低,2:中,3:高)
+    pub risk_reason: String,          //风险原因
     pub login_time: Option<DateTime>, //访问时间
 }
 
@@ -52,8 +57,11 @@ impl Into<LoginLogResp> for LoginLog {
             engine: self.engine,                 //渲染引擎信息
             engine_details: self.engine_details, //渲染引擎详细信息
             extra: self.extra,                   //其他信息（可选）
+            client_type: self.client_type,       //客户端类型(human:用户,bot:爬虫,api:接口调用)
             status: self.status,                 //登录状态(0:失败,1:成功)
             msg: self.msg,                       //提示消息
+            risk_level: self.risk_level,         //风险等级(0:无,1:低,2:中,3:高)
+            risk_reason: self.risk_reason,       //风险原因
             login_time: self.login_time,         //访问时间
         }
     }
@@ -76,6 +84,37 @@ impl_select_page!(LoginLog{select_page() =>"
        order by login_time desc"
 },"sys_login_log");
 
+/*
+ *查询最近的成功登录记录(用于登录风险检测,login_names为同一用户的手机、账号、邮箱)
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+pub async fn select_recent_success_login_log(rb: &RBatis, login_names: &[String], limit: u64) -> rbatis::Result<Vec<LoginLog>> {
+    if login_names.is_empty() {
+        return Ok(Vec::new());
+    }
+    let sql = format!(
+        "select * from sys_login_log where status = 1 and login_name in ({}) order by id desc limit ?",
+        login_names.iter().map(|_| "?").collect::<Vec<&str>>().join(", ")
+    );
+    let mut param = login_names.iter().map(|x| value!(x)).collect::<Vec<_>>();
+    param.push(value!(limit));
+    rb.query_decode(&sql, param).await
+}
+
+// 系统访问记录列表允许排序的字段
+pub const LOGIN_LOG_SORT: SortColumns = SortColumns {
+    columns: &[
+        ("id", "id"),
+        ("loginName", "login_name"),
+        ("ipaddr", "ipaddr"),
+        ("status", "status"),
+        ("riskLevel", "risk_level"),
+        ("loginTime", "login_time"),
+    ],
+    default: "login_time desc",
+};
+
 /*
  *根据条件分页查询系统访问记录
  *author：刘飞华
@@ -83,20 +122,72 @@ impl_select_page!(LoginLog{select_page() =>"
  */
 impl_select_page!(LoginLog{select_login_log_list(req:&QueryLoginLogListReq) =>"
     where 1=1
-     if req.loginName != '' && req.loginName != null:
-       ` and login_name like concat('%', #{req.loginName}, '%') `
-     if req.ipaddr != '' && req.ipaddr != null:
-       ` and ipaddr like concat('%', #{req.ipaddr}, '%') `
-     if req.browser != '' && req.browser != null:
-       ` and browser like concat('%', #{req.browser}, '%') `
-     if req.os != '' && req.os != null:
-       ` and os = like concat('%', #{req.os}, '%') `
-     if req.status != 2:
-       ` and status = #{req.status} `
+     if req.loginName != null && req.loginName != '':
+      ` and login_name like concat('%', #{req.loginName}, '%') `
+     if req.ipaddr != null && req.ipaddr != '':
+      ` and ipaddr like concat('%', #{req.ipaddr}, '%') `
+     if req.loginLocation != null && req.loginLocation != '':
+      ` and login_location like concat('%', #{req.loginLocation}, '%') `
+     if req.browser != null && req.browser != '':
+      ` and browser like concat('%', #{req.browser}, '%') `
+     if req.os != null && req.os != '':
+      ` and os like concat('%', #{req.os}, '%') `
+     if req.clientType != null && req.clientType != '':
+      ` and client_type = #{req.clientType} `
+     if req.riskLevel != null:
+      ` and risk_level >= #{req.riskLevel} `
+     if req.status != null:
+      ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and login_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and login_time <= #{req.endTime} `
      if !sql.contains('count'):
-       ` order by login_time desc `"
+      ` order by ${req.orderBy} `"
 },"sys_login_log");
 
+/*
+ *根据条件游标分页查询系统访问记录(按login_time和id倒序,从游标对应的记录之后开始查询)
+ *author：刘飞华
+ *date：2026/10/19 05:12:40
+ */
+impl_select!(LoginLog{select_login_log_cursor_list(req:&QueryLoginLogCursorListReq) => "
+    where 1=1
+     if req.loginName != null && req.loginName != '':
+      ` and login_name like concat('%', #{req.loginName}, '%') `
+     if req.ipaddr != null && req.ipaddr != '':
+      ` and ipaddr like concat('%', #{req.ipaddr}, '%') `
+     if req.loginLocation != null && req.loginLocation != '':
+      ` and login_location like concat('%', #{req.loginLocation}, '%') `
+     if req.browser != null && req.browser != '':
+      ` and browser like concat('%', #{req.browser}, '%') `
+     if req.os != null && req.os != '':
+      ` and os like concat('%', #{req.os}, '%') `
+     if req.clientType != null && req.clientType != '':
+      ` and client_type = #{req.clientType} `
+     if req.riskLevel != null:
+      ` and risk_level >= #{req.riskLevel} `
+     if req.status != null:
+      ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and login_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and login_time <= #{req.endTime} `
+     if req.cursorTime != null && req.cursorId != null:
+      ` and (login_time < #{req.cursorTime} or (login_time = #{req.cursorTime} and id < #{req.cursorId})) `
+     ` order by login_time desc, id desc limit #{req.limit} `"
+},"sys_login_log");
+
+/*
+ *查询系统访问记录的近似总数(表的估算行数,不受查询条件影响)
+ *author：刘飞华
+ *date：2026/10/19 05:12:40
+ */
+#[sql("select ifnull(table_rows, 0) from information_schema.tables where table_schema = database() and table_name = 'sys_login_log'")]
+pub async fn count_login_log_estimate(rb: &RBatis) -> rbatis::Result<u64> {
+    impled!()
+}
+
 /*
  *清空系统登录日志
  *author：刘飞华
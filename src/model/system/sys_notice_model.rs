@@ -2,9 +2,13 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
-use crate::vo::system::sys_notice_vo::{NoticeReq, NoticeResp, QueryNoticeListReq};
+use crate::common::page::{PageQuery, SortColumns};
+use crate::vo::system::sys_notice_vo::{MyNoticeResp, NoticeReq, NoticeResp, QueryMyNoticeListReq, QueryNoticeListReq};
 use rbatis::rbdc::datetime::DateTime;
+use rbatis::RBatis;
+use rbs::{value, Value};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 /*
  *通知公告表
  *author：刘飞华
@@ -12,14 +16,19 @@ use serde::{Deserialize, Serialize};
  */
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Notice {
-    pub id: Option<i64>,               //公告ID
-    pub notice_title: String,          //公告标题
-    pub notice_type: i8,               //公告类型（1:通知,2:公告）
-    pub notice_content: String,        //公告内容
-    pub status: i8,                    //公告状态（0:关闭,1:正常 ）
-    pub remark: Option<String>,        //备注
-    pub create_time: Option<DateTime>, //创建时间
-    pub update_time: Option<DateTime>, //修改时间
+    pub id: Option<i64>,                //公告ID
+    pub notice_title: String,           //公告标题
+    pub notice_type: i8,                //公告类型（1:通知,2:公告）
+    pub notice_content: String,         //公告内容
+    pub status: i8,                     //公告状态（0:关闭,1:正常 ）
+    pub target_type: i8,                //发送范围（0:全部用户,1:指定部门,2:指定角色,3:指定用户）
+    pub target_ids: String,             //发送对象id(逗号分隔)
+    pub publish_time: Option<DateTime>, //定时发布时间
+    pub expire_time: Option<DateTime>,  //过期时间(到期后自动关闭)
+    pub scheduled: i8,                  //是否等待定时发布(0:否,1:是)
+    pub remark: Option<String>,         //备注
+    pub create_time: Option<DateTime>,  //创建时间
+    pub update_time: Option<DateTime>,  //修改时间
 }
 
 /*
@@ -32,14 +41,19 @@ rbatis::crud!(Notice {}, "sys_notice");
 impl From<NoticeReq> for Notice {
     fn from(item: NoticeReq) -> Self {
         let mut model = Notice {
-            id: item.id,                         //公告ID
-            notice_title: item.notice_title,     //公告标题
-            notice_type: item.notice_type,       //公告类型（1:通知,2:公告）
-            notice_content: item.notice_content, //公告内容
-            status: item.status,                 //公告状态（0:关闭,1:正常 ）
-            remark: item.remark,                 //备注
-            create_time: None,                   //创建时间
-            update_time: None,                   //修改时间
+            id: item.id,                                                                                  //公告ID
+            notice_title: item.notice_title,                                                              //公告标题
+            notice_type: item.notice_type,                                                                //公告类型（1:通知,2:公告）
+            notice_content: item.notice_content,                                                          //公告内容
+            status: item.status,                                                                          //公告状态（0:关闭,1:正常 ）
+            target_type: item.target_type,                                                                //发送范围（0:全部用户,1:指定部门,2:指定角色,3:指定用户）
+            target_ids: item.target_ids.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(","), //发送对象id(逗号分隔)
+            publish_time: item.publish_time,                                                              //定时发布时间
+            expire_time: item.expire_time,                                                                //过期时间(到期后自动关闭)
+            scheduled: 0,                                                                                 //是否等待定时发布(0:否,1:是)
+            remark: item.remark,                                                                          //备注
+            create_time: None,                                                                            //创建时间
+            update_time: None,                                                                            //修改时间
         };
         if let None = item.id {
             model.create_time = Some(DateTime::now());
@@ -53,14 +67,19 @@ impl From<NoticeReq> for Notice {
 impl Into<NoticeResp> for Notice {
     fn into(self) -> NoticeResp {
         NoticeResp {
-            id: self.id,                         //公告ID
-            notice_title: self.notice_title,     //公告标题
-            notice_type: self.notice_type,       //公告类型（1:通知,2:公告）
-            notice_content: self.notice_content, //公告内容
-            status: self.status,                 //公告状态（0:关闭,1:正常 ）
-            remark: self.remark,                 //备注
-            create_time: self.create_time,       //创建时间
-            update_time: self.update_time,       //修改时间
+            id: self.id,                                                                                   //公告ID
+            notice_title: self.notice_title,                                                               //公告标题
+            notice_type: self.notice_type,                                                                 //公告类型（1:通知,2:公告）
+            notice_content: self.notice_content,                                                           //公告内容
+            status: self.status,                                                                           //公告状态（0:关闭,1:正常 ）
+            target_type: self.target_type,                                                                 //发送范围（0:全部用户,1:指定部门,2:指定角色,3:指定用户）
+            target_ids: self.target_ids.split(',').filter_map(|x| x.trim().parse::<i64>().ok()).collect(), //发送对象id
+            publish_time: self.publish_time,                                                               //定时发布时间
+            expire_time: self.expire_time,                                                                 //过期时间
+            scheduled: self.scheduled,                                                                     //是否等待定时发布(0:否,1:是)
+            remark: self.remark,                                                                           //备注
+            create_time: self.create_time,                                                                 //创建时间
+            update_time: self.update_time,                                                                 //修改时间
         }
     }
 }
@@ -78,6 +97,20 @@ impl_select!(Notice{select_by_id(id:&i64) -> Option => "`where id = #{id} limit
  */
 impl_select!(Notice{select_by_title(title:&str) -> Option => "`where notice_title = #{title} limit 1`"}, "sys_notice");
 
+// 通知公告列表允许排序的字段
+pub const NOTICE_SORT: SortColumns = SortColumns {
+    columns: &[
+        ("id", "id"),
+        ("noticeTitle", "notice_title"),
+        ("noticeType", "notice_type"),
+        ("status", "status"),
+        ("publishTime", "publish_time"),
+        ("expireTime", "expire_time"),
+        ("createTime", "create_time"),
+    ],
+    default: "create_time desc",
+};
+
 /*
  *根据条件分页查询通知公告表
  *author：刘飞华
@@ -85,12 +118,191 @@ impl_select!(Notice{select_by_title(title:&str) -> Option => "`where notice_titl
  */
 impl_select_page!(Notice{select_sys_notice_list(req:&QueryNoticeListReq) =>"
     where 1=1
-     if req.noticeTitle != '' && req.noticeTitle != null:
-       ` and notice_title like concat('%', #{req.noticeTitle}, '%') `
-     if req.noticeType != 0:
+     if req.noticeTitle != null && req.noticeTitle != '':
+      ` and notice_title like concat('%', #{req.noticeTitle}, '%') `
+     if req.noticeType != null:
       ` and notice_type = #{req.noticeType} `
-     if req.status != 2:
-       ` and status = #{req.status} `
+     if req.status != null:
+      ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and create_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and create_time <= #{req.endTime} `
      if !sql.contains('count'):
-       ` order by create_time desc `"
+      ` order by ${req.orderBy} `"
 },"sys_notice");
+
+/*
+ *通知公告接收范围条件(n:通知公告表, u:用户表)
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+pub const NOTICE_AUDIENCE_SQL: &str = "(n.target_type = 0 \
+    or (n.target_type = 1 and find_in_set(u.dept_id, n.target_ids)) \
+    or (n.target_type = 2 and exists (select 1 from sys_user_role ur where ur.user_id = u.id and find_in_set(ur.role_id, n.target_ids))) \
+    or (n.target_type = 3 and find_in_set(u.id, n.target_ids)))";
+
+/*
+ *用户收到的通知公告
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UserNotice {
+    pub id: Option<i64>,               //公告ID
+    pub notice_title: String,          //公告标题
+    pub notice_type: i8,               //公告类型（1:通知,2:公告）
+    pub notice_content: String,        //公告内容
+    pub read_time: Option<DateTime>,   //阅读时间
+    pub create_time: Option<DateTime>, //创建时间
+}
+
+impl From<UserNotice> for MyNoticeResp {
+    fn from(item: UserNotice) -> Self {
+        MyNoticeResp {
+            id: item.id,                         //公告ID
+            notice_title: item.notice_title,     //公告标题
+            notice_type: item.notice_type,       //公告类型（1:通知,2:公告）
+            notice_content: item.notice_content, //公告内容
+            is_read: item.read_time.is_some(),   //是否已读
+            read_time: item.read_time,           //阅读时间
+            create_time: item.create_time,       //创建时间
+        }
+    }
+}
+
+/*
+ *按公告类型统计的数量
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NoticeTypeCount {
+    pub notice_type: i8, //公告类型（1:通知,2:公告）
+    pub count: u64,      //数量
+}
+
+// 我的通知公告列表允许排序的字段
+pub const MY_NOTICE_SORT: SortColumns = SortColumns {
+    columns: &[("id", "n.id"), ("noticeType", "n.notice_type"), ("readTime", "r.read_time"), ("createTime", "n.create_time")],
+    default: "n.create_time desc",
+};
+
+/*
+ *拼接用户收到的通知公告查询条件
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+fn build_user_notice_where(user_id: i64, req: &QueryMyNoticeListReq) -> (String, Vec<Value>) {
+    let mut sql = format!(
+        " from sys_notice n join sys_user u on u.id = ? left join sys_notice_read r on r.notice_id = n.id and r.user_id = u.id where n.status = 1 and (n.expire_time is null or n.expire_time > now()) and {}",
+        NOTICE_AUDIENCE_SQL
+    );
+    let mut param = vec![value!(user_id)];
+
+    if let Some(notice_type) = req.notice_type {
+        sql.push_str(" and n.notice_type = ?");
+        param.push(value!(notice_type));
+    }
+
+    match req.read_status {
+        Some(0) => sql.push_str(" and r.id is null"),
+        Some(1) => sql.push_str(" and r.id is not null"),
+        _ => {}
+    }
+    req.page.push_time_range("n.create_time", &mut sql, &mut param);
+    (sql, param)
+}
+
+/*
+ *分页查询用户收到的通知公告
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+pub async fn select_user_notice_list(rb: &RBatis, user_id: i64, req: &QueryMyNoticeListReq) -> rbatis::Result<Vec<UserNotice>> {
+    let (where_sql, mut param) = build_user_notice_where(user_id, req);
+    let sql = format!(
+        "select n.id, n.notice_title, n.notice_type, n.notice_content, r.read_time, n.create_time {} order by {} limit ?, ?",
+        where_sql, req.page.order_by
+    );
+    param.push(value!(req.page.offset()));
+    param.push(value!(req.page.page_size));
+    rb.query_decode(&sql, param).await
+}
+
+/*
+ *查询用户收到的通知公告数量
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+pub async fn count_user_notice(rb: &RBatis, user_id: i64, req: &QueryMyNoticeListReq) -> rbatis::Result<u64> {
+    let (where_sql, param) = build_user_notice_where(user_id, req);
+    rb.query_decode(&format!("select count(1) {}", where_sql), param).await
+}
+
+/*
+ *按公告类型统计用户的未读数量
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+pub async fn count_user_unread_by_type(rb: &RBatis, user_id: i64) -> rbatis::Result<Vec<NoticeTypeCount>> {
+    let req = QueryMyNoticeListReq {
+        page: PageQuery::default(),
+        notice_type: None,
+        read_status: Some(0),
+    };
+    let (where_sql, param) = build_user_notice_where(user_id, &req);
+    let sql = format!("select n.notice_type, count(1) as count {} group by n.notice_type", where_sql);
+    rb.query_decode(&sql, param).await
+}
+
+/*
+ *查询通知公告的发送人数
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+pub async fn count_notice_target(rb: &RBatis, notice_id: i64) -> rbatis::Result<u64> {
+    let sql = format!("select count(1) from sys_user u join sys_notice n on n.id = ? where u.del_flag = 1 and {}", NOTICE_AUDIENCE_SQL);
+    rb.query_decode(&sql, vec![value!(notice_id)]).await
+}
+
+/*
+ *查询通知公告的接收用户id
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+pub async fn select_notice_target_user_ids(rb: &RBatis, notice_id: i64) -> rbatis::Result<Vec<i64>> {
+    let sql = format!("select u.id from sys_user u join sys_notice n on n.id = ? where u.del_flag = 1 and {}", NOTICE_AUDIENCE_SQL);
+    let list: Vec<HashMap<String, i64>> = rb.query_decode(&sql, vec![value!(notice_id)]).await?;
+    Ok(list.into_iter().filter_map(|x| x.get("id").copied()).collect())
+}
+
+/*
+ *查询到达发布时间的定时通知公告id
+ *author：刘飞华
+ *date：2026/10/18 16:05:42
+ */
+pub async fn select_due_notice_ids(rb: &RBatis) -> rbatis::Result<Vec<i64>> {
+    let list: Vec<HashMap<String, i64>> = rb.query_decode("select id from sys_notice where scheduled = 1 and publish_time <= now()", vec![]).await?;
+    Ok(list.into_iter().filter_map(|x| x.get("id").copied()).collect())
+}
+
+/*
+ *发布定时通知公告(多个实例同时执行时只有一个能更新成功)
+ *author：刘飞华
+ *date：2026/10/18 16:05:42
+ */
+pub async fn publish_scheduled_notice(rb: &RBatis, id: i64) -> rbatis::Result<bool> {
+    let sql = "update sys_notice set status = 1, scheduled = 0 where id = ? and scheduled = 1";
+    rb.exec(sql, vec![value!(id)]).await.map(|x| x.rows_affected == 1)
+}
+
+/*
+ *关闭已过期的通知公告
+ *author：刘飞华
+ *date：2026/10/18 16:05:42
+ */
+pub async fn close_expired_notice(rb: &RBatis) -> rbatis::Result<u64> {
+    let sql = "update sys_notice set status = 0 where status = 1 and expire_time is not null and expire_time <= now()";
+    rb.exec(sql, vec![]).await.map(|x| x.rows_affected)
+}
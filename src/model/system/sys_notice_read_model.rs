@@ -0,0 +1,123 @@
+// author：刘飞华
+// createTime：2026/10/18 14:20:05
+
+use crate::common::page::SortColumns;
+use crate::model::system::sys_notice_model::NOTICE_AUDIENCE_SQL;
+use crate::vo::system::sys_notice_vo::{NoticeReadUserResp, QueryNoticeReadUserListReq};
+use rbatis::rbdc::datetime::DateTime;
+use rbatis::RBatis;
+use rbs::{value, Value};
+use serde::{Deserialize, Serialize};
+
+/*
+ *通知公告已读记录
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NoticeRead {
+    pub id: Option<i64>,             //主键
+    pub notice_id: i64,              //公告ID
+    pub user_id: i64,                //用户ID
+    pub read_time: Option<DateTime>, //阅读时间
+}
+
+/*
+ *通知公告已读记录基本操作
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+rbatis::crud!(NoticeRead {}, "sys_notice_read");
+
+/*
+ *通知公告已读用户
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NoticeReadUser {
+    pub user_id: i64,                //用户ID
+    pub user_name: String,           //用户账号
+    pub nick_name: String,           //用户昵称
+    pub read_time: Option<DateTime>, //阅读时间
+}
+
+impl From<NoticeReadUser> for NoticeReadUserResp {
+    fn from(item: NoticeReadUser) -> Self {
+        NoticeReadUserResp {
+            user_id: item.user_id,     //用户ID
+            user_name: item.user_name, //用户账号
+            nick_name: item.nick_name, //用户昵称
+            read_time: item.read_time, //阅读时间
+        }
+    }
+}
+
+/*
+ *标记用户可见的通知公告为已读(ids为空时标记全部)
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+pub async fn read_user_notice(rb: &RBatis, user_id: i64, ids: &[i64]) -> rbatis::Result<u64> {
+    let mut sql = format!(
+        "insert ignore into sys_notice_read (notice_id, user_id, read_time) select n.id, u.id, now() from sys_notice n join sys_user u on u.id = ? where n.status = 1 and {}",
+        NOTICE_AUDIENCE_SQL
+    );
+    let mut param = vec![value!(user_id)];
+    if !ids.is_empty() {
+        sql.push_str(&format!(" and n.id in ({})", ids.iter().map(|_| "?").collect::<Vec<&str>>().join(", ")));
+        param.extend(ids.iter().map(|&id| value!(id)));
+    }
+    rb.exec(&sql, param).await.map(|x| x.rows_affected)
+}
+
+/*
+ *查询通知公告的已读人数
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+#[sql("select count(1) from sys_notice_read r join sys_user u on u.id = r.user_id where u.del_flag = 1 and r.notice_id = ?")]
+pub async fn count_notice_read(rb: &RBatis, notice_id: i64) -> rbatis::Result<u64> {
+    impled!()
+}
+
+// 已读用户列表允许排序的字段
+pub const NOTICE_READ_USER_SORT: SortColumns = SortColumns {
+    columns: &[("userId", "r.user_id"), ("userName", "u.user_name"), ("readTime", "r.read_time")],
+    default: "r.read_time desc",
+};
+
+/*
+ *构建已读用户的查询条件
+ *author：刘飞华
+ *date：2026/10/19 04:20:36
+ */
+fn build_notice_read_user_where(req: &QueryNoticeReadUserListReq) -> (String, Vec<Value>) {
+    let mut sql = " from sys_notice_read r join sys_user u on u.id = r.user_id where u.del_flag = 1 and r.notice_id = ?".to_string();
+    let mut param = vec![value!(req.notice_id)];
+    req.page.push_time_range("r.read_time", &mut sql, &mut param);
+    (sql, param)
+}
+
+/*
+ *分页查询通知公告的已读用户
+ *author：刘飞华
+ *date：2026/10/18 14:20:05
+ */
+pub async fn select_notice_read_user_list(rb: &RBatis, req: &QueryNoticeReadUserListReq) -> rbatis::Result<Vec<NoticeReadUser>> {
+    let (where_sql, mut param) = build_notice_read_user_where(req);
+    let sql = format!("select r.user_id, u.user_name, u.nick_name, r.read_time {} order by {} limit ?, ?", where_sql, req.page.order_by);
+    param.push(value!(req.page.offset()));
+    param.push(value!(req.page.page_size));
+    rb.query_decode(&sql, param).await
+}
+
+/*
+ *查询通知公告的已读用户数量
+ *author：刘飞华
+ *date：2026/10/19 04:20:36
+ */
+pub async fn count_notice_read_user(rb: &RBatis, req: &QueryNoticeReadUserListReq) -> rbatis::Result<u64> {
+    let (where_sql, param) = build_notice_read_user_where(req);
+    rb.query_decode(&format!("select count(1) {}", where_sql), param).await
+}
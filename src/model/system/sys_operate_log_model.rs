@@ -2,7 +2,8 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
-use crate::vo::system::sys_operate_log_vo::{OperateLogResp, QueryOperateLogListReq};
+use crate::common::page::SortColumns;
+use crate::vo::system::sys_operate_log_vo::{OperateLogResp, QueryOperateLogCursorListReq, QueryOperateLogListReq};
 use rbatis::rbdc::datetime::DateTime;
 use rbatis::RBatis;
 use serde::{Deserialize, Serialize};
@@ -79,38 +80,105 @@ impl_select_page!(OperateLog{select_page() =>"
        order by operate_time desc"
 },"sys_operate_log");
 
+// 操作日志列表允许排序的字段
+pub const OPERATE_LOG_SORT: SortColumns = SortColumns {
+    columns: &[
+        ("id", "id"),
+        ("title", "title"),
+        ("businessType", "business_type"),
+        ("operateName", "operate_name"),
+        ("status", "status"),
+        ("operateTime", "operate_time"),
+        ("costTime", "cost_time"),
+    ],
+    default: "operate_time desc",
+};
+
 /*
  *根据条件分页查询操作日志记录
  *author：刘飞华
  *date：2024/12/25 10:01:11
  */
-impl_select_page!(OperateLog{select_page_by_name(
-    req:&QueryOperateLogListReq) =>"
+impl_select_page!(OperateLog{select_page_by_name(req:&QueryOperateLogListReq) =>"
     where 1=1
-     if req.title != '' && req.title != null:
-       ` and title like concat('%', #{req.title}, '%') `
-     if req.businessType != 4:
-       ` and business_type = #{req.businessType} `
-     if req.method != '' && req.method != null:
-       ` and method = #{req.method} `
-     if req.requestMethod != '' && req.requestMethod != null:
-       ` and request_method = #{req.requestMethod} `
-     if req.operatorType != 3:
-       ` and operator_type = #{req.operatorType} `
-     if req.operateName != '' && req.operateName != null:
-       ` and operate_name = #{req.operateName} `
-     if req.deptName != '' && req.deptName != null:
-       ` and dept_name = #{req.deptName} `
-     if req.operateUrl != '' && req.operateUrl != null:
-       ` and operate_url = #{req.operateUrl} `
-     if req.operateIp != '' && req.operateIp != null:
-       ` and operate_ip = #{req.operateIp} `
-     if req.status != 2:
-       ` and status = #{req.status} `
+     if req.title != null && req.title != '':
+      ` and title like concat('%', #{req.title}, '%') `
+     if req.businessType != null:
+      ` and business_type = #{req.businessType} `
+     if req.method != null && req.method != '':
+      ` and method = #{req.method} `
+     if req.requestMethod != null && req.requestMethod != '':
+      ` and request_method = #{req.requestMethod} `
+     if req.operatorType != null:
+      ` and operator_type = #{req.operatorType} `
+     if req.operateName != null && req.operateName != '':
+      ` and operate_name = #{req.operateName} `
+     if req.deptName != null && req.deptName != '':
+      ` and dept_name = #{req.deptName} `
+     if req.operateUrl != null && req.operateUrl != '':
+      ` and operate_url = #{req.operateUrl} `
+     if req.operateIp != null && req.operateIp != '':
+      ` and operate_ip = #{req.operateIp} `
+     if req.operateLocation != null && req.operateLocation != '':
+      ` and operate_location like concat('%', #{req.operateLocation}, '%') `
+     if req.status != null:
+      ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and operate_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and operate_time <= #{req.endTime} `
      if !sql.contains('count'):
-       ` order by operate_time desc `"
+      ` order by ${req.orderBy} `"
+},"sys_operate_log");
+
+/*
+ *根据条件游标分页查询操作日志记录(按operate_time和id倒序,从游标对应的记录之后开始查询)
+ *author：刘飞华
+ *date：2026/10/19 05:12:40
+ */
+impl_select!(OperateLog{select_operate_log_cursor_list(req:&QueryOperateLogCursorListReq) => "
+    where 1=1
+     if req.title != null && req.title != '':
+      ` and title like concat('%', #{req.title}, '%') `
+     if req.businessType != null:
+      ` and business_type = #{req.businessType} `
+     if req.method != null && req.method != '':
+      ` and method = #{req.method} `
+     if req.requestMethod != null && req.requestMethod != '':
+      ` and request_method = #{req.requestMethod} `
+     if req.operatorType != null:
+      ` and operator_type = #{req.operatorType} `
+     if req.operateName != null && req.operateName != '':
+      ` and operate_name = #{req.operateName} `
+     if req.deptName != null && req.deptName != '':
+      ` and dept_name = #{req.deptName} `
+     if req.operateUrl != null && req.operateUrl != '':
+      ` and operate_url = #{req.operateUrl} `
+     if req.operateIp != null && req.operateIp != '':
+      ` and operate_ip = #{req.operateIp} `
+     if req.operateLocation != null && req.operateLocation != '':
+      ` and operate_location like concat('%', #{req.operateLocation}, '%') `
+     if req.status != null:
+      ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and operate_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and operate_time <= #{req.endTime} `
+     if req.cursorTime != null && req.cursorId != null:
+      ` and (operate_time < #{req.cursorTime} or (operate_time = #{req.cursorTime} and id < #{req.cursorId})) `
+     ` order by operate_time desc, id desc limit #{req.limit} `"
 },"sys_operate_log");
 
+/*
+ *查询操作日志记录的近似总数(表的估算行数,不受查询条件影响)
+ *author：刘飞华
+ *date：2026/10/19 05:12:40
+ */
+#[sql("select ifnull(table_rows, 0) from information_schema.tables where table_schema = database() and table_name = 'sys_operate_log'")]
+pub async fn count_operate_log_estimate(rb: &RBatis) -> rbatis::Result<u64> {
+    impled!()
+}
+
 /*
  *清空操作日志
  *author：刘飞华
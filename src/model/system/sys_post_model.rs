@@ -2,6 +2,7 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use crate::common::page::SortColumns;
 use crate::vo::system::sys_post_vo::{PostReq, PostResp, QueryPostListReq};
 use rbatis::rbdc::datetime::DateTime;
 use serde::{Deserialize, Serialize};
@@ -96,6 +97,19 @@ impl_select_page!(Post{select_page() =>"
        order by create_time desc"
 },"sys_post");
 
+// 岗位列表允许排序的字段
+pub const POST_SORT: SortColumns = SortColumns {
+    columns: &[
+        ("id", "id"),
+        ("postCode", "post_code"),
+        ("postName", "post_name"),
+        ("sort", "sort"),
+        ("status", "status"),
+        ("createTime", "create_time"),
+    ],
+    default: "create_time desc",
+};
+
 /*
  *根据条件分页查询岗位信息
  *author：刘飞华
@@ -105,10 +119,14 @@ impl_select_page!(Post{select_post_list(req:&QueryPostListReq) =>"
     where 1=1
      if req.postCode != null && req.postCode != '':
       ` and post_code like concat('%', #{req.postCode}, '%') `
-     if req.postName != null &&req. postName != '':
+     if req.postName != null && req.postName != '':
       ` and post_name like concat('%', #{req.postName}, '%') `
-     if req.status != 2:
+     if req.status != null:
       ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and create_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and create_time <= #{req.endTime} `
      if !sql.contains('count'):
-      ` order by create_time desc"
+      ` order by ${req.orderBy} `"
 },"sys_post");
@@ -1,6 +1,7 @@
 // author：刘飞华
 // createTime：2024/12/12 14:41:44
 
+use crate::common::page::SortColumns;
 use crate::vo::system::sys_role_vo::{QueryRoleListReq, RoleReq, RoleResp};
 use rbatis::rbdc::datetime::DateTime;
 use serde::{Deserialize, Serialize};
@@ -97,18 +98,29 @@ impl_select_page!(Role{select_page() =>"
        order by create_time desc"
 },"sys_role");
 
+// 角色列表允许排序的字段
+pub const ROLE_SORT: SortColumns = SortColumns {
+    columns: &[("id", "id"), ("roleName", "role_name"), ("roleKey", "role_key"), ("status", "status"), ("createTime", "create_time")],
+    default: "create_time desc",
+};
+
 /*
  *根据条件分页查询角色信息
  *author：刘飞华
  *date：2024/12/12 14:41:44
  */
 impl_select_page!(Role{select_sys_role_list(req:&QueryRoleListReq) =>"
-      where 1=1
+    where 1=1
      if req.roleName != null && req.roleName != '':
-       ` and role_name like concat('%', #{req.roleName}, '%') `
+      ` and role_name like concat('%', #{req.roleName}, '%') `
      if req.roleKey != null && req.roleKey != '':
-       ` and role_key like concat('%', #{req.roleKey}, '%') `
-     if req.status != 2:
-       ` and status = #{req.status} `
+      ` and role_key like concat('%', #{req.roleKey}, '%') `
+     if req.status != null:
+      ` and status = #{req.status} `
+     if req.beginTime != null:
+      ` and create_time >= #{req.beginTime} `
+     if req.endTime != null:
+      ` and create_time <= #{req.endTime} `
      if !sql.contains('count'):
-        ` order by create_time desc `"},"sys_role");
+      ` order by ${req.orderBy} `"
+},"sys_role");
@@ -0,0 +1,33 @@
+// author：刘飞华
+// createTime：2026/10/18 23:52:05
+
+use rbatis::rbdc::datetime::DateTime;
+use serde::{Deserialize, Serialize};
+
+/*
+ *用户第三方身份关联(单点登录、LDAP)
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UserIdentity {
+    pub id: Option<i64>,               //主键
+    pub user_id: i64,                  //用户id
+    pub provider: String,              //身份提供方(OIDC的issuer,LDAP的服务器地址)
+    pub subject: String,               //用户在身份提供方的唯一标识(OIDC的sub,LDAP的DN)
+    pub create_time: Option<DateTime>, //创建时间
+}
+
+/*
+ *用户第三方身份关联基本操作
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+rbatis::crud!(UserIdentity {}, "sys_user_identity");
+
+/*
+ *根据身份提供方和唯一标识查询关联的用户
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+impl_select!(UserIdentity{select_by_subject(provider:&str, subject:&str) -> Option => "`where provider = #{provider} and subject = #{subject} limit 1`"}, "sys_user_identity");
@@ -1,11 +1,13 @@
 // author：刘飞华
 // createTime：2024/12/12 14:41:44
 
+use crate::common::page::SortColumns;
+use crate::vo::system::sys_role_vo::{AllocatedListReq, UnallocatedListReq};
 use crate::vo::system::sys_user_vo::{QueryUserListReq, UserReq, UserResp};
 use rbatis::executor::Executor;
 use rbatis::rbdc::datetime::DateTime;
 use rbatis::rbdc::Error;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /*
  *用户信息
  *author：刘飞华
@@ -14,7 +16,6 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct User {
     pub id: Option<i64>,                   //主键
-    pub mobile: String,                    //手机
     pub user_name: String,                 //用户账号
     pub nick_name: String,                 //用户昵称
     pub user_type: Option<String>,         //用户类型（00系统用户）
@@ -28,10 +29,26 @@ pub struct User {
     pub login_browser: String,             //浏览器类型
     pub login_os: String,                  //操作系统
     pub pwd_update_date: Option<DateTime>, //密码最后更新时间
+    pub pwd_must_change: i8,               //是否需要修改密码(0:否,1:是)
+    pub locale: String,                    //语言偏好(zh-CN,en-US,为空时根据请求头Accept-Language)
     pub remark: Option<String>,            //备注
     pub del_flag: i8,                      //删除标志（0代表删除 1代表存在）
     pub create_time: Option<DateTime>,     //创建时间
     pub update_time: Option<DateTime>,     //修改时间
+    #[serde(serialize_with = "serialize_mobile", deserialize_with = "deserialize_mobile")]
+    pub mobile: String, //手机(没有手机号码时数据库中为null)
+}
+
+// 没有手机号码时保存为null(手机号码有唯一索引,允许多个null)
+fn serialize_mobile<S: Serializer>(mobile: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    match mobile {
+        "" => serializer.serialize_none(),
+        x => serializer.serialize_str(x),
+    }
+}
+
+fn deserialize_mobile<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
 }
 
 /*
@@ -59,6 +76,8 @@ impl From<UserReq> for User {
             login_browser: "".to_string(),               //浏览器类型
             login_os: "".to_string(),                    //操作系统
             pwd_update_date: None,                       //密码最后更新时间
+            pwd_must_change: 0,                          //是否需要修改密码(0:否,1:是)
+            locale: "".to_string(),                      //语言偏好
             remark: item.remark,                         //备注
             del_flag: 1,                                 //删除标志（0代表删除 1代表存在）
             create_time: None,                           //创建时间
@@ -90,6 +109,8 @@ impl Into<UserResp> for User {
             login_browser: self.login_browser,             //浏览器类型
             login_os: self.login_os,                       //操作系统
             pwd_update_date: self.pwd_update_date,         //密码最后更新时间
+            pwd_must_change: self.pwd_must_change,         //是否需要修改密码(0:否,1:是)
+            locale: self.locale,                           //语言偏好
             remark: self.remark,                           //备注
             create_time: self.create_time,                 //创建时间
             update_time: self.update_time,                 //修改时间
@@ -136,6 +157,25 @@ impl_select_page!(User{select_page() =>"
        order by create_time desc"
 },"sys_user");
 
+// 用户列表允许排序的字段
+pub const USER_SORT: SortColumns = SortColumns {
+    columns: &[
+        ("id", "id"),
+        ("userName", "user_name"),
+        ("mobile", "mobile"),
+        ("status", "status"),
+        ("loginDate", "login_date"),
+        ("createTime", "create_time"),
+    ],
+    default: "create_time desc",
+};
+
+// 角色已分配、未分配用户列表允许排序的字段
+pub const ROLE_USER_SORT: SortColumns = SortColumns {
+    columns: &[("id", "u.id"), ("userName", "u.user_name"), ("mobile", "u.mobile"), ("createTime", "u.create_time")],
+    default: "u.create_time desc",
+};
+
 /*
  *根据条件分页查询用户信息
  *author：刘飞华
@@ -147,12 +187,16 @@ impl_select_page!(User{select_sys_user_list(req:&QueryUserListReq) =>"
        ` and mobile like concat('%', #{req.mobile}, '%') `
      if req.userName != null && req.userName != '':
        ` and user_name like concat('%', #{req.userName}, '%') `
-     if req.status != 2:
+     if req.status != null:
        ` and status = #{req.status} `
-     if req.deptId != 0:
+     if req.deptId != null && req.deptId != 0:
        ` and (dept_id = #{req.deptId} OR dept_id IN (SELECT id FROM sys_dept WHERE find_in_set(#{req.deptId}, ancestors))) `
+     if req.beginTime != null:
+       ` and create_time >= #{req.beginTime} `
+     if req.endTime != null:
+       ` and create_time <= #{req.endTime} `
      if !sql.contains('count'):
-        ` order by create_time desc `"},"sys_user");
+        ` order by ${req.orderBy} `"},"sys_user");
 
 /*
  *根据条件分页查询已配用户角色列表
@@ -160,14 +204,14 @@ impl_select_page!(User{select_sys_user_list(req:&QueryUserListReq) =>"
  *date：2024/12/12 14:41:44
  */
 #[py_sql(
-    "`select u.* from sys_user u left join sys_user_role ur on u.id = ur.user_id where u.del_flag = 1 and ur.role_id = #{role_id} `
-            if mobile != '':
-                ` and u.mobile = #{mobile} `
-            if user_name != '':
-                ` and u.user_name = #{user_name} `
-            limit #{page_no},#{page_size}` "
+    "`select u.* from sys_user u left join sys_user_role ur on u.id = ur.user_id where u.del_flag = 1 and ur.role_id = #{req.roleId} `
+            if req.mobile != null && req.mobile != '':
+                ` and u.mobile = #{req.mobile} `
+            if req.userName != null && req.userName != '':
+                ` and u.user_name = #{req.userName} `
+            ` order by ${req.orderBy} limit #{offset},#{req.pageSize}` "
 )]
-async fn select_allocated_list(rb: &dyn Executor, role_id: i64, user_name: &str, mobile: &str, page_no: u64, page_size: u64) -> Result<Vec<User>, Error> {
+pub async fn select_allocated_list(rb: &dyn Executor, req: &AllocatedListReq, offset: u64) -> Result<Vec<User>, Error> {
     impled!()
 }
 
@@ -177,13 +221,13 @@ async fn select_allocated_list(rb: &dyn Executor, role_id: i64, user_name: &str,
  * date：2025/1/6 16:13
  */
 #[py_sql(
-    "`select count(1) from sys_user u left join sys_user_role ur on u.id = ur.user_id where u.del_flag = 1 and ur.role_id = #{role_id} `
-            if mobile != '':
-                ` and u.mobile = #{mobile} `
-            if user_name != '':
-                ` and u.user_name = #{user_name} `"
+    "`select count(1) from sys_user u left join sys_user_role ur on u.id = ur.user_id where u.del_flag = 1 and ur.role_id = #{req.roleId} `
+            if req.mobile != null && req.mobile != '':
+                ` and u.mobile = #{req.mobile} `
+            if req.userName != null && req.userName != '':
+                ` and u.user_name = #{req.userName} `"
 )]
-async fn count_allocated_list(rb: &dyn Executor, role_id: i64, user_name: &str, mobile: &str) -> Result<u64, Error> {
+pub async fn count_allocated_list(rb: &dyn Executor, req: &AllocatedListReq) -> Result<u64, Error> {
     impled!()
 }
 
@@ -193,14 +237,14 @@ async fn count_allocated_list(rb: &dyn Executor, role_id: i64, user_name: &str,
  * date：2025/1/6 16:17
  */
 #[py_sql(
-    "`select u.* from sys_user u left join sys_user_role ur on u.id = ur.user_id where u.del_flag = 1 and (ur.role_id != #{role_id} or ur.role_id is null) `
-            if mobile != '':
-                ` and u.mobile = #{mobile} `
-            if user_name != '':
-                ` and u.user_name = #{user_name} `
-            limit #{page_no},#{page_size}` "
+    "`select u.* from sys_user u left join sys_user_role ur on u.id = ur.user_id where u.del_flag = 1 and (ur.role_id != #{req.roleId} or ur.role_id is null) `
+            if req.mobile != null && req.mobile != '':
+                ` and u.mobile = #{req.mobile} `
+            if req.userName != null && req.userName != '':
+                ` and u.user_name = #{req.userName} `
+            ` order by ${req.orderBy} limit #{offset},#{req.pageSize}` "
 )]
-pub async fn select_unallocated_list(rb: &dyn Executor, role_id: i64, user_name: &str, mobile: &str, page_no: u64, page_size: u64) -> rbatis::Result<Vec<User>> {
+pub async fn select_unallocated_list(rb: &dyn Executor, req: &UnallocatedListReq, offset: u64) -> rbatis::Result<Vec<User>> {
     impled!()
 }
 
@@ -210,12 +254,12 @@ pub async fn select_unallocated_list(rb: &dyn Executor, role_id: i64, user_name:
  * date：2025/1/6 16:17
  */
 #[py_sql(
-    "`select count(1) from sys_user u left join sys_user_role ur on u.id = ur.user_id where u.del_flag = 1 and (ur.role_id != #{role_id} or ur.role_id is null) `
-            if mobile != '':
-                ` and u.mobile = #{mobile} `
-            if user_name != '':
-                ` and u.user_name = #{user_name} `"
+    "`select count(1) from sys_user u left join sys_user_role ur on u.id = ur.user_id where u.del_flag = 1 and (ur.role_id != #{req.roleId} or ur.role_id is null) `
+            if req.mobile != null && req.mobile != '':
+                ` and u.mobile = #{req.mobile} `
+            if req.userName != null && req.userName != '':
+                ` and u.user_name = #{req.userName} `"
 )]
-pub async fn count_unallocated_list(rb: &dyn Executor, role_id: i64, user_name: &str, mobile: &str) -> rbatis::Result<u64> {
+pub async fn count_unallocated_list(rb: &dyn Executor, req: &UnallocatedListReq) -> rbatis::Result<u64> {
     impled!()
 }
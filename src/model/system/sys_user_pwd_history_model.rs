@@ -0,0 +1,32 @@
+// author：刘飞华
+// createTime：2026/10/18 21:26:40
+
+use rbatis::rbdc::datetime::DateTime;
+use serde::{Deserialize, Serialize};
+
+/*
+ *用户历史密码
+ *author：刘飞华
+ *date：2026/10/18 21:26:40
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UserPwdHistory {
+    pub id: Option<i64>,               //主键
+    pub user_id: i64,                  //用户id
+    pub password: String,              //密码摘要(sha256,用户id作为盐)
+    pub create_time: Option<DateTime>, //创建时间
+}
+
+/*
+ *用户历史密码基本操作
+ *author：刘飞华
+ *date：2026/10/18 21:26:40
+ */
+rbatis::crud!(UserPwdHistory {}, "sys_user_pwd_history");
+
+/*
+ *查询用户最近使用过的密码
+ *author：刘飞华
+ *date：2026/10/18 21:26:40
+ */
+impl_select!(UserPwdHistory{select_recent(user_id:i64, limit:u64) => "`where user_id = #{user_id} order by id desc limit #{limit}`"}, "sys_user_pwd_history");
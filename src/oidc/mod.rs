@@ -0,0 +1,458 @@
+use crate::common::error::{AppError, AppResult};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+// 查询参数编码时保留的字符(RFC 3986 unreserved)
+const QUERY_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'_').remove(b'.').remove(b'~');
+
+// 允许的ID Token签名算法(只允许非对称算法,防止使用对称密钥伪造)
+const ALLOWED_ALGORITHMS: [Algorithm; 8] = [
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+];
+
+/*
+ *单点登录配置(OpenID Connect)
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+#[derive(Clone, Debug, Deserialize)]
+pub struct OidcConfig {
+    #[serde(default)]
+    pub enabled: bool, //是否开启单点登录
+    #[serde(default)]
+    pub issuer: String, //身份提供方地址(通过{issuer}/.well-known/openid-configuration获取接口地址)
+    #[serde(default)]
+    pub client_id: String, //客户端id
+    #[serde(default)]
+    pub client_secret: String, //客户端密钥(公共客户端为空)
+    #[serde(default)]
+    pub redirect_uri: String, //回调地址(前端页面,收到code和state后调用oidcCallback接口)
+    #[serde(default = "default_scopes")]
+    pub scopes: String, //申请的scope(空格分隔)
+    #[serde(default = "default_groups_claim")]
+    pub groups_claim: String, //ID Token中用户组的字段名
+    #[serde(default = "default_auto_create")]
+    pub auto_create: bool, //用户不存在时是否自动创建
+    #[serde(default = "default_dept_id")]
+    pub dept_id: i64, //自动创建用户的部门ID
+    #[serde(default)]
+    pub role_mapping: HashMap<String, String>, //用户组与角色权限字符(role_key)的对应关系
+}
+
+impl Default for OidcConfig {
+    fn default() -> Self {
+        OidcConfig {
+            enabled: false,
+            issuer: String::new(),
+            client_id: String::new(),
+            client_secret: String::new(),
+            redirect_uri: String::new(),
+            scopes: default_scopes(),
+            groups_claim: default_groups_claim(),
+            auto_create: default_auto_create(),
+            dept_id: default_dept_id(),
+            role_mapping: HashMap::new(),
+        }
+    }
+}
+
+fn default_scopes() -> String {
+    "openid profile email".to_string()
+}
+
+fn default_groups_claim() -> String {
+    "groups".to_string()
+}
+
+fn default_auto_create() -> bool {
+    true
+}
+
+fn default_dept_id() -> i64 {
+    1
+}
+
+// 身份提供方的接口地址(discovery)
+#[derive(Debug, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    #[serde(default)]
+    id_token: String,
+}
+
+/*
+ *ID Token中的用户信息
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+#[derive(Clone, Debug, Default)]
+pub struct OidcUser {
+    pub subject: String,            //用户在身份提供方的唯一标识(sub)
+    pub email: String,              //邮箱
+    pub email_verified: bool,       //邮箱是否已验证
+    pub name: String,               //姓名
+    pub preferred_username: String, //用户名
+    pub phone_number: String,       //手机号码
+    pub groups: Vec<String>,        //用户组
+}
+
+/*
+ *OpenID Connect客户端(授权码模式+PKCE)
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+pub struct OidcClient {
+    config: OidcConfig,
+    http: reqwest::Client,
+    metadata: RwLock<Option<Arc<ProviderMetadata>>>,
+    jwks: RwLock<Option<JwkSet>>,
+}
+
+impl OidcClient {
+    pub fn new(config: OidcConfig) -> Self {
+        OidcClient {
+            config,
+            http: reqwest::Client::new(),
+            metadata: RwLock::new(None),
+            jwks: RwLock::new(None),
+        }
+    }
+
+    pub fn config(&self) -> &OidcConfig {
+        &self.config
+    }
+
+    /*
+     *生成跳转到身份提供方的登录地址
+     *author：刘飞华
+     *date：2026/10/18 23:52:05
+     */
+    pub async fn authorize_url(&self, state: &str, nonce: &str, code_verifier: &str) -> AppResult<String> {
+        let metadata = self.metadata().await?;
+        let params = [
+            ("response_type", "code"),
+            ("client_id", self.config.client_id.as_str()),
+            ("redirect_uri", self.config.redirect_uri.as_str()),
+            ("scope", self.config.scopes.as_str()),
+            ("state", state),
+            ("nonce", nonce),
+            ("code_challenge", pkce_challenge(code_verifier).as_str()),
+            ("code_challenge_method", "S256"),
+        ]
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, utf8_percent_encode(v, QUERY_ENCODE_SET)))
+        .collect::<Vec<String>>()
+        .join("&");
+
+        let separator = if metadata.authorization_endpoint.contains('?') { '&' } else { '?' };
+        Ok(format!("{}{}{}", metadata.authorization_endpoint, separator, params))
+    }
+
+    /*
+     *使用授权码换取ID Token
+     *author：刘飞华
+     *date：2026/10/18 23:52:05
+     */
+    pub async fn exchange_code(&self, code: &str, code_verifier: &str) -> AppResult<String> {
+        let metadata = self.metadata().await?;
+        let body = [
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", self.config.redirect_uri.as_str()),
+            ("client_id", self.config.client_id.as_str()),
+            ("code_verifier", code_verifier),
+        ]
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, utf8_percent_encode(v, QUERY_ENCODE_SET)))
+        .collect::<Vec<String>>()
+        .join("&");
+
+        let mut request = self.http.post(&metadata.token_endpoint).header("content-type", "application/x-www-form-urlencoded").body(body);
+        if !self.config.client_secret.is_empty() {
+            request = request.basic_auth(&self.config.client_id, Some(&self.config.client_secret));
+        }
+        let response = request.send().await.map_err(|e| AppError::OidcError(format!("请求token接口失败: {}", e)))?;
+        let token: TokenResponse = read_json(response).await?;
+        if token.id_token.is_empty() {
+            return Err(AppError::OidcError("token接口没有返回id_token".to_string()));
+        }
+        Ok(token.id_token)
+    }
+
+    /*
+     *验证ID Token(签名、签发者、接收方、有效期和nonce)
+     *author：刘飞华
+     *date：2026/10/18 23:52:05
+     */
+    pub async fn verify_id_token(&self, id_token: &str, nonce: &str) -> AppResult<OidcUser> {
+        let metadata = self.metadata().await?;
+        let header = decode_header(id_token).map_err(|e| AppError::OidcError(format!("id_token格式不正确: {}", e)))?;
+        if !ALLOWED_ALGORITHMS.contains(&header.alg) {
+            return Err(AppError::OidcError(format!("不支持的签名算法: {:?}", header.alg)));
+        }
+        let key = self.decoding_key(header.kid.as_deref().unwrap_or_default()).await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_audience(&[&self.config.client_id]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+        let claims = decode::<HashMap<String, Value>>(id_token, &key, &validation)
+            .map_err(|e| AppError::OidcError(format!("id_token验证失败: {}", e)))?
+            .claims;
+
+        if claim_str(&claims, "nonce") != nonce {
+            return Err(AppError::OidcError("id_token的nonce不正确".to_string()));
+        }
+
+        let groups = match claims.get(&self.config.groups_claim) {
+            Some(Value::Array(x)) => x.iter().filter_map(|x| x.as_str()).map(|x| x.to_string()).collect(),
+            Some(Value::String(x)) => vec![x.clone()],
+            _ => Vec::new(),
+        };
+        let email_verified = match claims.get("email_verified") {
+            Some(Value::Bool(x)) => *x,
+            Some(Value::String(x)) => x == "true",
+            _ => false,
+        };
+
+        Ok(OidcUser {
+            subject: claim_str(&claims, "sub"),
+            email: claim_str(&claims, "email"),
+            email_verified,
+            name: claim_str(&claims, "name"),
+            preferred_username: claim_str(&claims, "preferred_username"),
+            phone_number: claim_str(&claims, "phone_number"),
+            groups,
+        })
+    }
+
+    // 查询身份提供方的接口地址(启动后第一次使用时查询)
+    async fn metadata(&self) -> AppResult<Arc<ProviderMetadata>> {
+        if let Some(x) = self.metadata.read().await.as_ref() {
+            return Ok(x.clone());
+        }
+
+        let issuer = self.config.issuer.trim_end_matches('/');
+        let metadata: ProviderMetadata = self.get_json(&format!("{}/.well-known/openid-configuration", issuer)).await?;
+        if metadata.issuer.trim_end_matches('/') != issuer {
+            return Err(AppError::OidcError(format!("issuer不一致: {}", metadata.issuer)));
+        }
+
+        let metadata = Arc::new(metadata);
+        *self.metadata.write().await = Some(metadata.clone());
+        Ok(metadata)
+    }
+
+    // 根据kid查询签名公钥(找不到时重新获取JWKS,身份提供方可能已轮换密钥)
+    async fn decoding_key(&self, kid: &str) -> AppResult<DecodingKey> {
+        let find = |jwks: &JwkSet| match kid.is_empty() {
+            true if jwks.keys.len() == 1 => jwks.keys.first().cloned(),
+            true => None,
+            false => jwks.find(kid).cloned(),
+        };
+
+        let mut jwk = self.jwks.read().await.as_ref().and_then(find);
+        if jwk.is_none() {
+            let metadata = self.metadata().await?;
+            let jwks: JwkSet = self.get_json(&metadata.jwks_uri).await?;
+            jwk = find(&jwks);
+            *self.jwks.write().await = Some(jwks);
+        }
+
+        match jwk {
+            None => Err(AppError::OidcError(format!("找不到签名公钥: {}", kid))),
+            Some(x) => DecodingKey::from_jwk(&x).map_err(|e| AppError::OidcError(format!("签名公钥格式不正确: {}", e))),
+        }
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> AppResult<T> {
+        let response = self.http.get(url).send().await.map_err(|e| AppError::OidcError(format!("请求{}失败: {}", url, e)))?;
+        read_json(response).await
+    }
+}
+
+async fn read_json<T: DeserializeOwned>(response: reqwest::Response) -> AppResult<T> {
+    let status = response.status();
+    let body = response.bytes().await.map_err(|e| AppError::OidcError(format!("读取响应失败: {}", e)))?;
+    if !status.is_success() {
+        return Err(AppError::OidcError(format!("请求失败: {}, {}", status, String::from_utf8_lossy(&body))));
+    }
+    serde_json::from_slice(&body).map_err(|e| AppError::OidcError(format!("响应格式不正确: {}", e)))
+}
+
+fn claim_str(claims: &HashMap<String, Value>, name: &str) -> String {
+    claims.get(name).and_then(|x| x.as_str()).unwrap_or_default().to_string()
+}
+
+/*
+ *生成随机字符串(用于state、nonce和PKCE的code_verifier)
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+pub fn random_token() -> String {
+    URL_SAFE_NO_PAD.encode(rand::random::<[u8; 32]>())
+}
+
+/*
+ *计算PKCE的code_challenge(S256)
+ *author：刘飞华
+ *date：2026/10/18 23:52:05
+ */
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::oidc::*;
+    use axum::extract::{Query, State};
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    // 测试用RSA私钥(只用于模拟身份提供方签发ID Token)
+    const TEST_PRIVATE_KEY: &str = include_str!("test_rsa.pem");
+    const TEST_MODULUS: &str = "x0mJAl1M3miT1Z5Ju2OKlTdW4YlM1ep_iLqmMcrY9bnaZ1MTm2KXXwkiwYQymwtoZBMqzrsSQvrJC7Vq1-v4ajcyCpGzhlmY9CnYFIQ7URdzktFLVTUb463Xty_XL6EWiu74720r_VwKzGlwZbfLarW4jbyKCdSGR6lkWDK2x1uGxI7vYg7WcvwLWnRNObzOeHBrhbZx1kXoOn9H9wRt3G8aUnTLS54962E0LK847V6kUeMxQWMlx51hpdrDZE4VVgEoGkW5N0u5U6NVDPCpAtuM6MuEvf74_7z3YIZas_mALXhe_lmIW9tC_GCiJp2aMXOk9t2SP8WZ1S-Y5ld-mw";
+
+    // 模拟身份提供方: 保存授权请求的code_challenge和nonce,换取token时校验code_verifier
+    #[derive(Clone)]
+    struct MockIdp {
+        issuer: String,
+        challenge: Arc<Mutex<(String, String)>>,
+    }
+
+    async fn discovery(State(idp): State<MockIdp>) -> Json<Value> {
+        Json(json!({
+            "issuer": idp.issuer,
+            "authorization_endpoint": format!("{}/authorize", idp.issuer),
+            "token_endpoint": format!("{}/token", idp.issuer),
+            "jwks_uri": format!("{}/jwks", idp.issuer),
+        }))
+    }
+
+    async fn jwks() -> Json<Value> {
+        Json(json!({"keys": [{"kty": "RSA", "kid": "test", "alg": "RS256", "use": "sig", "n": TEST_MODULUS, "e": "AQAB"}]}))
+    }
+
+    async fn authorize(State(idp): State<MockIdp>, Query(params): Query<HashMap<String, String>>) -> &'static str {
+        *idp.challenge.lock().unwrap() = (params["code_challenge"].clone(), params["nonce"].clone());
+        "ok"
+    }
+
+    async fn token(State(idp): State<MockIdp>, body: String) -> Result<Json<Value>, axum::http::StatusCode> {
+        let params = body.split('&').filter_map(|x| x.split_once('=')).collect::<HashMap<&str, &str>>();
+        let (challenge, nonce) = idp.challenge.lock().unwrap().clone();
+        if params.get("code") != Some(&"code1") || pkce_challenge(params.get("code_verifier").unwrap_or(&"")) != challenge {
+            return Err(axum::http::StatusCode::BAD_REQUEST);
+        }
+
+        let claims = json!({
+            "iss": idp.issuer,
+            "aud": "faxum",
+            "sub": "u-1001",
+            "exp": chrono::Utc::now().timestamp() + 300,
+            "nonce": nonce,
+            "email": "sso@example.com",
+            "email_verified": true,
+            "name": "单点登录",
+            "groups": ["admins", "dev"],
+        });
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some("test".to_string());
+        let id_token = encode(&header, &claims, &EncodingKey::from_rsa_pem(TEST_PRIVATE_KEY.as_bytes()).unwrap()).unwrap();
+        Ok(Json(json!({"access_token": "at", "token_type": "Bearer", "id_token": id_token})))
+    }
+
+    #[tokio::test]
+    async fn test_oidc_login() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer = format!("http://{}", listener.local_addr().unwrap());
+        let idp = MockIdp {
+            issuer: issuer.clone(),
+            challenge: Arc::new(Mutex::new(Default::default())),
+        };
+        let app = Router::new()
+            .route("/.well-known/openid-configuration", get(discovery))
+            .route("/jwks", get(jwks))
+            .route("/authorize", get(authorize))
+            .route("/token", post(token))
+            .with_state(idp);
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let client = OidcClient::new(OidcConfig {
+            enabled: true,
+            issuer: issuer.clone(),
+            client_id: "faxum".to_string(),
+            client_secret: "secret".to_string(),
+            redirect_uri: "http://localhost:8000/sso/callback".to_string(),
+            ..Default::default()
+        });
+
+        let (state, nonce, code_verifier) = (random_token(), random_token(), random_token());
+        let url = client.authorize_url(&state, &nonce, &code_verifier).await.unwrap();
+        assert!(url.starts_with(&format!("{}/authorize?response_type=code&client_id=faxum", issuer)));
+        assert!(url.contains("code_challenge_method=S256"));
+        reqwest::get(&url).await.unwrap().error_for_status().unwrap();
+
+        // code_verifier不正确时不能换取token
+        assert!(client.exchange_code("code1", "wrong").await.is_err());
+
+        let id_token = client.exchange_code("code1", &code_verifier).await.unwrap();
+        let user = client.verify_id_token(&id_token, &nonce).await.unwrap();
+        assert_eq!(user.subject, "u-1001");
+        assert_eq!(user.email, "sso@example.com");
+        assert!(user.email_verified);
+        assert_eq!(user.groups, vec!["admins", "dev"]);
+
+        // nonce不正确、接收方不正确时验证失败
+        assert!(client.verify_id_token(&id_token, "wrong").await.is_err());
+        let other = OidcClient::new(OidcConfig {
+            issuer: issuer.clone(),
+            client_id: "other".to_string(),
+            ..Default::default()
+        });
+        assert!(other.verify_id_token(&id_token, &nonce).await.is_err());
+
+        // 不接受对称算法签名的token
+        let forged = encode(
+            &Header::new(Algorithm::HS256),
+            &json!({"iss": issuer, "aud": "faxum", "sub": "x", "exp": 9999999999i64, "nonce": nonce}),
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .unwrap();
+        assert!(client.verify_id_token(&forged, &nonce).await.is_err());
+    }
+
+    #[test]
+    fn test_pkce_challenge() {
+        // RFC 7636 附录B的示例
+        assert_eq!(pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"), "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM");
+        assert_eq!(random_token().len(), 43);
+    }
+}
@@ -0,0 +1,260 @@
+use crate::common::result::ErrorResponse;
+use crate::handler::system::{
+    sys_api_key_handler, sys_config_handler, sys_dept_handler, sys_dict_data_handler, sys_dict_type_handler, sys_file_handler, sys_job_handler, sys_job_log_handler, sys_login_log_handler,
+    sys_menu_handler, sys_message_handler, sys_notice_handler, sys_operate_log_handler, sys_post_handler, sys_profile_handler, sys_role_handler, sys_user_handler,
+};
+use crate::utils::api_key_util::API_KEY_HEADER;
+use axum::Router;
+use serde::Deserialize;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme};
+use utoipa::openapi::{ContentBuilder, Ref, RefOr, ResponseBuilder};
+use utoipa::{Modify, OpenApi};
+use utoipa_swagger_ui::SwaggerUi;
+
+// 接口文档地址(不需要登录就可以访问)
+pub const OPENAPI_JSON_PATH: &str = "/api/openapi.json";
+pub const SWAGGER_UI_PATH: &str = "/api/docs";
+
+/*
+ *接口文档配置
+ *author：刘飞华
+ *date：2026/10/19 06:05:21
+ */
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct OpenApiConfig {
+    #[serde(default)]
+    pub enabled: bool, //是否开启接口文档(生产环境建议关闭)
+}
+
+/*
+ *接口文档(根据handler上的#[utoipa::path]和vo上的ToSchema生成,新增接口时需要在paths中添加)
+ *author：刘飞华
+ *date：2026/10/19 06:05:21
+ */
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "faxum-admin", description = "faxum-admin后台管理系统接口文档"),
+    paths(
+        sys_api_key_handler::add_sys_api_key,
+        sys_api_key_handler::update_sys_api_key,
+        sys_api_key_handler::revoke_sys_api_key,
+        sys_api_key_handler::query_sys_api_key_list,
+        sys_config_handler::add_sys_config,
+        sys_config_handler::delete_sys_config,
+        sys_config_handler::update_sys_config,
+        sys_config_handler::refresh_sys_config_cache,
+        sys_config_handler::query_sys_config_detail,
+        sys_config_handler::query_sys_config_list,
+        sys_dept_handler::add_sys_dept,
+        sys_dept_handler::delete_sys_dept,
+        sys_dept_handler::update_sys_dept,
+        sys_dept_handler::update_sys_dept_status,
+        sys_dept_handler::query_sys_dept_detail,
+        sys_dept_handler::query_sys_dept_list,
+        sys_dict_data_handler::add_sys_dict_data,
+        sys_dict_data_handler::delete_sys_dict_data,
+        sys_dict_data_handler::update_sys_dict_data,
+        sys_dict_data_handler::update_sys_dict_data_status,
+        sys_dict_data_handler::query_sys_dict_data_detail,
+        sys_dict_data_handler::query_sys_dict_data_list,
+        sys_dict_type_handler::add_sys_dict_type,
+        sys_dict_type_handler::delete_sys_dict_type,
+        sys_dict_type_handler::update_sys_dict_type,
+        sys_dict_type_handler::update_sys_dict_type_status,
+        sys_dict_type_handler::query_sys_dict_type_detail,
+        sys_dict_type_handler::query_sys_dict_type_list,
+        sys_file_handler::upload_file,
+        sys_file_handler::download_file,
+        sys_file_handler::delete_sys_file,
+        sys_file_handler::query_sys_file_detail,
+        sys_file_handler::query_sys_file_list,
+        sys_job_log_handler::delete_sys_job_log,
+        sys_job_log_handler::clean_sys_job_log,
+        sys_job_log_handler::query_sys_job_log_detail,
+        sys_job_log_handler::query_sys_job_log_list,
+        sys_job_handler::add_sys_job,
+        sys_job_handler::delete_sys_job,
+        sys_job_handler::update_sys_job,
+        sys_job_handler::update_sys_job_status,
+        sys_job_handler::run_sys_job,
+        sys_job_handler::query_sys_job_detail,
+        sys_job_handler::query_sys_job_list,
+        sys_job_handler::query_invoke_target_list,
+        sys_login_log_handler::delete_sys_login_log,
+        sys_login_log_handler::delete_sys_login_log_by_time,
+        sys_login_log_handler::clean_sys_login_log,
+        sys_login_log_handler::query_sys_login_log_detail,
+        sys_login_log_handler::query_sys_login_log_list,
+        sys_login_log_handler::query_sys_login_log_cursor_list,
+        sys_menu_handler::add_sys_menu,
+        sys_menu_handler::delete_sys_menu,
+        sys_menu_handler::update_sys_menu,
+        sys_menu_handler::update_sys_menu_status,
+        sys_menu_handler::query_sys_menu_detail,
+        sys_menu_handler::query_sys_menu_list,
+        sys_menu_handler::query_sys_menu_list_simple,
+        sys_message_handler::message_stream,
+        sys_notice_handler::add_sys_notice,
+        sys_notice_handler::delete_sys_notice,
+        sys_notice_handler::update_sys_notice,
+        sys_notice_handler::update_sys_notice_status,
+        sys_notice_handler::query_sys_notice_detail,
+        sys_notice_handler::query_sys_notice_list,
+        sys_notice_handler::query_notice_read_stats,
+        sys_notice_handler::query_notice_read_user_list,
+        sys_notice_handler::query_my_notice_list,
+        sys_notice_handler::query_my_unread_count,
+        sys_notice_handler::read_notice,
+        sys_notice_handler::read_all_notice,
+        sys_operate_log_handler::delete_sys_operate_log,
+        sys_operate_log_handler::delete_sys_operate_log_by_time,
+        sys_operate_log_handler::clean_sys_operate_log,
+        sys_operate_log_handler::query_sys_operate_log_detail,
+        sys_operate_log_handler::query_sys_operate_log_list,
+        sys_operate_log_handler::query_sys_operate_log_cursor_list,
+        sys_post_handler::add_sys_post,
+        sys_post_handler::delete_sys_post,
+        sys_post_handler::update_sys_post,
+        sys_post_handler::update_sys_post_status,
+        sys_post_handler::query_sys_post_detail,
+        sys_post_handler::query_sys_post_list,
+        sys_profile_handler::query_profile,
+        sys_profile_handler::update_profile,
+        sys_profile_handler::update_locale,
+        sys_profile_handler::upload_avatar,
+        sys_profile_handler::query_avatar,
+        sys_role_handler::add_sys_role,
+        sys_role_handler::delete_sys_role,
+        sys_role_handler::update_sys_role,
+        sys_role_handler::update_sys_role_status,
+        sys_role_handler::query_sys_role_detail,
+        sys_role_handler::query_sys_role_list,
+        sys_role_handler::query_role_menu,
+        sys_role_handler::update_role_menu,
+        sys_role_handler::query_allocated_list,
+        sys_role_handler::query_unallocated_list,
+        sys_role_handler::cancel_auth_user,
+        sys_role_handler::batch_cancel_auth_user,
+        sys_role_handler::batch_auth_user,
+        sys_user_handler::add_sys_user,
+        sys_user_handler::delete_sys_user,
+        sys_user_handler::update_sys_user,
+        sys_user_handler::update_sys_user_status,
+        sys_user_handler::force_logout,
+        sys_user_handler::reset_sys_user_password,
+        sys_user_handler::query_sys_user_detail,
+        sys_user_handler::query_sys_user_list,
+        sys_user_handler::login,
+        sys_user_handler::login_verify,
+        sys_user_handler::send_login_code,
+        sys_user_handler::login_by_code,
+        sys_user_handler::oidc_authorize,
+        sys_user_handler::oidc_callback,
+        sys_user_handler::login_update_password,
+        sys_user_handler::logout,
+        sys_user_handler::forgot_password,
+        sys_user_handler::confirm_reset_password,
+        sys_user_handler::query_user_menu,
+        sys_user_handler::query_user_role,
+        sys_user_handler::update_user_role,
+        sys_user_handler::update_sys_user_password,
+    ),
+    components(schemas(ErrorResponse)),
+    modifiers(&SecurityAddon, &ErrorResponseAddon)
+)]
+pub struct ApiDoc;
+
+/*
+ *认证方式: 登录token(Authorization: Bearer)或者API密钥(x-api-key),没有设置security的接口都需要认证
+ *author：刘飞华
+ *date：2026/10/19 06:05:21
+ */
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme("bearer_auth", SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).bearer_format("JWT").build()));
+        components.add_security_scheme("api_key", SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(API_KEY_HEADER))));
+        openapi.security = Some(vec![
+            SecurityRequirement::new("bearer_auth", Vec::<String>::new()),
+            SecurityRequirement::new("api_key", Vec::<String>::new()),
+        ]);
+    }
+}
+
+/*
+ *所有接口的错误响应(HTTP状态码和errorCode见错误码目录,开启legacy_status时HTTP状态码为200)
+ *author：刘飞华
+ *date：2026/10/19 06:05:21
+ */
+struct ErrorResponseAddon;
+
+impl Modify for ErrorResponseAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let response = ResponseBuilder::new()
+            .description("错误响应")
+            .content("application/json", ContentBuilder::new().schema(Some(Ref::from_schema_name("ErrorResponse"))).build())
+            .build();
+        openapi
+            .components
+            .get_or_insert_with(Default::default)
+            .responses
+            .insert("ErrorResponse".to_string(), RefOr::T(response));
+
+        for item in openapi.paths.paths.values_mut() {
+            for operation in [&mut item.get, &mut item.post].into_iter().flatten() {
+                operation.responses.responses.insert("default".to_string(), RefOr::Ref(Ref::from_response_name("ErrorResponse")));
+            }
+        }
+    }
+}
+
+/*
+ *构建接口文档路由(未开启时不注册,Swagger UI的静态资源在编译时打包,不依赖外部CDN)
+ *author：刘飞华
+ *date：2026/10/19 06:05:21
+ */
+pub fn build_openapi_route<S: Clone + Send + Sync + 'static>(config: &OpenApiConfig) -> Router<S> {
+    if !config.enabled {
+        return Router::new();
+    }
+    Router::new().merge(SwaggerUi::new(SWAGGER_UI_PATH).url(OPENAPI_JSON_PATH, ApiDoc::openapi()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_openapi() {
+        let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let paths = doc["paths"].as_object().unwrap();
+        assert!(paths.len() > 100);
+
+        // 登录接口不需要认证,其他接口使用全局的认证方式
+        assert_eq!(doc["paths"]["/api/system/user/login"]["post"]["security"], serde_json::json!([{}]));
+        assert_eq!(doc["paths"]["/api/system/user/addUser"]["post"]["security"], Value::Null);
+        assert!(doc["components"]["securitySchemes"]["bearer_auth"].is_object());
+        assert!(doc["paths"]["/api/system/user/addUser"]["post"]["responses"]["default"].is_object());
+
+        // 分页参数展开到请求参数中,服务端生成的字段不出现在文档中
+        let schemas = &doc["components"]["schemas"];
+        assert!(schemas["UserResp"].is_object());
+        assert!(schemas["PageQuery"]["properties"]["pageSize"].is_object());
+        assert!(schemas["PageQuery"]["properties"]["orderBy"].is_null());
+        assert!(schemas["CursorQuery"]["properties"]["limit"].is_null());
+    }
+
+    #[test]
+    fn test_openapi_route() {
+        // 和/api下的接口一起注册时路由不冲突
+        let _: Router = build_openapi_route(&OpenApiConfig { enabled: true }).nest("/api", Router::new().route("/system/user/login", axum::routing::post(|| async {})));
+
+        // Swagger UI的静态资源已打包,不需要访问外部CDN
+        let config = std::sync::Arc::new(utoipa_swagger_ui::Config::from(OPENAPI_JSON_PATH));
+        let file = utoipa_swagger_ui::serve("swagger-ui-bundle.js", config).unwrap().unwrap();
+        assert!(!file.bytes.is_empty());
+    }
+}
@@ -0,0 +1,18 @@
+use crate::handler::system::sys_api_key_handler;
+use crate::AppState;
+use axum::routing::post;
+use axum::Router;
+use std::sync::Arc;
+/*
+ *构建API密钥路由
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+pub fn build_sys_api_key_route() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/system/apiKey/addApiKey", post(sys_api_key_handler::add_sys_api_key))
+        .route("/system/apiKey/updateApiKey", post(sys_api_key_handler::update_sys_api_key))
+        .route("/system/apiKey/revokeApiKey", post(sys_api_key_handler::revoke_sys_api_key))
+        .route("/system/apiKey/queryApiKeyList", post(sys_api_key_handler::query_sys_api_key_list))
+    //记得在main.rs中添加路由build_sys_api_key_route()
+}
@@ -0,0 +1,20 @@
+use crate::handler::system::sys_config_handler;
+use crate::AppState;
+use axum::routing::{get, post};
+use axum::Router;
+use std::sync::Arc;
+/*
+ *构建参数配置路由
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+pub fn build_sys_config_route() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/system/config/addConfig", post(sys_config_handler::add_sys_config))
+        .route("/system/config/deleteConfig", post(sys_config_handler::delete_sys_config))
+        .route("/system/config/updateConfig", post(sys_config_handler::update_sys_config))
+        .route("/system/config/refreshConfigCache", get(sys_config_handler::refresh_sys_config_cache))
+        .route("/system/config/queryConfigDetail", post(sys_config_handler::query_sys_config_detail))
+        .route("/system/config/queryConfigList", post(sys_config_handler::query_sys_config_list))
+    //记得在main.rs中添加路由build_sys_config_route()
+}
@@ -0,0 +1,21 @@
+use crate::handler::system::sys_file_handler;
+use crate::AppState;
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{get, post};
+use axum::Router;
+use std::sync::Arc;
+/*
+ *构建文件管理路由
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+pub fn build_sys_file_route(max_size: usize) -> Router<Arc<AppState>> {
+    Router::new()
+        // 请求体大小限制: 文件大小加上multipart表单的额外内容
+        .route("/system/file/upload", post(sys_file_handler::upload_file).layer(DefaultBodyLimit::max(max_size + 64 * 1024)))
+        .route("/system/file/download", get(sys_file_handler::download_file))
+        .route("/system/file/deleteFile", post(sys_file_handler::delete_sys_file))
+        .route("/system/file/queryFileDetail", post(sys_file_handler::query_sys_file_detail))
+        .route("/system/file/queryFileList", post(sys_file_handler::query_sys_file_list))
+    //记得在main.rs中添加路由build_sys_file_route()
+}
@@ -0,0 +1,18 @@
+use crate::handler::system::sys_job_log_handler;
+use crate::AppState;
+use axum::routing::{get, post};
+use axum::Router;
+use std::sync::Arc;
+/*
+ *构建定时任务日志路由
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+pub fn build_sys_job_log_route() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/system/jobLog/deleteJobLog", post(sys_job_log_handler::delete_sys_job_log))
+        .route("/system/jobLog/cleanJobLog", get(sys_job_log_handler::clean_sys_job_log))
+        .route("/system/jobLog/queryJobLogDetail", post(sys_job_log_handler::query_sys_job_log_detail))
+        .route("/system/jobLog/queryJobLogList", post(sys_job_log_handler::query_sys_job_log_list))
+    //记得在main.rs中添加路由build_sys_job_log_route()
+}
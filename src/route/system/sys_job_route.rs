@@ -0,0 +1,22 @@
+use crate::handler::system::sys_job_handler;
+use crate::AppState;
+use axum::routing::{get, post};
+use axum::Router;
+use std::sync::Arc;
+/*
+ *构建定时任务路由
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+pub fn build_sys_job_route() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/system/job/addJob", post(sys_job_handler::add_sys_job))
+        .route("/system/job/deleteJob", post(sys_job_handler::delete_sys_job))
+        .route("/system/job/updateJob", post(sys_job_handler::update_sys_job))
+        .route("/system/job/updateJobStatus", post(sys_job_handler::update_sys_job_status))
+        .route("/system/job/runJob", post(sys_job_handler::run_sys_job))
+        .route("/system/job/queryJobDetail", post(sys_job_handler::query_sys_job_detail))
+        .route("/system/job/queryJobList", post(sys_job_handler::query_sys_job_list))
+        .route("/system/job/queryInvokeTargetList", get(sys_job_handler::query_invoke_target_list))
+    //记得在main.rs中添加路由build_sys_job_route()
+}
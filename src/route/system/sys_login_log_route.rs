@@ -11,8 +11,10 @@ use std::sync::Arc;
 pub fn build_sys_login_log_route() -> Router<Arc<AppState>> {
     Router::new()
         .route("/system/loginLog/deleteLoginLog", post(sys_login_log_handler::delete_sys_login_log))
+        .route("/system/loginLog/deleteLoginLogByTime", post(sys_login_log_handler::delete_sys_login_log_by_time))
         .route("/system/loginLog/cleanLoginLog", get(sys_login_log_handler::clean_sys_login_log))
         .route("/system/loginLog/queryLoginLogDetail", post(sys_login_log_handler::query_sys_login_log_detail))
         .route("/system/loginLog/queryLoginLogList", post(sys_login_log_handler::query_sys_login_log_list))
+        .route("/system/loginLog/queryLoginLogCursorList", post(sys_login_log_handler::query_sys_login_log_cursor_list))
     //记得在main.rs中添加路由build_sys_login_log_route()
 }
@@ -0,0 +1,14 @@
+use crate::handler::system::sys_message_handler;
+use crate::AppState;
+use axum::routing::get;
+use axum::Router;
+use std::sync::Arc;
+/*
+ *构建消息推送路由
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+pub fn build_sys_message_route() -> Router<Arc<AppState>> {
+    Router::new().route("/system/message/stream", get(sys_message_handler::message_stream))
+    //记得在main.rs中添加路由build_sys_message_route()
+}
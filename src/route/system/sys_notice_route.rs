@@ -1,6 +1,6 @@
 use crate::handler::system::sys_notice_handler;
 use crate::AppState;
-use axum::routing::post;
+use axum::routing::{get, post};
 use axum::Router;
 use std::sync::Arc;
 /*
@@ -16,5 +16,11 @@ pub fn build_sys_notice_route() -> Router<Arc<AppState>> {
         .route("/system/notice/updateNoticeStatus", post(sys_notice_handler::update_sys_notice_status))
         .route("/system/notice/queryNoticeDetail", post(sys_notice_handler::query_sys_notice_detail))
         .route("/system/notice/queryNoticeList", post(sys_notice_handler::query_sys_notice_list))
+        .route("/system/notice/queryNoticeReadStats", post(sys_notice_handler::query_notice_read_stats))
+        .route("/system/notice/queryNoticeReadUserList", post(sys_notice_handler::query_notice_read_user_list))
+        .route("/system/notice/queryMyNoticeList", post(sys_notice_handler::query_my_notice_list))
+        .route("/system/notice/queryMyUnreadCount", get(sys_notice_handler::query_my_unread_count))
+        .route("/system/notice/readNotice", post(sys_notice_handler::read_notice))
+        .route("/system/notice/readAllNotice", post(sys_notice_handler::read_all_notice))
     //记得在main.rs中添加路由build_sys_notice_route()
 }
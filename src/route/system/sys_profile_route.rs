@@ -0,0 +1,25 @@
+use crate::handler::system::sys_profile_handler;
+use crate::handler::system::sys_profile_handler::AVATAR_MAX_SIZE;
+use crate::AppState;
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{get, post};
+use axum::Router;
+use std::sync::Arc;
+/*
+ *构建个人中心路由
+ *author：刘飞华
+ *date：2026/10/18 20:12:40
+ */
+pub fn build_sys_profile_route() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/system/user/profile", get(sys_profile_handler::query_profile))
+        .route("/system/user/profile/updateProfile", post(sys_profile_handler::update_profile))
+        .route("/system/user/profile/updateLocale", post(sys_profile_handler::update_locale))
+        // 请求体大小限制: 头像文件大小加上multipart表单的额外内容
+        .route(
+            "/system/user/profile/uploadAvatar",
+            post(sys_profile_handler::upload_avatar).layer(DefaultBodyLimit::max(AVATAR_MAX_SIZE + 64 * 1024)),
+        )
+        .route("/system/user/profile/avatar/{file_name}", get(sys_profile_handler::query_avatar))
+    //记得在main.rs中添加路由build_sys_profile_route()
+}
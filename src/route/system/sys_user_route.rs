@@ -15,10 +15,20 @@ pub fn build_sys_user_route() -> Router<Arc<AppState>> {
         .route("/system/user/deleteUser", post(sys_user_handler::delete_sys_user))
         .route("/system/user/updateUser", post(sys_user_handler::update_sys_user))
         .route("/system/user/updateUserStatus", post(sys_user_handler::update_sys_user_status))
+        .route("/system/user/forceLogout", post(sys_user_handler::force_logout))
         .route("/system/user/reset_sys_user_password", post(sys_user_handler::reset_sys_user_password))
         .route("/system/user/queryUserDetail", post(sys_user_handler::query_sys_user_detail))
         .route("/system/user/queryUserList", post(sys_user_handler::query_sys_user_list))
         .route("/system/user/login", post(sys_user_handler::login))
+        .route("/system/user/loginVerify", post(sys_user_handler::login_verify))
+        .route("/system/user/sendLoginCode", post(sys_user_handler::send_login_code))
+        .route("/system/user/loginByCode", post(sys_user_handler::login_by_code))
+        .route("/system/user/oidcAuthorize", get(sys_user_handler::oidc_authorize))
+        .route("/system/user/oidcCallback", post(sys_user_handler::oidc_callback))
+        .route("/system/user/loginUpdatePassword", post(sys_user_handler::login_update_password))
+        .route("/system/user/logout", post(sys_user_handler::logout))
+        .route("/system/user/forgotPassword", post(sys_user_handler::forgot_password))
+        .route("/system/user/confirmResetPassword", post(sys_user_handler::confirm_reset_password))
         .route("/system/user/queryUserMenu", get(sys_user_handler::query_user_menu))
         .route("/system/user/queryUserRole", post(sys_user_handler::query_user_role))
         .route("/system/user/updateUserRole", post(sys_user_handler::update_user_role))
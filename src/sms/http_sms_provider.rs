@@ -0,0 +1,91 @@
+use crate::common::error::{AppError, AppResult};
+use crate::sms::{SmsConfig, SmsProvider};
+use async_trait::async_trait;
+use std::time::Duration;
+
+/*
+ *调用短信网关的HTTP接口发送验证码(POST JSON,返回2xx时视为发送成功)
+ *author：刘飞华
+ *date：2026/10/18 22:52:18
+ */
+pub struct HttpSmsProvider {
+    client: reqwest::Client,
+    config: SmsConfig,
+}
+
+impl HttpSmsProvider {
+    pub fn new(config: SmsConfig) -> Self {
+        HttpSmsProvider {
+            client: reqwest::Client::builder().timeout(Duration::from_secs(10)).build().unwrap_or_default(),
+            config,
+        }
+    }
+
+    // 替换请求体模板中的手机号码和验证码(按JSON字符串转义)
+    fn body(&self, mobile: &str, code: &str) -> String {
+        let escape = |x: &str| serde_json::to_string(x).map(|x| x[1..x.len() - 1].to_string()).unwrap_or_default();
+        self.config.body.replace("{mobile}", &escape(mobile)).replace("{code}", &escape(code))
+    }
+}
+
+#[async_trait]
+impl SmsProvider for HttpSmsProvider {
+    async fn send_code(&self, mobile: &str, code: &str) -> AppResult<()> {
+        let mut request = self.client.post(&self.config.url).header("content-type", "application/json");
+        for (name, value) in &self.config.headers {
+            request = request.header(name, value);
+        }
+
+        let response = request.body(self.body(mobile, code)).send().await.map_err(|e| AppError::SmsError(e.to_string()))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(AppError::SmsError(format!("{}, {}", status, text)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sms::http_sms_provider::*;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test]
+    async fn test_http_sms_provider() {
+        let received: Arc<Mutex<Vec<serde_json::Value>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let sink = received.clone();
+        let app = Router::new().route(
+            "/sms",
+            post(move |headers: HeaderMap, body: String| async move {
+                if headers.get("x-api-key").and_then(|x| x.to_str().ok()) != Some("secret") {
+                    return StatusCode::UNAUTHORIZED;
+                }
+                sink.lock().unwrap().push(serde_json::from_str(&body).unwrap());
+                StatusCode::OK
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/sms", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let mut config = SmsConfig {
+            kind: "http".to_string(),
+            url,
+            body: r#"{"phone":"{mobile}","template":"LOGIN","params":{"code":"{code}"}}"#.to_string(),
+            ..Default::default()
+        };
+        config.headers.insert("x-api-key".to_string(), "secret".to_string());
+
+        HttpSmsProvider::new(config.clone()).send_code("18613030111", "123456").await.unwrap();
+        let received = received.lock().unwrap().clone();
+        assert_eq!(received, vec![serde_json::json!({"phone": "18613030111", "template": "LOGIN", "params": {"code": "123456"}})]);
+
+        config.headers.clear();
+        assert!(HttpSmsProvider::new(config).send_code("18613030111", "123456").await.is_err());
+    }
+}
@@ -0,0 +1,18 @@
+use crate::common::error::AppResult;
+use crate::sms::SmsProvider;
+use async_trait::async_trait;
+
+/*
+ *验证码短信输出到日志(开发环境使用,不实际发送)
+ *author：刘飞华
+ *date：2026/10/18 22:52:18
+ */
+pub struct LogSmsProvider;
+
+#[async_trait]
+impl SmsProvider for LogSmsProvider {
+    async fn send_code(&self, mobile: &str, code: &str) -> AppResult<()> {
+        log::info!("send sms code mobile: {}, code: {}", mobile, code);
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+pub mod http_sms_provider;
+pub mod log_sms_provider;
+
+use crate::common::error::AppResult;
+use crate::sms::http_sms_provider::HttpSmsProvider;
+use crate::sms::log_sms_provider::LogSmsProvider;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/*
+ *短信发送(HTTP接口、开发环境输出到日志)
+ *author：刘飞华
+ *date：2026/10/18 22:52:18
+ */
+#[async_trait]
+pub trait SmsProvider: Send + Sync {
+    // 发送验证码短信
+    async fn send_code(&self, mobile: &str, code: &str) -> AppResult<()>;
+}
+
+/*
+ *短信发送配置
+ *author：刘飞华
+ *date：2026/10/18 22:52:18
+ */
+#[derive(Clone, Debug, Deserialize)]
+pub struct SmsConfig {
+    #[serde(default = "default_kind")]
+    pub kind: String, //发送方式(log:输出到日志,http:调用短信网关的HTTP接口)
+    #[serde(default)]
+    pub url: String, //短信网关地址
+    #[serde(default)]
+    pub headers: HashMap<String, String>, //请求头(如鉴权信息)
+    #[serde(default = "default_body")]
+    pub body: String, //请求体模板(JSON),{mobile}和{code}会被替换为手机号码和验证码
+}
+
+impl Default for SmsConfig {
+    fn default() -> Self {
+        SmsConfig {
+            kind: default_kind(),
+            url: String::new(),
+            headers: HashMap::new(),
+            body: default_body(),
+        }
+    }
+}
+
+fn default_kind() -> String {
+    "log".to_string()
+}
+
+fn default_body() -> String {
+    r#"{"mobile":"{mobile}","code":"{code}"}"#.to_string()
+}
+
+/*
+ *根据配置创建短信发送
+ *author：刘飞华
+ *date：2026/10/18 22:52:18
+ */
+pub fn build_sms_provider(config: &SmsConfig) -> Arc<dyn SmsProvider> {
+    match config.kind.as_str() {
+        "http" => Arc::new(HttpSmsProvider::new(config.clone())),
+        "log" => Arc::new(LogSmsProvider),
+        x => {
+            log::error!("unknown sms kind: {}, use log sms provider", x);
+            Arc::new(LogSmsProvider)
+        }
+    }
+}
+
+/*
+ *后台发送验证码短信(不等待发送结果,发送失败时记录日志)
+ *author：刘飞华
+ *date：2026/10/18 22:52:18
+ */
+pub fn send_code_background(sms: Arc<dyn SmsProvider>, mobile: String, code: String) {
+    tokio::spawn(async move {
+        if let Err(e) = sms.send_code(&mobile, &code).await {
+            log::error!("send sms code error mobile: {}, error message: {:?}", mobile, e);
+        }
+    });
+}
+
+/*
+ *校验手机号码格式(中国大陆11位手机号码)
+ *author：刘飞华
+ *date：2026/10/18 22:52:18
+ */
+pub fn is_valid_mobile(mobile: &str) -> bool {
+    mobile.len() == 11 && mobile.starts_with('1') && mobile.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sms::*;
+
+    #[test]
+    fn test_is_valid_mobile() {
+        assert!(is_valid_mobile("18613030111"));
+        assert!(!is_valid_mobile("28613030111"));
+        assert!(!is_valid_mobile("1861303011"));
+        assert!(!is_valid_mobile("1861303011a"));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::common::error::{AppError, AppResult};
+use crate::storage::Storage;
+use async_trait::async_trait;
+use axum::body::{Body, Bytes};
+use std::io::SeekFrom;
+use std::path::PathBuf;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio_util::io::ReaderStream;
+
+/*
+ *本地文件系统存储
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: &str) -> Self {
+        LocalStorage { root: PathBuf::from(root) }
+    }
+
+    // 文件路径(key只允许字母、数字、下划线、中划线、点和斜杠,不允许访问存储目录以外的文件)
+    fn path(&self, key: &str) -> AppResult<PathBuf> {
+        let valid = !key.is_empty() && key.split('/').all(|x| !x.is_empty() && x != "." && x != "..") && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.' || c == '/');
+        if !valid {
+            return Err(AppError::StorageError(format!("非法的文件路径: {}", key)));
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    fn kind(&self) -> &'static str {
+        "local"
+    }
+
+    async fn put(&self, key: &str, data: Bytes, _content_type: &str) -> AppResult<()> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        // 先写临时文件再重命名,避免读到写了一半的文件
+        let tmp = path.with_extension(format!("tmp{:08x}", rand::random::<u32>()));
+        tokio::fs::write(&tmp, &data).await?;
+        tokio::fs::rename(&tmp, &path).await?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str, range: Option<(u64, u64)>) -> AppResult<Body> {
+        let path = self.path(key)?;
+        let mut file = tokio::fs::File::open(&path).await?;
+
+        match range {
+            None => Ok(Body::from_stream(ReaderStream::new(file))),
+            Some((start, end)) => {
+                file.seek(SeekFrom::Start(start)).await?;
+                Ok(Body::from_stream(ReaderStream::new(file.take(end - start + 1))))
+            }
+        }
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::local_storage::*;
+
+    #[tokio::test]
+    async fn test_local_storage() {
+        let root = std::env::temp_dir().join(format!("faxum_storage_{:08x}", rand::random::<u32>()));
+        let storage = LocalStorage::new(root.to_str().unwrap());
+
+        storage.put("ab/abc.txt", Bytes::from("0123456789"), "text/plain").await.unwrap();
+
+        let body = storage.get("ab/abc.txt", None).await.unwrap();
+        assert_eq!(axum::body::to_bytes(body, 100).await.unwrap(), "0123456789");
+
+        let body = storage.get("ab/abc.txt", Some((2, 5))).await.unwrap();
+        assert_eq!(axum::body::to_bytes(body, 100).await.unwrap(), "2345");
+
+        assert!(storage.get("../abc.txt", None).await.is_err());
+
+        storage.delete("ab/abc.txt").await.unwrap();
+        storage.delete("ab/abc.txt").await.unwrap();
+        assert!(storage.get("ab/abc.txt", None).await.is_err());
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,181 @@
+pub mod local_storage;
+pub mod s3_storage;
+
+use crate::common::error::AppResult;
+use crate::storage::local_storage::LocalStorage;
+use crate::storage::s3_storage::S3Storage;
+use async_trait::async_trait;
+use axum::body::{Body, Bytes};
+use serde::Deserialize;
+
+/*
+ *文件存储(本地文件系统、S3兼容的对象存储)
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+#[async_trait]
+pub trait Storage: Send + Sync {
+    // 存储类型(local,s3)
+    fn kind(&self) -> &'static str;
+
+    // 保存文件
+    async fn put(&self, key: &str, data: Bytes, content_type: &str) -> AppResult<()>;
+
+    // 读取文件(range为闭区间的字节范围)
+    async fn get(&self, key: &str, range: Option<(u64, u64)>) -> AppResult<Body>;
+
+    // 删除文件(文件不存在时不报错)
+    async fn delete(&self, key: &str) -> AppResult<()>;
+}
+
+/*
+ *文件存储配置
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+#[derive(Clone, Debug, Deserialize)]
+pub struct StorageConfig {
+    #[serde(default = "default_kind")]
+    pub kind: String, //存储类型(local:本地文件系统,s3:S3兼容的对象存储)
+    #[serde(default = "default_local_dir")]
+    pub local_dir: String, //本地存储目录
+    #[serde(default = "default_max_size")]
+    pub max_size: usize, //单个文件最大大小(字节)
+    #[serde(default)]
+    pub allowed_types: Vec<String>, //允许上传的文件扩展名(为空时不限制)
+    #[serde(default)]
+    pub s3: S3Config, //S3配置
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        StorageConfig {
+            kind: default_kind(),
+            local_dir: default_local_dir(),
+            max_size: default_max_size(),
+            allowed_types: Vec::new(),
+            s3: S3Config::default(),
+        }
+    }
+}
+
+impl StorageConfig {
+    /*
+     *判断文件扩展名是否允许上传(不区分大小写)
+     *author：刘飞华
+     *date：2026/10/18 20:48:15
+     */
+    pub fn is_allowed_type(&self, ext: &str) -> bool {
+        self.allowed_types.is_empty() || self.allowed_types.iter().any(|x| x.eq_ignore_ascii_case(ext))
+    }
+}
+
+/*
+ *S3兼容的对象存储配置(如MinIO)
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct S3Config {
+    #[serde(default)]
+    pub endpoint: String, //服务地址(如 http://127.0.0.1:9000)
+    #[serde(default)]
+    pub region: String, //区域
+    #[serde(default)]
+    pub bucket: String, //存储桶
+    #[serde(default)]
+    pub access_key: String, //访问密钥
+    #[serde(default)]
+    pub secret_key: String, //私有访问密钥
+    #[serde(default)]
+    pub path_style: bool, //是否使用路径方式访问存储桶(MinIO需要开启)
+}
+
+fn default_kind() -> String {
+    "local".to_string()
+}
+
+fn default_local_dir() -> String {
+    "uploads/files".to_string()
+}
+
+fn default_max_size() -> usize {
+    10 * 1024 * 1024
+}
+
+/*
+ *根据配置创建文件存储
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+pub fn build_storage(config: &StorageConfig) -> Box<dyn Storage> {
+    match config.kind.as_str() {
+        "s3" => Box::new(S3Storage::new(config.s3.clone())),
+        "local" => Box::new(LocalStorage::new(&config.local_dir)),
+        x => {
+            log::error!("unknown storage kind: {}, use local storage", x);
+            Box::new(LocalStorage::new(&config.local_dir))
+        }
+    }
+}
+
+/*
+ *解析Range请求头(只支持单个范围),返回闭区间的字节范围
+ *None: 格式不正确或者范围不满足(应返回416)
+ *author：刘飞华
+ *date：2026/10/18 20:48:15
+ */
+pub fn parse_range(value: &str, size: u64) -> Option<(u64, u64)> {
+    let (start, end) = value.trim().strip_prefix("bytes=")?.split_once('-')?;
+    if size == 0 || start.contains(',') || end.contains(',') {
+        return None;
+    }
+
+    let (start, end) = match (start.trim(), end.trim()) {
+        // 最后N个字节: bytes=-500
+        ("", suffix) => {
+            let suffix = suffix.parse::<u64>().ok()?;
+            if suffix == 0 {
+                return None;
+            }
+            (size.saturating_sub(suffix), size - 1)
+        }
+        // 从start到结尾: bytes=500-
+        (start, "") => (start.parse::<u64>().ok()?, size - 1),
+        (start, end) => (start.parse::<u64>().ok()?, end.parse::<u64>().ok()?.min(size - 1)),
+    };
+
+    if start > end || start >= size {
+        return None;
+    }
+    Some((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::*;
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("bytes=0-9", 100), Some((0, 9)));
+        assert_eq!(parse_range("bytes=90-", 100), Some((90, 99)));
+        assert_eq!(parse_range("bytes=-10", 100), Some((90, 99)));
+        assert_eq!(parse_range("bytes=-200", 100), Some((0, 99)));
+        assert_eq!(parse_range("bytes=50-500", 100), Some((50, 99)));
+        assert_eq!(parse_range("bytes=100-", 100), None);
+        assert_eq!(parse_range("bytes=9-0", 100), None);
+        assert_eq!(parse_range("bytes=0-1,5-6", 100), None);
+        assert_eq!(parse_range("items=0-1", 100), None);
+        assert_eq!(parse_range("bytes=0-0", 0), None);
+    }
+
+    #[test]
+    fn test_is_allowed_type() {
+        let mut config = StorageConfig::default();
+        assert!(config.is_allowed_type("exe"));
+
+        config.allowed_types = vec!["png".to_string(), "pdf".to_string()];
+        assert!(config.is_allowed_type("PNG"));
+        assert!(!config.is_allowed_type("exe"));
+    }
+}
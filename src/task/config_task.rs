@@ -0,0 +1,14 @@
+use crate::task::scheduler::JobResult;
+use crate::utils::config_util::clear_config_cache;
+use crate::AppState;
+use std::sync::Arc;
+
+/*
+ *清空参数缓存,下次读取时从数据库重新加载
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+pub async fn refresh_cache(state: Arc<AppState>, _params: String) -> JobResult {
+    clear_config_cache(&state).map_err(|e| e.to_string())?;
+    Ok("参数缓存已刷新".to_string())
+}
@@ -0,0 +1,24 @@
+use crate::task::scheduler::JobResult;
+use crate::AppState;
+use log::info;
+use std::sync::Arc;
+
+/*
+ *示例任务(无参)
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+pub async fn no_params(_state: Arc<AppState>, _params: String) -> JobResult {
+    info!("execute demo task without params");
+    Ok("执行无参方法".to_string())
+}
+
+/*
+ *示例任务(有参)
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+pub async fn params(_state: Arc<AppState>, params: String) -> JobResult {
+    info!("execute demo task params: {}", params);
+    Ok(format!("执行有参方法: {}", params))
+}
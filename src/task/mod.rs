@@ -0,0 +1,20 @@
+pub mod config_task;
+pub mod demo_task;
+pub mod log_task;
+pub mod notice_task;
+pub mod scheduler;
+
+use crate::task::scheduler::JobScheduler;
+
+/*
+ *构建定时任务调度器,在这里注册可以在sys_job中配置的任务
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+pub fn build_job_scheduler() -> JobScheduler {
+    JobScheduler::new()
+        .register("demoTask.noParams", |state, params| Box::pin(demo_task::no_params(state, params)))
+        .register("demoTask.params", |state, params| Box::pin(demo_task::params(state, params)))
+        .register("configTask.refreshCache", |state, params| Box::pin(config_task::refresh_cache(state, params)))
+        .register("logTask.cleanExpiredLog", |state, params| Box::pin(log_task::clean_expired_log(state, params)))
+}
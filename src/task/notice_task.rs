@@ -0,0 +1,66 @@
+use crate::handler::system::sys_notice_handler::push_notice;
+use crate::model::system::sys_notice_model::{close_expired_notice, publish_scheduled_notice, select_due_notice_ids};
+use crate::AppState;
+use log::info;
+use std::sync::Arc;
+use std::time::Duration;
+
+// 定时发布和过期检查的执行间隔(秒)
+const NOTICE_TASK_INTERVAL: u64 = 30;
+
+/*
+ *启动通知公告定时任务(发布到期的定时通知公告,关闭已过期的通知公告)
+ *author：刘飞华
+ *date：2026/10/18 16:05:42
+ */
+pub fn start_notice_task(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(NOTICE_TASK_INTERVAL));
+        loop {
+            interval.tick().await;
+            publish_due_notice(&state).await;
+            close_notice(&state).await;
+        }
+    });
+}
+
+/*
+ *发布到期的定时通知公告,并推送给接收用户
+ *author：刘飞华
+ *date：2026/10/18 16:05:42
+ */
+async fn publish_due_notice(state: &AppState) {
+    let rb = &state.batis;
+
+    let ids = match select_due_notice_ids(rb).await {
+        Ok(ids) => ids,
+        Err(e) => {
+            log::error!("select due notice error: {:?}", e);
+            return;
+        }
+    };
+
+    for id in ids {
+        match publish_scheduled_notice(rb, id).await {
+            Ok(true) => {
+                info!("publish scheduled notice id: {}", id);
+                push_notice(state, id).await;
+            }
+            Ok(false) => {}
+            Err(e) => log::error!("publish scheduled notice error id: {}, error message: {:?}", id, e),
+        }
+    }
+}
+
+/*
+ *关闭已过期的通知公告
+ *author：刘飞华
+ *date：2026/10/18 16:05:42
+ */
+async fn close_notice(state: &AppState) {
+    match close_expired_notice(&state.batis).await {
+        Ok(0) => {}
+        Ok(n) => info!("close expired notice count: {}", n),
+        Err(e) => log::error!("close expired notice error: {:?}", e),
+    }
+}
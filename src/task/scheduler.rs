@@ -0,0 +1,380 @@
+use crate::model::system::sys_job_log_model::JobLog;
+use crate::model::system::sys_job_model::Job;
+use crate::AppState;
+use chrono::{Local, TimeZone};
+use cron::Schedule;
+use futures::future::BoxFuture;
+use log::info;
+use rbatis::rbdc::DateTime;
+use redis::Commands;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+// 任务执行结果(Ok:日志信息, Err:异常信息)
+pub type JobResult = Result<String, String>;
+
+// 任务处理函数(参数为任务参数job_params)
+pub type JobHandler = fn(Arc<AppState>, String) -> BoxFuture<'static, JobResult>;
+
+// 计划执行错误策略（1:立即执行,2:执行一次,3:放弃执行）
+pub const MISFIRE_FIRE_ALL: i8 = 1;
+pub const MISFIRE_FIRE_ONCE: i8 = 2;
+pub const MISFIRE_IGNORE: i8 = 3;
+
+// 触发方式（1:定时触发,2:手动执行,3:错过补偿）
+const TRIGGER_SCHEDULED: i8 = 1;
+const TRIGGER_MANUAL: i8 = 2;
+const TRIGGER_MISFIRE: i8 = 3;
+
+// 立即执行策略最多补偿的次数
+const MAX_MISFIRE_RUNS: usize = 10;
+
+// 同步数据库中任务状态的间隔(秒),用于多实例部署时感知其他实例的修改
+const SYNC_INTERVAL: u64 = 60;
+
+/*
+ *定时任务调度器
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+pub struct JobScheduler {
+    handlers: HashMap<&'static str, JobHandler>, //已注册的任务(调用目标 -> 处理函数)
+    tasks: Mutex<HashMap<i64, ScheduledTask>>,   //正在调度的任务(任务ID -> 调度信息)
+}
+
+struct ScheduledTask {
+    cron_expression: String,
+    handle: JoinHandle<()>,
+}
+
+impl Default for JobScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JobScheduler {
+    pub fn new() -> Self {
+        JobScheduler {
+            handlers: HashMap::new(),
+            tasks: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /*
+     *注册任务,调用目标为sys_job表的invoke_target
+     *author：刘飞华
+     *date：2026/10/18 16:40:18
+     */
+    pub fn register(mut self, invoke_target: &'static str, handler: JobHandler) -> Self {
+        self.handlers.insert(invoke_target, handler);
+        self
+    }
+
+    /*
+     *判断调用目标是否已注册
+     *author：刘飞华
+     *date：2026/10/18 16:40:18
+     */
+    pub fn has_handler(&self, invoke_target: &str) -> bool {
+        self.handlers.contains_key(invoke_target)
+    }
+
+    /*
+     *查询已注册的调用目标
+     *author：刘飞华
+     *date：2026/10/18 16:40:18
+     */
+    pub fn handler_names(&self) -> Vec<String> {
+        let mut names = self.handlers.keys().map(|x| x.to_string()).collect::<Vec<String>>();
+        names.sort();
+        names
+    }
+
+    /*
+     *启动调度器,加载状态正常的任务,并定时与数据库同步
+     *author：刘飞华
+     *date：2026/10/18 16:40:18
+     */
+    pub fn start(&self, state: Arc<AppState>) {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(SYNC_INTERVAL));
+            loop {
+                interval.tick().await;
+                state.scheduler.sync_jobs(&state).await;
+            }
+        });
+    }
+
+    /*
+     *与数据库同步任务:启动新增或恢复的任务,重启修改了cron表达式的任务,停止暂停或删除的任务
+     *author：刘飞华
+     *date：2026/10/18 16:40:18
+     */
+    async fn sync_jobs(&self, state: &Arc<AppState>) {
+        let jobs = match Job::select_enabled_job(&state.batis).await {
+            Ok(x) => x,
+            Err(e) => {
+                log::error!("sync job error: {:?}", e);
+                return;
+            }
+        };
+
+        let ids = jobs.iter().filter_map(|x| x.id).collect::<Vec<i64>>();
+        let stopped = {
+            let tasks = self.tasks.lock().unwrap();
+            tasks.keys().filter(|x| !ids.contains(x)).copied().collect::<Vec<i64>>()
+        };
+        for id in stopped {
+            self.unschedule_job(id);
+        }
+
+        for job in jobs {
+            let changed = {
+                let tasks = self.tasks.lock().unwrap();
+                match tasks.get(&job.id.unwrap_or_default()) {
+                    None => true,
+                    Some(x) => x.handle.is_finished() || x.cron_expression != job.cron_expression,
+                }
+            };
+            if changed {
+                self.schedule_job(state, job);
+            }
+        }
+    }
+
+    /*
+     *开始调度任务(已在调度的任务会先停止),启动时按计划执行错误策略处理错过的执行
+     *author：刘飞华
+     *date：2026/10/18 16:40:18
+     */
+    pub fn schedule_job(&self, state: &Arc<AppState>, job: Job) {
+        let id = job.id.unwrap_or_default();
+        self.unschedule_job(id);
+
+        let schedule = match Schedule::from_str(&job.cron_expression) {
+            Ok(x) => x,
+            Err(e) => {
+                log::error!("schedule job error id: {}, cron: {}, error message: {:?}", id, job.cron_expression, e);
+                return;
+            }
+        };
+
+        info!("schedule job id: {}, name: {}, cron: {}", id, job.job_name, job.cron_expression);
+        let cron_expression = job.cron_expression.clone();
+        let handle = tokio::spawn(run_schedule(Arc::clone(state), job, schedule));
+        self.tasks.lock().unwrap().insert(id, ScheduledTask { cron_expression, handle });
+    }
+
+    /*
+     *停止调度任务(不影响正在执行中的任务)
+     *author：刘飞华
+     *date：2026/10/18 16:40:18
+     */
+    pub fn unschedule_job(&self, id: i64) {
+        if let Some(x) = self.tasks.lock().unwrap().remove(&id) {
+            info!("unschedule job id: {}", id);
+            x.handle.abort();
+        }
+    }
+
+    /*
+     *立即执行一次任务
+     *author：刘飞华
+     *date：2026/10/18 16:40:18
+     */
+    pub fn run_job(&self, state: &Arc<AppState>, job: Job) {
+        tokio::spawn(execute_job(Arc::clone(state), job, TRIGGER_MANUAL));
+    }
+}
+
+/*
+ *校验cron表达式
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+pub fn is_valid_cron(cron_expression: &str) -> bool {
+    Schedule::from_str(cron_expression).is_ok()
+}
+
+/*
+ *计算下次执行时间
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+pub fn next_valid_time(cron_expression: &str) -> String {
+    Schedule::from_str(cron_expression)
+        .ok()
+        .and_then(|x| x.upcoming(Local).next())
+        .map(|x| x.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+/*
+ *按cron表达式循环调度任务
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+async fn run_schedule(state: Arc<AppState>, job: Job, schedule: Schedule) {
+    let id = job.id.unwrap_or_default();
+    handle_misfire(&state, &job, &schedule).await;
+
+    while let Some(next) = schedule.upcoming(Local).next() {
+        let wait = (next - Local::now()).to_std().unwrap_or_default();
+        tokio::time::sleep(wait).await;
+
+        // 执行前重新查询任务,保证使用最新的任务参数
+        let job = match Job::select_by_id(&state.batis, &id).await {
+            Ok(Some(x)) if x.status == 1 => x,
+            Ok(_) => return,
+            Err(e) => {
+                log::error!("query job error id: {}, error message: {:?}", id, e);
+                continue;
+            }
+        };
+
+        // 多实例部署时同一次触发只由一个实例执行
+        if !acquire_lock(&state, &format!("axum:admin:job:fire:{}:{}", id, next.timestamp()), 300) {
+            continue;
+        }
+        tokio::spawn(execute_job(Arc::clone(&state), job, TRIGGER_SCHEDULED));
+    }
+}
+
+/*
+ *按计划执行错误策略处理错过的执行(服务停止或任务暂停期间)
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+async fn handle_misfire(state: &Arc<AppState>, job: &Job, schedule: &Schedule) {
+    if job.misfire_policy != MISFIRE_FIRE_ALL && job.misfire_policy != MISFIRE_FIRE_ONCE {
+        return;
+    }
+    let id = job.id.unwrap_or_default();
+
+    let last_log = match JobLog::select_last_scheduled_log(&state.batis, id).await {
+        Ok(x) => x,
+        Err(e) => {
+            log::error!("query last job log error id: {}, error message: {:?}", id, e);
+            return;
+        }
+    };
+    let last_time = match last_log.and_then(|x| x.start_time).or(job.create_time.clone()) {
+        Some(x) => x.unix_timestamp(),
+        None => return,
+    };
+    let last = match Local.timestamp_opt(last_time, 0).single() {
+        Some(x) => x,
+        None => return,
+    };
+
+    let now = Local::now();
+    let missed = schedule.after(&last).take_while(|x| *x <= now).take(MAX_MISFIRE_RUNS).count();
+    if missed == 0 {
+        return;
+    }
+
+    // 多实例部署时只由一个实例补偿
+    if !acquire_lock(state, &format!("axum:admin:job:misfire:{}:{}", id, last_time), 300) {
+        return;
+    }
+
+    let times = if job.misfire_policy == MISFIRE_FIRE_ALL { missed } else { 1 };
+    info!("job id: {} missed {} times, misfire policy: {}, run {} times", id, missed, job.misfire_policy, times);
+    for _ in 0..times {
+        execute_job(Arc::clone(state), job.clone(), TRIGGER_MISFIRE).await;
+    }
+}
+
+/*
+ *执行任务并记录执行日志
+ *author：刘飞华
+ *date：2026/10/18 16:40:18
+ */
+async fn execute_job(state: Arc<AppState>, job: Job, trigger_type: i8) {
+    let id = job.id.unwrap_or_default();
+
+    // 禁止并发时,上次执行未结束则跳过本次执行
+    let running_key = format!("axum:admin:job:running:{}", id);
+    if job.concurrent == 1 && !acquire_lock(&state, &running_key, 86400) {
+        log::warn!("job id: {} is running, skip this execution", id);
+        return;
+    }
+
+    let start_time = DateTime::now();
+    let instant = Instant::now();
+
+    let result = match state.scheduler.handlers.get(job.invoke_target.as_str()) {
+        None => Err(format!("调用目标未注册: {}", job.invoke_target)),
+        // 在单独的任务中执行,任务panic时也能记录日志
+        Some(handler) => match tokio::spawn(handler(Arc::clone(&state), job.job_params.clone())).await {
+            Ok(x) => x,
+            Err(e) => Err(format!("任务执行异常: {}", e)),
+        },
+    };
+
+    if job.concurrent == 1 {
+        release_lock(&state, &running_key);
+    }
+
+    let (status, job_message, error_msg) = match result {
+        Ok(x) => (1, x, String::new()),
+        Err(e) => (0, "执行失败".to_string(), e),
+    };
+    let job_log = JobLog {
+        id: None,                                       //任务日志ID
+        job_id: id,                                     //任务ID
+        job_name: job.job_name,                         //任务名称
+        job_group: job.job_group,                       //任务组名
+        invoke_target: job.invoke_target,               //调用目标
+        job_params: job.job_params,                     //任务参数
+        trigger_type,                                   //触发方式（1:定时触发,2:手动执行,3:错过补偿）
+        job_message,                                    //日志信息
+        status,                                         //执行状态（0:失败,1:成功）
+        error_msg,                                      //异常信息
+        start_time: Some(start_time),                   //开始时间
+        end_time: Some(DateTime::now()),                //结束时间
+        duration: instant.elapsed().as_millis() as i64, //执行耗时(毫秒)
+    };
+
+    match JobLog::insert(&state.batis, &job_log).await {
+        Ok(_) => info!("execute job success: {:?}", job_log),
+        Err(e) => log::error!("add job log error params: {:?}, error message: {:?}", job_log, e),
+    }
+}
+
+fn acquire_lock(state: &AppState, key: &str, seconds: u64) -> bool {
+    let result = state
+        .redis
+        .get_connection()
+        .and_then(|mut conn| redis::cmd("SET").arg(key).arg(1).arg("NX").arg("EX").arg(seconds).query::<Option<String>>(&mut conn));
+    match result {
+        Ok(x) => x.is_some(),
+        Err(e) => {
+            log::error!("acquire job lock error key: {}, error message: {:?}", key, e);
+            false
+        }
+    }
+}
+
+fn release_lock(state: &AppState, key: &str) {
+    if let Err(e) = state.redis.get_connection().and_then(|mut conn| conn.del::<_, ()>(key)) {
+        log::error!("release job lock error key: {}, error message: {:?}", key, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::task::scheduler::*;
+
+    #[test]
+    fn test_is_valid_cron() {
+        assert!(is_valid_cron("0/10 * * * * ?"));
+        assert!(is_valid_cron("0 0 2 * * ?"));
+        assert!(!is_valid_cron("* * *"));
+        assert!(next_valid_time("bad").is_empty());
+    }
+}
@@ -0,0 +1,145 @@
+use crate::handler::system::sys_user_handler::query_btn_menu;
+use crate::i18n::t;
+use crate::model::system::sys_api_key_model::{update_last_used, ApiKey};
+use crate::model::system::sys_user_model::User;
+use crate::AppState;
+use rbatis::rbdc::DateTime;
+use sha2::{Digest, Sha256};
+
+// API密钥前缀(通过Authorization: Bearer传递时用于区分jwt)
+pub const API_KEY_PREFIX: &str = "fak_";
+// API密钥请求头
+pub const API_KEY_HEADER: &str = "x-api-key";
+// 列表中展示的密钥前缀长度
+const KEY_PREFIX_LENGTH: usize = 12;
+// 最后使用时间的记录间隔(秒),同一IP在间隔内重复使用时不更新
+const LAST_USED_INTERVAL: i64 = 60;
+
+/*
+ *API密钥验证通过后的访问信息
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+#[derive(Debug)]
+pub struct ApiKeyAccess {
+    pub user_id: i64,             //所属用户id
+    pub permissions: Vec<String>, //可以访问的接口
+    pub is_admin: bool,           //是否可以访问全部接口
+    pub restricted: bool,         //是否限制了可以访问的接口(限制后只能访问明确授权的接口)
+}
+
+/*
+ *生成API密钥,返回(密钥,展示用的前缀,摘要)
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+pub fn generate_api_key() -> (String, String, String) {
+    let api_key = format!("{}{:032x}{:032x}", API_KEY_PREFIX, rand::random::<u128>(), rand::random::<u128>());
+    let key_prefix = api_key.chars().take(KEY_PREFIX_LENGTH).collect();
+    let key_hash = hash_api_key(&api_key);
+    (api_key, key_prefix, key_hash)
+}
+
+/*
+ *计算API密钥摘要(数据库只保存摘要)
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+pub fn hash_api_key(api_key: &str) -> String {
+    hex::encode(Sha256::digest(api_key.as_bytes()))
+}
+
+/*
+ *计算API密钥的有效权限
+ *密钥没有限制接口时与所属用户的权限相同,否则取密钥接口与用户权限的交集(用户权限变化后立即生效)
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+pub fn effective_permissions(owner_permissions: Vec<String>, owner_is_admin: bool, key_permissions: &[String]) -> (Vec<String>, bool) {
+    if key_permissions.is_empty() {
+        return (owner_permissions, owner_is_admin);
+    }
+    let permissions = key_permissions.iter().filter(|x| owner_is_admin || owner_permissions.contains(x)).cloned().collect();
+    (permissions, false)
+}
+
+/*
+ *验证API密钥(状态、过期时间、所属用户状态),并记录最后使用时间和IP
+ *author：刘飞华
+ *date：2026/10/19 01:20:42
+ */
+pub async fn verify_api_key(state: &AppState, api_key: &str, ip: &str) -> Result<ApiKeyAccess, String> {
+    let rb = &state.batis;
+    let item = match ApiKey::select_by_key_hash(rb, &hash_api_key(api_key)).await {
+        Ok(Some(x)) => x,
+        Ok(None) => return Err(t("api_key.invalid")),
+        Err(e) => {
+            log::error!("query api key error: {}", e);
+            return Err(t("api_key.verify_failed"));
+        }
+    };
+
+    if item.status != 1 {
+        return Err(t("api_key.revoked"));
+    }
+    let now = DateTime::now();
+    if item.expire_time.as_ref().is_some_and(|x| x.unix_timestamp() <= now.unix_timestamp()) {
+        return Err(t("api_key.expired"));
+    }
+
+    match User::select_by_id(rb, item.user_id).await {
+        Ok(Some(user)) if user.status == 1 => {}
+        Ok(_) => return Err(t("api_key.user_disabled")),
+        Err(e) => {
+            log::error!("query api key user error: {}", e);
+            return Err(t("api_key.verify_failed"));
+        }
+    }
+
+    let id = item.id.unwrap_or_default();
+    let recently_used = item.last_used_time.as_ref().is_some_and(|x| now.unix_timestamp() - x.unix_timestamp() < LAST_USED_INTERVAL);
+    if !recently_used || item.last_used_ip != ip {
+        if let Err(e) = update_last_used(rb, id, ip).await {
+            log::error!("update api key last used error: {}", e);
+        }
+    }
+
+    let (owner_permissions, owner_is_admin) = query_btn_menu(&item.user_id, rb.clone()).await;
+    let key_permissions = item.permission_list();
+    let (permissions, is_admin) = effective_permissions(owner_permissions, owner_is_admin, &key_permissions);
+    log::info!("api key access key_id: {}, user_id: {}", id, item.user_id);
+    Ok(ApiKeyAccess {
+        user_id: item.user_id,
+        permissions,
+        is_admin,
+        restricted: !key_permissions.is_empty(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::api_key_util::*;
+
+    #[test]
+    fn test_generate_api_key() {
+        let (api_key, key_prefix, key_hash) = generate_api_key();
+        assert!(api_key.starts_with(API_KEY_PREFIX));
+        assert_eq!(api_key.len(), API_KEY_PREFIX.len() + 64);
+        assert!(api_key.starts_with(&key_prefix));
+        assert_eq!(key_hash, hash_api_key(&api_key));
+        assert_eq!(key_hash.len(), 64);
+        assert_ne!(generate_api_key().0, api_key);
+    }
+
+    #[test]
+    fn test_effective_permissions() {
+        let owner = vec!["/api/system/user/queryUserList".to_string(), "/api/system/role/queryRoleList".to_string()];
+
+        assert_eq!(effective_permissions(owner.clone(), false, &[]), (owner.clone(), false));
+        assert_eq!(effective_permissions(owner.clone(), true, &[]), (owner.clone(), true));
+
+        let key = vec!["/api/system/user/queryUserList".to_string(), "/api/system/user/deleteUser".to_string()];
+        assert_eq!(effective_permissions(owner.clone(), false, &key), (vec!["/api/system/user/queryUserList".to_string()], false));
+        assert_eq!(effective_permissions(owner, true, &key), (key, false));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::common::error::AppResult;
+use crate::model::system::sys_config_model::Config;
+use crate::AppState;
+use redis::Commands;
+use serde::de::DeserializeOwned;
+
+// 参数缓存(redis hash, field为参数键名, value为参数键值)
+const CONFIG_CACHE_KEY: &str = "axum:admin:config";
+
+// 内置参数键名
+pub const USER_INIT_PASSWORD: &str = "sys.user.initPassword";
+pub const ACCOUNT_CAPTCHA_ENABLED: &str = "sys.account.captchaEnabled";
+pub const ACCOUNT_REGISTER_USER: &str = "sys.account.registerUser";
+pub const LOG_LOGIN_RETENTION_DAYS: &str = "sys.log.loginLogRetentionDays";
+pub const LOG_LOGIN_MAX_ROWS: &str = "sys.log.loginLogMaxRows";
+pub const LOG_OPERATE_RETENTION_DAYS: &str = "sys.log.operateLogRetentionDays";
+pub const LOG_OPERATE_MAX_ROWS: &str = "sys.log.operateLogMaxRows";
+pub const LOG_ARCHIVE_ENABLED: &str = "sys.log.archiveEnabled";
+pub const LOG_ARCHIVE_DIR: &str = "sys.log.archiveDir";
+pub const LOGIN_RISK_ENABLED: &str = "sys.login.riskEnabled";
+pub const LOGIN_ALLOWED_HOURS: &str = "sys.login.allowedHours";
+pub const LOGIN_TRAVEL_MINUTES: &str = "sys.login.travelMinutes";
+pub const LOGIN_VERIFY_RISK_LEVEL: &str = "sys.login.verifyRiskLevel";
+pub const LOGIN_ACCOUNT_TYPES: &str = "sys.login.accountTypes";
+pub const PASSWORD_MIN_LENGTH: &str = "sys.password.minLength";
+pub const PASSWORD_CHAR_TYPES: &str = "sys.password.charTypes";
+pub const PASSWORD_HISTORY_COUNT: &str = "sys.password.historyCount";
+pub const PASSWORD_MAX_AGE_DAYS: &str = "sys.password.maxAgeDays";
+
+// 参数值类型（1:字符串,2:数字,3:布尔,4:JSON）
+pub const VALUE_TYPE_STRING: i8 = 1;
+pub const VALUE_TYPE_NUMBER: i8 = 2;
+pub const VALUE_TYPE_BOOL: i8 = 3;
+pub const VALUE_TYPE_JSON: i8 = 4;
+
+/*
+ *根据参数键名查询参数值(优先读取缓存)
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+pub async fn get_config_value(state: &AppState, key: &str) -> AppResult<Option<String>> {
+    let mut conn = state.redis.get_connection()?;
+
+    let cached: Option<String> = conn.hget(CONFIG_CACHE_KEY, key)?;
+    if cached.is_some() {
+        return Ok(cached);
+    }
+
+    match Config::select_by_config_key(&state.batis, key).await? {
+        None => Ok(None),
+        Some(x) => {
+            conn.hset::<_, _, _, ()>(CONFIG_CACHE_KEY, key, &x.config_value)?;
+            Ok(Some(x.config_value))
+        }
+    }
+}
+
+/*
+ *查询字符串参数,不存在或查询失败时返回默认值
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+pub async fn get_config_str(state: &AppState, key: &str, default: &str) -> String {
+    match get_config_value(state, key).await {
+        Ok(Some(x)) => x,
+        Ok(None) => default.to_string(),
+        Err(e) => {
+            log::error!("get config {} error: {:?}", key, e);
+            default.to_string()
+        }
+    }
+}
+
+/*
+ *查询布尔参数,不存在或格式不正确时返回默认值
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+pub async fn get_config_bool(state: &AppState, key: &str, default: bool) -> bool {
+    let value = get_config_str(state, key, "").await;
+    parse_bool(&value).unwrap_or(default)
+}
+
+/*
+ *查询数字参数,不存在或格式不正确时返回默认值
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+pub async fn get_config_i64(state: &AppState, key: &str, default: i64) -> i64 {
+    let value = get_config_str(state, key, "").await;
+    value.trim().parse::<i64>().unwrap_or(default)
+}
+
+/*
+ *查询JSON参数,不存在或格式不正确时返回默认值
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+pub async fn get_config_json<T: DeserializeOwned>(state: &AppState, key: &str, default: T) -> T {
+    let value = get_config_str(state, key, "").await;
+    serde_json::from_str(&value).unwrap_or(default)
+}
+
+/*
+ *删除参数缓存
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+pub fn remove_config_cache(state: &AppState, keys: &[String]) -> AppResult<()> {
+    if keys.is_empty() {
+        return Ok(());
+    }
+    let mut conn = state.redis.get_connection()?;
+    conn.hdel::<_, _, ()>(CONFIG_CACHE_KEY, keys)?;
+    Ok(())
+}
+
+/*
+ *清空参数缓存
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+pub fn clear_config_cache(state: &AppState) -> AppResult<()> {
+    let mut conn = state.redis.get_connection()?;
+    conn.del::<_, ()>(CONFIG_CACHE_KEY)?;
+    Ok(())
+}
+
+/*
+ *解析布尔参数值(true/false, 1/0, Y/N, yes/no)
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+pub fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "1" | "y" | "yes" => Some(true),
+        "false" | "0" | "n" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/*
+ *校验参数值是否与参数值类型匹配
+ *author：刘飞华
+ *date：2026/10/18 10:12:36
+ */
+pub fn is_valid_config_value(value_type: i8, value: &str) -> bool {
+    match value_type {
+        VALUE_TYPE_STRING => true,
+        VALUE_TYPE_NUMBER => value.trim().parse::<f64>().is_ok(),
+        VALUE_TYPE_BOOL => parse_bool(value).is_some(),
+        VALUE_TYPE_JSON => serde_json::from_str::<serde_json::Value>(value).is_ok(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::config_util::*;
+
+    #[test]
+    fn test_parse_bool() {
+        assert_eq!(parse_bool(" TRUE "), Some(true));
+        assert_eq!(parse_bool("Y"), Some(true));
+        assert_eq!(parse_bool("0"), Some(false));
+        assert_eq!(parse_bool("off"), None);
+    }
+
+    #[test]
+    fn test_is_valid_config_value() {
+        assert!(is_valid_config_value(VALUE_TYPE_STRING, "abc"));
+        assert!(is_valid_config_value(VALUE_TYPE_NUMBER, "12.5"));
+        assert!(!is_valid_config_value(VALUE_TYPE_NUMBER, "abc"));
+        assert!(is_valid_config_value(VALUE_TYPE_BOOL, "false"));
+        assert!(is_valid_config_value(VALUE_TYPE_JSON, r#"{"a": [1, 2]}"#));
+        assert!(!is_valid_config_value(VALUE_TYPE_JSON, "{a:1"));
+        assert!(!is_valid_config_value(9, "1"));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::utils::ip_util::is_internal_ip;
+use std::net::IpAddr;
+
+// ip2region xdb文件结构: 256字节的头部 + 256*256*8字节的向量索引 + 数据区 + 段索引(每个14字节)
+const HEADER_LENGTH: usize = 256;
+const VECTOR_INDEX_COLS: usize = 256;
+const VECTOR_INDEX_SIZE: usize = 8;
+const SEGMENT_INDEX_SIZE: usize = 14;
+
+/*
+ *离线IP地址库(ip2region xdb格式,将整个文件加载到内存中查询)
+ *author：刘飞华
+ *date：2026/10/18 17:58:12
+ */
+pub struct IpRegion {
+    content: Option<Vec<u8>>,
+}
+
+impl IpRegion {
+    /*
+     *加载xdb文件,文件不存在时只能识别内网IP
+     *author：刘飞华
+     *date：2026/10/18 17:58:12
+     */
+    pub fn load(path: &str) -> IpRegion {
+        if path.is_empty() {
+            return IpRegion { content: None };
+        }
+        match std::fs::read(path) {
+            Ok(content) if content.len() > HEADER_LENGTH + VECTOR_INDEX_COLS * VECTOR_INDEX_COLS * VECTOR_INDEX_SIZE => {
+                log::info!("load ip region db: {}", path);
+                IpRegion { content: Some(content) }
+            }
+            Ok(_) => {
+                log::error!("invalid ip region db: {}", path);
+                IpRegion { content: None }
+            }
+            Err(e) => {
+                log::warn!("ip region db {} not loaded: {:?}", path, e);
+                IpRegion { content: None }
+            }
+        }
+    }
+
+    pub fn from_bytes(content: Vec<u8>) -> IpRegion {
+        IpRegion { content: Some(content) }
+    }
+
+    /*
+     *查询IP的原始区域信息(国家|区域|省份|城市|ISP)
+     *author：刘飞华
+     *date：2026/10/18 17:58:12
+     */
+    pub fn search(&self, ip: &IpAddr) -> Option<String> {
+        let content = self.content.as_ref()?;
+        let ip = match ip {
+            IpAddr::V4(x) => u32::from(*x),
+            IpAddr::V6(x) => u32::from(x.to_ipv4_mapped()?),
+        };
+
+        let il0 = (ip >> 24) as usize;
+        let il1 = ((ip >> 16) & 0xff) as usize;
+        let idx = HEADER_LENGTH + il0 * VECTOR_INDEX_COLS * VECTOR_INDEX_SIZE + il1 * VECTOR_INDEX_SIZE;
+        let s_ptr = read_u32(content, idx)? as usize;
+        let e_ptr = read_u32(content, idx + 4)? as usize;
+        if e_ptr < s_ptr {
+            return None;
+        }
+
+        // 二分查找段索引
+        let (mut low, mut high) = (0usize, (e_ptr - s_ptr) / SEGMENT_INDEX_SIZE);
+        while low <= high {
+            let mid = (low + high) / 2;
+            let p = s_ptr + mid * SEGMENT_INDEX_SIZE;
+            let start_ip = read_u32(content, p)?;
+            let end_ip = read_u32(content, p + 4)?;
+            if ip < start_ip {
+                if mid == 0 {
+                    return None;
+                }
+                high = mid - 1;
+            } else if ip > end_ip {
+                low = mid + 1;
+            } else {
+                let data_len = u16::from_le_bytes([*content.get(p + 8)?, *content.get(p + 9)?]) as usize;
+                let data_ptr = read_u32(content, p + 10)? as usize;
+                let data = content.get(data_ptr..data_ptr + data_len)?;
+                return String::from_utf8(data.to_vec()).ok();
+            }
+        }
+        None
+    }
+
+    /*
+     *查询IP所在地(如: 中国 广东省 深圳市),查询不到时返回未知
+     *author：刘飞华
+     *date：2026/10/18 17:58:12
+     */
+    pub fn get_location(&self, ip: &IpAddr) -> String {
+        if is_internal_ip(ip) {
+            return "内网IP".to_string();
+        }
+
+        match self.search(ip) {
+            None => "未知".to_string(),
+            Some(region) => {
+                let parts = region.split('|').collect::<Vec<&str>>();
+                // 只保留国家、省份、城市,去掉重复和为0的部分
+                let mut location: Vec<&str> = Vec::new();
+                for x in [parts.first(), parts.get(2), parts.get(3)].into_iter().flatten() {
+                    if !x.is_empty() && *x != "0" && !location.contains(x) {
+                        location.push(x);
+                    }
+                }
+                if location.is_empty() {
+                    "未知".to_string()
+                } else {
+                    location.join(" ")
+                }
+            }
+        }
+    }
+}
+
+fn read_u32(content: &[u8], offset: usize) -> Option<u32> {
+    let bytes = content.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::ip_region_util::*;
+
+    // 构建只包含 1.0.0.0-1.0.0.255 和 1.0.1.0-1.0.3.255 两个段的xdb数据
+    fn build_xdb() -> Vec<u8> {
+        let segments: [(u32, u32, &str); 2] = [(0x01000000, 0x010000ff, "中国|0|福建省|福州市|电信"), (0x01000100, 0x010003ff, "澳大利亚|0|0|0|0")];
+
+        let data_start = HEADER_LENGTH + VECTOR_INDEX_COLS * VECTOR_INDEX_COLS * VECTOR_INDEX_SIZE;
+        let mut content = vec![0u8; data_start];
+        let mut data_ptrs = Vec::new();
+        for (_, _, region) in segments.iter() {
+            data_ptrs.push(content.len() as u32);
+            content.extend_from_slice(region.as_bytes());
+        }
+
+        let index_start = content.len();
+        for (i, (start, end, region)) in segments.iter().enumerate() {
+            content.extend_from_slice(&start.to_le_bytes());
+            content.extend_from_slice(&end.to_le_bytes());
+            content.extend_from_slice(&(region.len() as u16).to_le_bytes());
+            content.extend_from_slice(&data_ptrs[i].to_le_bytes());
+        }
+
+        // 两个段都在 1.0.x.x 下
+        let idx = HEADER_LENGTH + VECTOR_INDEX_COLS * VECTOR_INDEX_SIZE;
+        content[idx..idx + 4].copy_from_slice(&(index_start as u32).to_le_bytes());
+        content[idx + 4..idx + 8].copy_from_slice(&((index_start + SEGMENT_INDEX_SIZE) as u32).to_le_bytes());
+        content
+    }
+
+    #[test]
+    fn test_search() {
+        let region = IpRegion::from_bytes(build_xdb());
+        assert_eq!(region.search(&"1.0.0.8".parse().unwrap()), Some("中国|0|福建省|福州市|电信".to_string()));
+        assert_eq!(region.get_location(&"1.0.0.8".parse().unwrap()), "中国 福建省 福州市");
+        assert_eq!(region.get_location(&"1.0.2.1".parse().unwrap()), "澳大利亚");
+        assert_eq!(region.get_location(&"1.0.8.1".parse().unwrap()), "未知");
+        assert_eq!(region.get_location(&"2.0.0.1".parse().unwrap()), "未知");
+        assert_eq!(region.get_location(&"192.168.0.1".parse().unwrap()), "内网IP");
+        assert_eq!(IpRegion::load("").get_location(&"8.8.8.8".parse().unwrap()), "未知");
+    }
+}
@@ -0,0 +1,178 @@
+use axum::http::HeaderMap;
+use std::net::IpAddr;
+
+/*
+ *IP网段(支持单个IP和CIDR格式,如 10.0.0.0/8、::1)
+ *author：刘飞华
+ *date：2026/10/18 17:58:12
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct IpNet {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl IpNet {
+    pub fn parse(value: &str) -> Option<IpNet> {
+        let value = value.trim();
+        let (addr, prefix) = match value.split_once('/') {
+            None => (value, None),
+            Some((addr, prefix)) => (addr, Some(prefix.parse::<u8>().ok()?)),
+        };
+
+        let addr = addr.parse::<IpAddr>().ok()?;
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix = prefix.unwrap_or(max);
+        if prefix > max {
+            return None;
+        }
+        Some(IpNet { addr, prefix })
+    }
+
+    /*
+     *判断IP是否在网段内
+     *author：刘飞华
+     *date：2026/10/18 17:58:12
+     */
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.addr, normalize_ip(*ip)) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+/*
+ *解析可信代理列表,忽略格式不正确的配置
+ *author：刘飞华
+ *date：2026/10/18 17:58:12
+ */
+pub fn parse_trusted_proxies(values: &[String]) -> Vec<IpNet> {
+    values
+        .iter()
+        .filter_map(|x| {
+            let net = IpNet::parse(x);
+            if net.is_none() {
+                log::warn!("invalid trusted proxy: {}", x);
+            }
+            net
+        })
+        .collect()
+}
+
+/*
+ *获取客户端真实IP
+ *只有请求来自可信代理时才使用X-Forwarded-For/X-Real-IP,否则使用连接的socket地址
+ *author：刘飞华
+ *date：2026/10/18 17:58:12
+ */
+pub fn get_client_ip(headers: &HeaderMap, remote_ip: IpAddr, trusted_proxies: &[IpNet]) -> IpAddr {
+    let remote_ip = normalize_ip(remote_ip);
+    let is_trusted = |ip: &IpAddr| trusted_proxies.iter().any(|x| x.contains(ip));
+
+    if !is_trusted(&remote_ip) {
+        return remote_ip;
+    }
+
+    // X-Forwarded-For: client, proxy1, proxy2 从右往左跳过可信代理,第一个不可信的地址就是客户端
+    let forwarded = headers
+        .get_all("X-Forwarded-For")
+        .iter()
+        .filter_map(|x| x.to_str().ok())
+        .flat_map(|x| x.split(','))
+        .filter_map(|x| x.trim().parse::<IpAddr>().ok())
+        .map(normalize_ip)
+        .collect::<Vec<IpAddr>>();
+
+    if let Some(ip) = forwarded.iter().rev().find(|x| !is_trusted(x)) {
+        return *ip;
+    }
+    if let Some(ip) = forwarded.first() {
+        return *ip;
+    }
+
+    headers
+        .get("X-Real-IP")
+        .and_then(|x| x.to_str().ok())
+        .and_then(|x| x.trim().parse::<IpAddr>().ok())
+        .map(normalize_ip)
+        .unwrap_or(remote_ip)
+}
+
+/*
+ *判断是否为内网IP
+ *author：刘飞华
+ *date：2026/10/18 17:58:12
+ */
+pub fn is_internal_ip(ip: &IpAddr) -> bool {
+    match normalize_ip(*ip) {
+        IpAddr::V4(x) => x.is_private() || x.is_loopback() || x.is_link_local() || x.is_unspecified(),
+        IpAddr::V6(x) => x.is_loopback() || x.is_unspecified() || (x.segments()[0] & 0xfe00) == 0xfc00 || (x.segments()[0] & 0xffc0) == 0xfe80,
+    }
+}
+
+// IPv4映射的IPv6地址(::ffff:1.2.3.4)转换为IPv4
+fn normalize_ip(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(x) => x.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(ip),
+        _ => ip,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::ip_util::*;
+
+    fn headers(values: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (k, v) in values {
+            headers.append(*k, v.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_ip_net() {
+        let net = IpNet::parse("10.0.0.0/8").unwrap();
+        assert!(net.contains(&"10.1.2.3".parse().unwrap()));
+        assert!(!net.contains(&"11.1.2.3".parse().unwrap()));
+        assert!(IpNet::parse("127.0.0.1").unwrap().contains(&"::ffff:127.0.0.1".parse().unwrap()));
+        assert!(IpNet::parse("fc00::/7").unwrap().contains(&"fd12::1".parse().unwrap()));
+        assert!(IpNet::parse("0.0.0.0/0").unwrap().contains(&"8.8.8.8".parse().unwrap()));
+        assert!(IpNet::parse("10.0.0.0/33").is_none());
+        assert!(IpNet::parse("abc").is_none());
+    }
+
+    #[test]
+    fn test_get_client_ip() {
+        let trusted = parse_trusted_proxies(&["127.0.0.1".to_string(), "10.0.0.0/8".to_string()]);
+        let remote = "127.0.0.1".parse().unwrap();
+
+        // 不可信的来源不使用请求头
+        let h = headers(&[("X-Forwarded-For", "1.1.1.1")]);
+        assert_eq!(get_client_ip(&h, "8.8.8.8".parse().unwrap(), &trusted), "8.8.8.8".parse::<IpAddr>().unwrap());
+
+        // 跳过可信代理,伪造的最左侧地址不会被使用
+        let h = headers(&[("X-Forwarded-For", "6.6.6.6, 2.2.2.2, 10.0.0.2")]);
+        assert_eq!(get_client_ip(&h, remote, &trusted), "2.2.2.2".parse::<IpAddr>().unwrap());
+
+        let h = headers(&[("X-Real-IP", "3.3.3.3")]);
+        assert_eq!(get_client_ip(&h, remote, &trusted), "3.3.3.3".parse::<IpAddr>().unwrap());
+
+        assert_eq!(get_client_ip(&HeaderMap::new(), remote, &trusted), remote);
+    }
+
+    #[test]
+    fn test_is_internal_ip() {
+        assert!(is_internal_ip(&"192.168.1.1".parse().unwrap()));
+        assert!(is_internal_ip(&"::1".parse().unwrap()));
+        assert!(!is_internal_ip(&"8.8.8.8".parse().unwrap()));
+    }
+}
@@ -1,9 +1,13 @@
 use crate::common::error::AppError;
 use crate::common::error::AppError::JwtTokenError;
+use crate::i18n::t;
 use jsonwebtoken::{decode, encode, errors::ErrorKind, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+// 受限token(需要修改密码),只能访问修改密码和退出登录接口
+pub const SCOPE_PWD_CHANGE: &str = "pwd_change";
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct JwtToken {
     pub id: i64,
@@ -20,6 +24,8 @@ pub struct JwtToken {
     sub: String,
     // (subject)：主题
     jti: String, // (JWT ID)：编号
+    #[serde(default)]
+    pub scope: String, // 权限范围(为空时不限制)
 }
 
 impl JwtToken {
@@ -39,15 +45,22 @@ impl JwtToken {
             nbf: now.as_secs() as usize,     // (Not Before)：生效时间
             sub: String::from("rust_admin"), // (subject)：主题
             jti: String::from("ignore"),     // (JWT ID)：编号
+            scope: String::new(),
         }
     }
 
+    /// set scope of the token
+    pub fn with_scope(mut self, scope: &str) -> JwtToken {
+        self.scope = scope.to_string();
+        self
+    }
+
     /// create token
     /// secret: your secret string
     pub fn create_token(&self, secret: &str) -> Result<String, AppError> {
         match encode(&Header::default(), self, &EncodingKey::from_secret(secret.as_ref())) {
             Ok(t) => Ok(t),
-            Err(_) => Err(JwtTokenError("create token error".to_string())),
+            Err(_) => Err(JwtTokenError(t("jwt.create_failed"))),
         }
     }
     /// verify token invalid
@@ -61,11 +74,11 @@ impl JwtToken {
             Ok(c) => Ok(c.claims),
 
             Err(err) => match *err.kind() {
-                ErrorKind::InvalidToken => return Err(JwtTokenError("InvalidToken".to_string())), // Example on how to handle a specific error
-                ErrorKind::InvalidIssuer => return Err(JwtTokenError("InvalidIssuer".to_string())), // Example on how to handle a specific error
-                ErrorKind::ExpiredSignature => return Err(JwtTokenError("token 已经超时了".to_string())), // Example on how to handle a specific error
+                ErrorKind::InvalidToken => return Err(JwtTokenError(t("jwt.invalid"))),         // Example on how to handle a specific error
+                ErrorKind::InvalidIssuer => return Err(JwtTokenError(t("jwt.invalid_issuer"))), // Example on how to handle a specific error
+                ErrorKind::ExpiredSignature => return Err(JwtTokenError(t("jwt.expired"))),     // Example on how to handle a specific error
                 // _ => return Err(Error::from("InvalidToken other errors")),
-                _ => Err(JwtTokenError("InvalidToken other errors".to_string())),
+                _ => Err(JwtTokenError(t("jwt.invalid"))),
             },
         }
     }
@@ -73,7 +86,7 @@ impl JwtToken {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::jwt_util::JwtToken;
+    use crate::utils::jwt_util::{JwtToken, SCOPE_PWD_CHANGE};
 
     #[test]
     fn test_jwt() {
@@ -83,4 +96,13 @@ mod tests {
         let token = JwtToken::verify("123", &res);
         println!("{:?}", token)
     }
+
+    #[test]
+    fn test_jwt_scope() {
+        let res = JwtToken::new(1, "koobe").create_token("123").unwrap();
+        assert_eq!(JwtToken::verify("123", &res).unwrap().scope, "");
+
+        let res = JwtToken::new(1, "koobe").with_scope(SCOPE_PWD_CHANGE).create_token("123").unwrap();
+        assert_eq!(JwtToken::verify("123", &res).unwrap().scope, SCOPE_PWD_CHANGE);
+    }
 }
@@ -0,0 +1,92 @@
+use crate::common::error::AppResult;
+use crate::utils::config_util::{get_config_bool, get_config_str, LOG_ARCHIVE_DIR, LOG_ARCHIVE_ENABLED};
+use crate::AppState;
+use chrono::Local;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use log::info;
+use rbs::{value, Value};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+
+// 每批处理的行数(避免一次删除过多数据长时间锁表)
+const BATCH_SIZE: u64 = 1000;
+
+/*
+ *按条件删除日志,开启归档时先将日志写入gzip压缩的JSONL文件
+ *author：刘飞华
+ *date：2026/10/18 17:20:33
+ */
+pub async fn purge_log<T>(state: &AppState, table: &str, where_sql: &str, params: Vec<Value>, id_of: fn(&T) -> Option<i64>) -> AppResult<u64>
+where
+    T: DeserializeOwned + Serialize,
+{
+    let rb = &state.batis;
+    let mut total = 0;
+
+    if !get_config_bool(state, LOG_ARCHIVE_ENABLED, false).await {
+        let sql = format!("delete from {} where {} limit {}", table, where_sql, BATCH_SIZE);
+        loop {
+            let rows = rb.exec(&sql, params.clone()).await?.rows_affected;
+            total += rows;
+            if rows < BATCH_SIZE {
+                break;
+            }
+        }
+        info!("purge {} count: {}", table, total);
+        return Ok(total);
+    }
+
+    let archive_dir = get_config_str(state, LOG_ARCHIVE_DIR, "logs/archive").await;
+    let mut writer: Option<(String, GzEncoder<File>)> = None;
+
+    let sql = format!("select * from {} where {} order by id limit {}", table, where_sql, BATCH_SIZE);
+    loop {
+        let list: Vec<T> = rb.query_decode(&sql, params.clone()).await?;
+        if list.is_empty() {
+            break;
+        }
+
+        if writer.is_none() {
+            writer = Some(create_archive_file(&archive_dir, table)?);
+        }
+        if let Some((_, encoder)) = writer.as_mut() {
+            for x in &list {
+                writeln!(encoder, "{}", serde_json::to_string(x).unwrap_or_default())?;
+            }
+            // 先落盘再删除,保证删除的日志都已归档
+            encoder.flush()?;
+        }
+
+        let ids = list.iter().filter_map(id_of).collect::<Vec<i64>>();
+        let delete_sql = format!("delete from {} where id in ({})", table, ids.iter().map(|_| "?").collect::<Vec<&str>>().join(", "));
+        rb.exec(&delete_sql, ids.iter().map(|&id| value!(id)).collect()).await?;
+
+        total += ids.len() as u64;
+        if (list.len() as u64) < BATCH_SIZE {
+            break;
+        }
+    }
+
+    if let Some((path, encoder)) = writer {
+        encoder.finish()?;
+        info!("archive {} count: {} to file: {}", table, total, path);
+    }
+    Ok(total)
+}
+
+/*
+ *创建归档文件(文件名:表名_时间.jsonl.gz)
+ *author：刘飞华
+ *date：2026/10/18 17:20:33
+ */
+fn create_archive_file(dir: &str, table: &str) -> AppResult<(String, GzEncoder<File>)> {
+    fs::create_dir_all(dir)?;
+    let file_name = format!("{}_{}.jsonl.gz", table, Local::now().format("%Y%m%d%H%M%S%3f"));
+    let path = Path::new(dir).join(file_name).to_string_lossy().to_string();
+    let file = File::create(&path)?;
+    Ok((path, GzEncoder::new(file, Compression::default())))
+}
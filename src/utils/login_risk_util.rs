@@ -0,0 +1,264 @@
+use crate::i18n::tr;
+use crate::model::system::sys_login_log_model::{select_recent_success_login_log, LoginLog};
+use crate::model::system::sys_user_model::User;
+use crate::utils::config_util::{get_config_bool, get_config_i64, get_config_str, LOGIN_ALLOWED_HOURS, LOGIN_RISK_ENABLED, LOGIN_TRAVEL_MINUTES};
+use crate::AppState;
+use chrono::{DateTime, Local, NaiveTime};
+
+// 风险等级
+pub const RISK_NONE: i8 = 0; //无风险
+pub const RISK_LOW: i8 = 1; //低风险
+pub const RISK_MEDIUM: i8 = 2; //中风险
+pub const RISK_HIGH: i8 = 3; //高风险
+
+// 参与比较的历史登录记录数量
+const HISTORY_SIZE: u64 = 50;
+
+// 无法识别地点时的登录地点(不参与地点比较)
+const UNKNOWN_LOCATIONS: [&str; 3] = ["", "内网IP", "未知"];
+
+/*
+ *登录风险
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+#[derive(Clone, Debug, Default)]
+pub struct LoginRisk {
+    pub level: i8,            //风险等级(0:无,1:低,2:中,3:高)
+    pub reasons: Vec<String>, //风险原因
+}
+
+impl LoginRisk {
+    fn add(&mut self, level: i8, reason: String) {
+        self.level = self.level.max(level);
+        self.reasons.push(reason);
+    }
+
+    pub fn is_risky(&self) -> bool {
+        self.level > RISK_NONE
+    }
+
+    pub fn reason(&self) -> String {
+        self.reasons.join("; ").chars().take(255).collect()
+    }
+}
+
+/*
+ *风险等级名称的语言包key
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+pub fn risk_level_key(level: i8) -> &'static str {
+    match level {
+        RISK_LOW => "risk.level_low",
+        RISK_MEDIUM => "risk.level_medium",
+        RISK_HIGH => "risk.level_high",
+        _ => "risk.level_none",
+    }
+}
+
+/*
+ *登录风险规则
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+#[derive(Clone, Debug, Default)]
+pub struct LoginRiskRule {
+    pub allowed_hours: Option<(NaiveTime, NaiveTime)>, //允许登录的时段(支持跨天,如22:00-06:00)
+    pub travel_minutes: i64,                           //两次登录的国家不同且间隔小于该分钟数时视为不可能的移动(0:不检测)
+}
+
+/*
+ *解析允许登录的时段(格式: 08:00-20:00),为空或格式不正确时不限制
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+pub fn parse_allowed_hours(value: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let (start, end) = value.trim().split_once('-')?;
+    let start = NaiveTime::parse_from_str(start.trim(), "%H:%M").ok()?;
+    let end = NaiveTime::parse_from_str(end.trim(), "%H:%M").ok()?;
+    Some((start, end))
+}
+
+/*
+ *检查登录风险(查询配置的规则和最近的成功登录记录)
+ *用户可以使用手机、账号或邮箱登录,历史记录按用户的全部登录标识查询
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+pub async fn check_login_risk(state: &AppState, current: &LoginLog, user: &User) -> LoginRisk {
+    if !get_config_bool(state, LOGIN_RISK_ENABLED, true).await {
+        return LoginRisk::default();
+    }
+
+    let rule = LoginRiskRule {
+        allowed_hours: parse_allowed_hours(&get_config_str(state, LOGIN_ALLOWED_HOURS, "").await),
+        travel_minutes: get_config_i64(state, LOGIN_TRAVEL_MINUTES, 120).await,
+    };
+
+    let login_names = [&user.mobile, &user.user_name, &user.email].into_iter().filter(|x| !x.is_empty()).cloned().collect::<Vec<String>>();
+    let history = match select_recent_success_login_log(&state.batis, &login_names, HISTORY_SIZE).await {
+        Ok(x) => x,
+        Err(e) => {
+            log::error!("query login history error login_name: {}, error message: {:?}", current.login_name, e);
+            Vec::new()
+        }
+    };
+
+    evaluate_login_risk(current, &history, &rule, Local::now())
+}
+
+/*
+ *根据历史登录记录评估本次登录的风险
+ *新设备、新城市、非允许时段为低风险,新国家为中风险,短时间内跨国登录为高风险
+ *ip2region不提供经纬度,不可能的移动按国家判断
+ *author：刘飞华
+ *date：2026/10/18 19:36:20
+ */
+pub fn evaluate_login_risk(current: &LoginLog, history: &[LoginLog], rule: &LoginRiskRule, now: DateTime<Local>) -> LoginRisk {
+    let mut risk = LoginRisk::default();
+
+    if let Some((start, end)) = rule.allowed_hours {
+        let time = now.time();
+        let allowed = if start <= end { time >= start && time <= end } else { time >= start || time <= end };
+        if !allowed {
+            risk.add(RISK_LOW, tr("risk.off_hours", &[("time", &now.format("%H:%M").to_string())]));
+        }
+    }
+
+    // 第一次登录没有可比较的记录
+    if history.is_empty() {
+        return risk;
+    }
+
+    if !history.iter().any(|x| device_key(x) == device_key(current)) {
+        let device = format!("{} {} {}", current.platform, current.os, current.browser);
+        risk.add(RISK_LOW, tr("risk.new_device", &[("device", &device)]));
+    }
+
+    if is_known_location(&current.login_location) {
+        let country = country_of(&current.login_location);
+        let known = history.iter().filter(|x| is_known_location(&x.login_location)).collect::<Vec<&LoginLog>>();
+
+        if !known.is_empty() {
+            if !known.iter().any(|x| country_of(&x.login_location) == country) {
+                risk.add(RISK_MEDIUM, tr("risk.new_country", &[("country", country)]));
+            } else if !known.iter().any(|x| x.login_location == current.login_location) {
+                risk.add(RISK_LOW, tr("risk.new_city", &[("city", &current.login_location)]));
+            }
+
+            // 历史记录按时间倒序,第一条就是上一次的登录
+            let last = known[0];
+            if let Some(last_time) = &last.login_time {
+                let minutes = (now.timestamp() - last_time.unix_timestamp()) / 60;
+                if rule.travel_minutes > 0 && minutes < rule.travel_minutes && country_of(&last.login_location) != country {
+                    risk.add(RISK_HIGH, tr("risk.travel", &[("minutes", &minutes.max(0).to_string()), ("location", &last.login_location)]));
+                }
+            }
+        }
+    }
+
+    risk
+}
+
+fn is_known_location(location: &str) -> bool {
+    !UNKNOWN_LOCATIONS.contains(&location.trim())
+}
+
+fn country_of(location: &str) -> &str {
+    location.split_whitespace().next().unwrap_or_default()
+}
+
+// 设备标识: 设备 + 操作系统(去掉版本号) + 浏览器,系统和浏览器升级不算新设备
+fn device_key(log: &LoginLog) -> (String, String, String) {
+    let os = log.os.split_whitespace().take_while(|x| !x.starts_with(|c: char| c.is_ascii_digit())).collect::<Vec<&str>>().join(" ");
+    (log.platform.clone(), os, log.browser.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::system::sys_login_log_model::LoginLog;
+    use crate::utils::login_risk_util::*;
+    use chrono::TimeZone;
+
+    fn login_log(location: &str, os: &str, minutes: i64) -> LoginLog {
+        LoginLog {
+            id: None,
+            login_name: "18613030111".to_string(),
+            ipaddr: "".to_string(),
+            login_location: location.to_string(),
+            platform: "PC".to_string(),
+            browser: "Chrome".to_string(),
+            version: "".to_string(),
+            os: os.to_string(),
+            arch: "".to_string(),
+            engine: "".to_string(),
+            engine_details: "".to_string(),
+            extra: "".to_string(),
+            client_type: "human".to_string(),
+            status: 1,
+            msg: "".to_string(),
+            risk_level: 0,
+            risk_reason: "".to_string(),
+            login_time: Some(rbatis::rbdc::DateTime::from_timestamp(now(minutes).timestamp())),
+        }
+    }
+
+    fn now(minutes: i64) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 10, 18, 0, 0, 0).unwrap() + chrono::Duration::minutes(minutes)
+    }
+
+    #[test]
+    fn test_evaluate_login_risk() {
+        let rule = LoginRiskRule {
+            allowed_hours: None,
+            travel_minutes: 120,
+        };
+        let history = vec![login_log("中国 福建省 福州市", "Windows 10", 600)];
+
+        // 第一次登录
+        assert_eq!(evaluate_login_risk(&login_log("美国", "Linux", 0), &[], &rule, now(660)).level, RISK_NONE);
+
+        // 同一设备同一城市, 系统升级不算新设备
+        assert_eq!(evaluate_login_risk(&login_log("中国 福建省 福州市", "Windows 11", 0), &history, &rule, now(660)).level, RISK_NONE);
+
+        // 新设备
+        let risk = evaluate_login_risk(&login_log("中国 福建省 福州市", "Mac OS X 10.15.7", 0), &history, &rule, now(660));
+        assert_eq!((risk.level, risk.reasons.len()), (RISK_LOW, 1));
+
+        // 新城市
+        assert_eq!(evaluate_login_risk(&login_log("中国 广东省 深圳市", "Windows 10", 0), &history, &rule, now(660)).level, RISK_LOW);
+
+        // 新国家, 但间隔足够长
+        assert_eq!(evaluate_login_risk(&login_log("美国", "Windows 10", 0), &history, &rule, now(900)).level, RISK_MEDIUM);
+
+        // 短时间内跨国登录
+        let risk = evaluate_login_risk(&login_log("美国", "Windows 10", 0), &history, &rule, now(630));
+        assert_eq!((risk.level, risk.reasons.len()), (RISK_HIGH, 2));
+
+        // 内网IP不参与地点比较
+        assert_eq!(evaluate_login_risk(&login_log("内网IP", "Windows 10", 0), &history, &rule, now(630)).level, RISK_NONE);
+    }
+
+    #[test]
+    fn test_allowed_hours() {
+        let rule = LoginRiskRule {
+            allowed_hours: parse_allowed_hours("08:00-20:00"),
+            travel_minutes: 0,
+        };
+        let current = login_log("内网IP", "Windows 10", 0);
+        assert_eq!(evaluate_login_risk(&current, &[], &rule, now(9 * 60)).level, RISK_NONE);
+        assert_eq!(evaluate_login_risk(&current, &[], &rule, now(20 * 60 + 30)).level, RISK_LOW);
+
+        // 跨天时段
+        let rule = LoginRiskRule {
+            allowed_hours: parse_allowed_hours("22:00-06:00"),
+            travel_minutes: 0,
+        };
+        assert_eq!(evaluate_login_risk(&current, &[], &rule, now(60)).level, RISK_NONE);
+        assert_eq!(evaluate_login_risk(&current, &[], &rule, now(12 * 60)).level, RISK_LOW);
+
+        assert_eq!(parse_allowed_hours(""), None);
+        assert_eq!(parse_allowed_hours("8点-20点"), None);
+    }
+}
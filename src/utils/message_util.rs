@@ -0,0 +1,118 @@
+use crate::common::error::AppResult;
+use crate::AppState;
+use redis::{Client, Commands};
+use serde::{Deserialize, Serialize};
+use std::thread;
+use std::time::Duration;
+use tokio::sync::broadcast::Sender;
+
+// 消息推送频道(redis pub/sub, 所有服务实例都订阅该频道)
+const MESSAGE_CHANNEL: &str = "axum:admin:message";
+
+// 消息事件类型
+pub const EVENT_NOTICE: &str = "notice"; //新的通知公告
+pub const EVENT_PERMISSION: &str = "permission"; //权限变更
+pub const EVENT_LOGOUT: &str = "logout"; //强制下线
+
+/*
+ *推送消息
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushMessage {
+    pub event: String,           //事件类型(notice,permission,logout)
+    pub user_ids: Vec<i64>,      //接收用户id(为空时推送给所有在线用户)
+    pub data: serde_json::Value, //消息内容
+}
+
+impl PushMessage {
+    pub fn new(event: &str, user_ids: Vec<i64>, data: serde_json::Value) -> Self {
+        PushMessage {
+            event: event.to_string(),
+            user_ids,
+            data,
+        }
+    }
+
+    /*
+     *判断消息是否推送给该用户
+     *author：刘飞华
+     *date：2026/10/18 15:32:10
+     */
+    pub fn is_for(&self, user_id: i64) -> bool {
+        self.user_ids.is_empty() || self.user_ids.contains(&user_id)
+    }
+}
+
+/*
+ *发布消息(通过redis发布,由各个服务实例推送给自己的在线用户)
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+pub fn publish_message(state: &AppState, message: &PushMessage) -> AppResult<()> {
+    let mut conn = state.redis.get_connection()?;
+    let payload = serde_json::to_string(message).unwrap_or_default();
+    conn.publish::<_, _, ()>(MESSAGE_CHANNEL, payload)?;
+    Ok(())
+}
+
+/*
+ *发布消息,失败时只记录日志(用于业务操作成功后的附带推送)
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+pub fn send_message(state: &AppState, event: &str, user_ids: Vec<i64>, data: serde_json::Value) {
+    let message = PushMessage::new(event, user_ids, data);
+    if let Err(e) = publish_message(state, &message) {
+        log::error!("publish message error params: {:?}, error message: {:?}", message, e);
+    }
+}
+
+/*
+ *订阅redis消息频道,转发到本实例的广播通道(断线后自动重连)
+ *author：刘飞华
+ *date：2026/10/18 15:32:10
+ */
+pub fn start_message_subscriber(client: Client, sender: Sender<PushMessage>) {
+    thread::spawn(move || loop {
+        if let Err(e) = subscribe_message(&client, &sender) {
+            log::error!("subscribe message error: {:?}, retry after 3 seconds", e);
+        }
+        thread::sleep(Duration::from_secs(3));
+    });
+}
+
+fn subscribe_message(client: &Client, sender: &Sender<PushMessage>) -> redis::RedisResult<()> {
+    let mut conn = client.get_connection()?;
+    let mut pubsub = conn.as_pubsub();
+    pubsub.subscribe(MESSAGE_CHANNEL)?;
+    log::info!("subscribe message channel: {}", MESSAGE_CHANNEL);
+
+    loop {
+        let payload: String = pubsub.get_message()?.get_payload()?;
+        match serde_json::from_str::<PushMessage>(&payload) {
+            // 没有在线用户时发送会失败,忽略即可
+            Ok(message) => {
+                let _ = sender.send(message);
+            }
+            Err(e) => log::error!("parse message error payload: {}, error message: {:?}", payload, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::message_util::*;
+
+    #[test]
+    fn test_is_for() {
+        let all = PushMessage::new(EVENT_NOTICE, vec![], serde_json::Value::Null);
+        assert!(all.is_for(1));
+
+        let some = PushMessage::new(EVENT_LOGOUT, vec![2, 3], serde_json::Value::Null);
+        assert!(some.is_for(3));
+        assert!(!some.is_for(1));
+    }
+}
@@ -1,5 +1,13 @@
+pub mod api_key_util;
+pub mod config_util;
 pub mod db;
+pub mod ip_region_util;
+pub mod ip_util;
 pub mod jwt_util;
+pub mod log_archive_util;
+pub mod login_risk_util;
+pub mod message_util;
+pub mod password_util;
 pub mod redis_util;
 pub mod time_util;
 pub mod user_agent_util;
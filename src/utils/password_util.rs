@@ -0,0 +1,190 @@
+use crate::common::error::{AppError, AppResult};
+use crate::i18n::{t, tr};
+use crate::model::system::sys_user_model::User;
+use crate::model::system::sys_user_pwd_history_model::UserPwdHistory;
+use crate::utils::config_util::{get_config_i64, PASSWORD_CHAR_TYPES, PASSWORD_HISTORY_COUNT, PASSWORD_MAX_AGE_DAYS, PASSWORD_MIN_LENGTH};
+use crate::AppState;
+use rbatis::rbdc::DateTime;
+use sha2::{Digest, Sha256};
+
+// 密码长度上限(sys_user.password为varchar(64))
+const PASSWORD_MAX_LENGTH: usize = 64;
+
+/*
+ *密码策略
+ *author：刘飞华
+ *date：2026/10/18 21:26:40
+ */
+#[derive(Clone, Debug)]
+pub struct PasswordPolicy {
+    pub min_length: usize,  //最小长度
+    pub char_types: usize,  //至少包含的字符种类数(小写字母、大写字母、数字、特殊字符)
+    pub history_count: u64, //不能与最近N次使用过的密码相同,0表示不限制
+    pub max_age_days: i64,  //密码有效天数,0表示永不过期
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        PasswordPolicy {
+            min_length: 8,
+            char_types: 2,
+            history_count: 3,
+            max_age_days: 0,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /*
+     *从参数配置中读取密码策略
+     *author：刘飞华
+     *date：2026/10/18 21:26:40
+     */
+    pub async fn load(state: &AppState) -> Self {
+        let default = PasswordPolicy::default();
+        PasswordPolicy {
+            min_length: get_config_i64(state, PASSWORD_MIN_LENGTH, default.min_length as i64).await.clamp(1, PASSWORD_MAX_LENGTH as i64) as usize,
+            char_types: get_config_i64(state, PASSWORD_CHAR_TYPES, default.char_types as i64).await.clamp(1, 4) as usize,
+            history_count: get_config_i64(state, PASSWORD_HISTORY_COUNT, default.history_count as i64).await.max(0) as u64,
+            max_age_days: get_config_i64(state, PASSWORD_MAX_AGE_DAYS, default.max_age_days).await.max(0),
+        }
+    }
+
+    /*
+     *校验密码强度(长度、字符种类、不能与登录账号或手机号码相同)
+     *author：刘飞华
+     *date：2026/10/18 21:26:40
+     */
+    pub fn check_strength(&self, password: &str, user_name: &str, mobile: &str) -> Result<(), String> {
+        let length = password.chars().count();
+        if length < self.min_length {
+            return Err(tr("password.too_short", &[("min", &self.min_length.to_string())]));
+        }
+        if length > PASSWORD_MAX_LENGTH {
+            return Err(tr("password.too_long", &[("max", &PASSWORD_MAX_LENGTH.to_string())]));
+        }
+
+        let types = [
+            password.chars().any(|c| c.is_ascii_lowercase()),
+            password.chars().any(|c| c.is_ascii_uppercase()),
+            password.chars().any(|c| c.is_ascii_digit()),
+            password.chars().any(|c| !c.is_ascii_alphanumeric()),
+        ];
+        if types.iter().filter(|x| **x).count() < self.char_types {
+            return Err(tr("password.char_types", &[("count", &self.char_types.to_string())]));
+        }
+
+        if !user_name.is_empty() && password.eq_ignore_ascii_case(user_name) {
+            return Err(t("password.same_as_account"));
+        }
+        if !mobile.is_empty() && password == mobile {
+            return Err(t("password.same_as_mobile"));
+        }
+        Ok(())
+    }
+
+    /*
+     *判断密码是否已经过期(没有密码更新时间时使用用户创建时间)
+     *author：刘飞华
+     *date：2026/10/18 21:26:40
+     */
+    pub fn is_expired(&self, user: &User, now: &DateTime) -> bool {
+        self.is_expired_since(user.pwd_update_date.as_ref().or(user.create_time.as_ref()), now)
+    }
+
+    fn is_expired_since(&self, update_date: Option<&DateTime>, now: &DateTime) -> bool {
+        if self.max_age_days <= 0 {
+            return false;
+        }
+        match update_date {
+            None => false,
+            Some(x) => now.unix_timestamp() - x.unix_timestamp() > self.max_age_days * 86400,
+        }
+    }
+}
+
+/*
+ *校验新密码是否符合密码策略(强度和最近使用过的密码)
+ *author：刘飞华
+ *date：2026/10/18 21:26:40
+ */
+pub async fn check_password(state: &AppState, user: &User, password: &str) -> AppResult<()> {
+    let policy = PasswordPolicy::load(state).await;
+    policy.check_strength(password, &user.user_name, &user.mobile).map_err(AppError::ValidationError)?;
+
+    let Some(user_id) = user.id else {
+        return Ok(());
+    };
+    if policy.history_count == 0 {
+        return Ok(());
+    }
+
+    let hash = password_hash(user_id, password);
+    if user.password == password || UserPwdHistory::select_recent(&state.batis, user_id, policy.history_count).await?.iter().any(|x| x.password == hash) {
+        return Err(AppError::ValidationError(tr("password.recently_used", &[("count", &policy.history_count.to_string())])));
+    }
+    Ok(())
+}
+
+/*
+ *记录密码历史(只保存摘要)
+ *author：刘飞华
+ *date：2026/10/18 21:26:40
+ */
+pub async fn save_password_history(state: &AppState, user_id: i64, password: &str) -> AppResult<()> {
+    let history = UserPwdHistory {
+        id: None,
+        user_id,
+        password: password_hash(user_id, password),
+        create_time: Some(DateTime::now()),
+    };
+    UserPwdHistory::insert(&state.batis, &history).await?;
+    Ok(())
+}
+
+// 密码摘要(用户id作为盐)
+fn password_hash(user_id: i64, password: &str) -> String {
+    hex::encode(Sha256::digest(format!("{}:{}", user_id, password).as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::password_util::*;
+
+    #[test]
+    fn test_check_strength() {
+        let policy = PasswordPolicy {
+            min_length: 8,
+            char_types: 3,
+            ..Default::default()
+        };
+
+        assert!(policy.check_strength("Abc12345", "admin", "18613030111").is_ok());
+        assert!(policy.check_strength("abc@1234", "admin", "18613030111").is_ok());
+        assert_eq!(policy.check_strength("Abc1234", "admin", "").unwrap_err(), "密码长度不能少于8位");
+        assert!(policy.check_strength("abcd1234", "admin", "").is_err());
+        assert!(policy.check_strength(&"Abc1".repeat(20), "admin", "").is_err());
+        assert_eq!(policy.check_strength("Admin@123", "admin@123", "").unwrap_err(), "密码不能与登录账号相同");
+
+        let policy = PasswordPolicy {
+            min_length: 6,
+            char_types: 1,
+            ..Default::default()
+        };
+        assert_eq!(policy.check_strength("18613030111", "admin", "18613030111").unwrap_err(), "密码不能与手机号码相同");
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let now = DateTime::from_timestamp(1_000 * 86400);
+        let policy = PasswordPolicy {
+            max_age_days: 90,
+            ..Default::default()
+        };
+
+        assert!(!policy.is_expired_since(None, &now));
+        assert!(policy.is_expired_since(Some(&DateTime::from_timestamp(800 * 86400)), &now));
+        assert!(!policy.is_expired_since(Some(&DateTime::from_timestamp(950 * 86400)), &now));
+        assert!(!PasswordPolicy::default().is_expired_since(Some(&DateTime::from_timestamp(0)), &now));
+    }
+}
@@ -1,5 +1,19 @@
-use redis::Client;
+use crate::common::error::AppResult;
+use redis::{Client, Commands, Connection};
 
 pub async fn init_redis(url: &str) -> Client {
     redis::Client::open(url).expect("Invalid redis URL")
 }
+
+/*
+ *固定窗口限流: 在seconds秒内最多允许limit次,超过时返回false
+ *author：刘飞华
+ *date：2026/10/18 22:20:36
+ */
+pub fn check_rate_limit(conn: &mut Connection, key: &str, limit: i64, seconds: i64) -> AppResult<bool> {
+    let count: i64 = conn.incr(key, 1)?;
+    if count == 1 {
+        conn.expire::<_, ()>(key, seconds)?;
+    }
+    Ok(count <= limit)
+}
@@ -1,66 +1,281 @@
-use regex::Regex;
+use regex::{Captures, Regex, RegexBuilder};
+use serde::Deserialize;
+use std::sync::LazyLock;
+
+// 客户端类型
+pub const CLIENT_TYPE_HUMAN: &str = "human"; //用户(浏览器、App)
+pub const CLIENT_TYPE_BOT: &str = "bot"; //爬虫、自动化工具
+pub const CLIENT_TYPE_API: &str = "api"; //接口调用客户端(curl、postman等)
+
+// 未识别时的默认值
+const OTHER: &str = "Other";
+// 解析结果的最大长度(与登录日志的字段长度一致)
+const MAX_LENGTH: usize = 50;
+
+// 解析规则(regexes.yaml格式)
+static PARSERS: LazyLock<UserAgentParsers> = LazyLock::new(|| UserAgentParsers::from_yaml(include_str!("user_agent_regexes.yaml")));
 
 #[derive(Debug, Clone)]
 pub struct UserAgentUtil {
-    pub platform: String,       //平台信息
+    pub platform: String,       //平台信息(设备)
     pub os: String,             //操作系统信息
     pub arch: String,           //体系结构信息
     pub engine: String,         //渲染引擎信息
-    pub engine_details: String, //渲染引擎详细信息
+    pub engine_details: String, //渲染引擎详细信息(版本)
     pub browser: String,        //浏览器名称
     pub version: String,        //浏览器版本
-    pub extra: String,          //其他信息（可选）
+    pub extra: String,          //其他信息（可选,设备品牌和型号）
+    pub client_type: String,    //客户端类型(human:用户,bot:爬虫,api:接口调用)
 }
 
 impl UserAgentUtil {
     pub fn new(user_agent: &str) -> Self {
-        let mut parse = UserAgentUtil {
-            platform: "".to_string(),
-            os: "".to_string(),
-            arch: "".to_string(),
-            engine: "".to_string(),
-            engine_details: "".to_string(),
-            browser: "".to_string(),
-            version: "".to_string(),
-            extra: "".to_string(),
+        let user_agent = user_agent.trim();
+        let parsers = &*PARSERS;
+
+        let (browser, version, client_type) = match parsers.user_agent.iter().find_map(|x| x.parse(user_agent)) {
+            Some((values, client_type)) => (values[0].clone(), join_version(&values[1..]), client_type),
+            None => (OTHER.to_string(), "".to_string(), None),
+        };
+
+        let os = match parsers.os.iter().find_map(|x| x.parse(user_agent)) {
+            Some((values, _)) => {
+                let version = join_version(&values[1..]);
+                if version.is_empty() {
+                    values[0].clone()
+                } else {
+                    format!("{} {}", values[0], version)
+                }
+            }
+            None => OTHER.to_string(),
+        };
+
+        let (platform, extra) = match parsers.device.iter().find_map(|x| x.parse(user_agent)) {
+            Some((values, _)) => (
+                values[0].clone(),
+                values[1..].iter().filter(|x| !x.is_empty() && *x != &values[0]).cloned().collect::<Vec<String>>().join(" "),
+            ),
+            None => (OTHER.to_string(), "".to_string()),
+        };
+
+        let (engine, engine_details) = match parsers.engine.iter().find_map(|x| x.parse(user_agent)) {
+            Some((values, _)) => (values[0].clone(), join_version(&values[1..])),
+            None => ("".to_string(), "".to_string()),
+        };
+
+        let arch = parsers.arch.iter().find_map(|x| x.parse(user_agent)).map(|(values, _)| values[0].clone()).unwrap_or_default();
+
+        // 没有User-Agent的请求不是浏览器发起的,按接口调用处理
+        let client_type = match client_type {
+            Some(x) => x,
+            None if platform == "Spider" => CLIENT_TYPE_BOT.to_string(),
+            None if user_agent.is_empty() => CLIENT_TYPE_API.to_string(),
+            None => CLIENT_TYPE_HUMAN.to_string(),
+        };
+
+        UserAgentUtil {
+            platform: truncate(platform),
+            os: truncate(os),
+            arch: truncate(arch),
+            engine: truncate(engine),
+            engine_details: truncate(engine_details),
+            browser: truncate(browser),
+            version: truncate(version),
+            extra: truncate(extra),
+            client_type,
+        }
+    }
+
+    pub fn is_bot(&self) -> bool {
+        self.client_type == CLIENT_TYPE_BOT
+    }
+
+    pub fn is_api(&self) -> bool {
+        self.client_type == CLIENT_TYPE_API
+    }
+}
+
+/*
+ *regexes.yaml文件结构
+ *author：刘飞华
+ *date：2026/10/18 18:52:36
+ */
+#[derive(Debug, Default, Deserialize)]
+struct RegexFile {
+    #[serde(default)]
+    user_agent_parsers: Vec<RegexEntry>,
+    #[serde(default)]
+    os_parsers: Vec<RegexEntry>,
+    #[serde(default)]
+    device_parsers: Vec<RegexEntry>,
+    #[serde(default)]
+    engine_parsers: Vec<RegexEntry>,
+    #[serde(default)]
+    arch_parsers: Vec<RegexEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RegexEntry {
+    regex: String,
+    regex_flag: Option<String>,
+    client_type: Option<String>,
+    #[serde(alias = "os_replacement", alias = "device_replacement", alias = "arch_replacement")]
+    family_replacement: Option<String>,
+    #[serde(alias = "os_v1_replacement", alias = "brand_replacement")]
+    v1_replacement: Option<String>,
+    #[serde(alias = "os_v2_replacement", alias = "model_replacement")]
+    v2_replacement: Option<String>,
+    #[serde(alias = "os_v3_replacement")]
+    v3_replacement: Option<String>,
+}
+
+struct UserAgentParsers {
+    user_agent: Vec<Matcher>,
+    os: Vec<Matcher>,
+    device: Vec<Matcher>,
+    engine: Vec<Matcher>,
+    arch: Vec<Matcher>,
+}
+
+impl UserAgentParsers {
+    fn from_yaml(content: &str) -> Self {
+        let file = serde_yaml::from_str::<RegexFile>(content).unwrap_or_else(|e| {
+            log::error!("parse user agent regexes error: {:?}", e);
+            RegexFile::default()
+        });
+
+        // 没有配置替换值时: 名称和版本依次使用捕获组; 设备的品牌默认为空,型号默认使用第一个捕获组
+        let default = ["$1", "$2", "$3", "$4"];
+        let device = ["$1", "", "$1"];
+        UserAgentParsers {
+            user_agent: file.user_agent_parsers.iter().filter_map(|x| Matcher::new(x, &default)).collect(),
+            os: file.os_parsers.iter().filter_map(|x| Matcher::new(x, &default)).collect(),
+            device: file.device_parsers.iter().filter_map(|x| Matcher::new(x, &device)).collect(),
+            engine: file.engine_parsers.iter().filter_map(|x| Matcher::new(x, &default)).collect(),
+            arch: file.arch_parsers.iter().filter_map(|x| Matcher::new(x, &default[..1])).collect(),
+        }
+    }
+}
+
+/*
+ *单条解析规则
+ *author：刘飞华
+ *date：2026/10/18 18:52:36
+ */
+struct Matcher {
+    regex: Regex,
+    replacements: Vec<String>,
+    client_type: Option<String>,
+}
+
+impl Matcher {
+    fn new(entry: &RegexEntry, default: &[&str]) -> Option<Matcher> {
+        let regex = RegexBuilder::new(&entry.regex).case_insensitive(entry.regex_flag.as_deref() == Some("i")).build();
+        let regex = match regex {
+            Ok(x) => x,
+            Err(e) => {
+                log::error!("invalid user agent regex: {}, error message: {:?}", entry.regex, e);
+                return None;
+            }
         };
-        // 定义正则表达式来匹配并提取信息
-        let re = Regex::new(
-            r"(?x)
-        Mozilla/5.0 \s*                      # Mozilla标识
-        \((?P<platform>[^;]+); \s*            # 平台信息
-        (?P<os>[^;]+); \s*                    # 操作系统信息
-        (?P<arch>[^\)]+)\) \s*                # 体系结构信息
-        (?P<engine>[^\s]+) \s*                # 渲染引擎信息
-        \((?P<engine_details>[^\)]+)\) \s*    # 渲染引擎详细信息
-        (?P<browser>[^\s/]+)/                 # 浏览器名称
-        (?P<version>[^\s]+) \s*               # 浏览器版本
-        (?P<extra>[^\s]+)?                    # 其他信息（可选）
-    ",
-        )
-        .unwrap();
-
-        // 使用正则表达式进行匹配
-        if let Some(captures) = re.captures(user_agent) {
-            let platform = captures.name("platform").unwrap().as_str();
-            let os = captures.name("os").unwrap().as_str();
-            let arch = captures.name("arch").unwrap().as_str();
-            let engine = captures.name("engine").unwrap().as_str();
-            let engine_details = captures.name("engine_details").unwrap().as_str();
-            let browser = captures.name("browser").unwrap().as_str();
-            let version = captures.name("version").unwrap().as_str();
-            let extra = captures.name("extra").map_or("", |m| m.as_str());
-
-            parse.platform = platform.to_string();
-            parse.os = os.to_string();
-            parse.arch = arch.to_string();
-            parse.engine = engine.to_string();
-            parse.engine_details = engine_details.to_string();
-            parse.browser = browser.to_string();
-            parse.version = version.to_string();
-            parse.extra = extra.to_string();
+
+        let values = [&entry.family_replacement, &entry.v1_replacement, &entry.v2_replacement, &entry.v3_replacement];
+        let replacements = default.iter().zip(values).map(|(d, v)| v.clone().unwrap_or_else(|| d.to_string())).collect();
+        Some(Matcher {
+            regex,
+            replacements,
+            client_type: entry.client_type.clone(),
+        })
+    }
+
+    fn parse(&self, user_agent: &str) -> Option<(Vec<String>, Option<String>)> {
+        let captures = self.regex.captures(user_agent)?;
+        let values = self.replacements.iter().map(|x| replace(x, &captures)).collect::<Vec<String>>();
+        if values[0].is_empty() {
+            return None;
+        }
+        Some((values, self.client_type.clone()))
+    }
+}
+
+// 将$1~$9替换为对应的捕获组(不存在的捕获组替换为空)
+fn replace(replacement: &str, captures: &Captures) -> String {
+    let mut result = String::new();
+    let mut chars = replacement.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek().and_then(|x| x.to_digit(10))) {
+            ('$', Some(i)) => {
+                chars.next();
+                result.push_str(captures.get(i as usize).map_or("", |m| m.as_str()));
+            }
+            _ => result.push(c),
         }
+    }
+    result.trim().to_string()
+}
+
+// 拼接版本号,遇到空值就结束(如: 120.0.6099)
+fn join_version(values: &[String]) -> String {
+    values.iter().take_while(|x| !x.is_empty()).cloned().collect::<Vec<String>>().join(".")
+}
+
+fn truncate(value: String) -> String {
+    if value.chars().count() > MAX_LENGTH {
+        value.chars().take(MAX_LENGTH).collect()
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::user_agent_util::*;
+
+    // 测试用例(只校验用例中配置的字段)
+    #[derive(Debug, Deserialize)]
+    struct Fixture {
+        user_agent: String,
+        browser: Option<String>,
+        version: Option<String>,
+        os: Option<String>,
+        platform: Option<String>,
+        extra: Option<String>,
+        engine: Option<String>,
+        arch: Option<String>,
+        client_type: Option<String>,
+    }
 
-        parse
+    #[test]
+    fn test_regexes() {
+        let file = serde_yaml::from_str::<RegexFile>(include_str!("user_agent_regexes.yaml")).unwrap();
+        let parsers = UserAgentParsers::from_yaml(include_str!("user_agent_regexes.yaml"));
+        assert_eq!(parsers.user_agent.len(), file.user_agent_parsers.len());
+        assert_eq!(parsers.os.len(), file.os_parsers.len());
+        assert_eq!(parsers.device.len(), file.device_parsers.len());
+        assert_eq!(parsers.engine.len(), file.engine_parsers.len());
+        assert_eq!(parsers.arch.len(), file.arch_parsers.len());
+    }
+
+    #[test]
+    fn test_fixtures() {
+        let fixtures = serde_yaml::from_str::<Vec<Fixture>>(include_str!("user_agent_fixtures.yaml")).unwrap();
+        for x in fixtures {
+            let agent = UserAgentUtil::new(&x.user_agent);
+            let cases = [
+                (&x.browser, &agent.browser),
+                (&x.version, &agent.version),
+                (&x.os, &agent.os),
+                (&x.platform, &agent.platform),
+                (&x.extra, &agent.extra),
+                (&x.engine, &agent.engine),
+                (&x.arch, &agent.arch),
+                (&x.client_type, &agent.client_type),
+            ];
+            for (expected, actual) in cases {
+                if let Some(expected) = expected {
+                    assert_eq!(expected, actual, "user agent: {}, parse result: {:?}", x.user_agent, agent);
+                }
+            }
+        }
     }
 }
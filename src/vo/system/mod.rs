@@ -1,6 +1,11 @@
+pub mod sys_api_key_vo;
+pub mod sys_config_vo;
 pub mod sys_dept_vo;
 pub mod sys_dict_data_vo;
 pub mod sys_dict_type_vo;
+pub mod sys_file_vo;
+pub mod sys_job_log_vo;
+pub mod sys_job_vo;
 pub mod sys_login_log_vo;
 pub mod sys_menu_vo;
 pub mod sys_notice_vo;
@@ -0,0 +1,108 @@
+// author：刘飞华
+// createTime：2026/10/19 01:20:42
+
+use crate::common::page::PageQuery;
+use crate::common::result::serialize_datetime;
+use rbatis::rbdc::DateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/*
+添加API密钥请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AddApiKeyReq {
+    #[validate(length(min = 1, max = 50, message = "validation.length_between"))]
+    pub key_name: String, //密钥名称
+    #[serde(default)]
+    pub permissions: Vec<String>, //允许访问的接口(为空时与当前用户的权限相同)
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub expire_time: Option<DateTime>, //过期时间(为空时不过期)
+    #[validate(length(max = 255, message = "validation.length_max"))]
+    pub remark: Option<String>, //备注
+}
+
+/*
+更新API密钥请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateApiKeyReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub id: i64, //主键
+    #[validate(length(min = 1, max = 50, message = "validation.length_between"))]
+    pub key_name: String, //密钥名称
+    #[serde(default)]
+    pub permissions: Vec<String>, //允许访问的接口(为空时与当前用户的权限相同)
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub expire_time: Option<DateTime>, //过期时间(为空时不过期)
+    #[validate(length(max = 255, message = "validation.length_max"))]
+    pub remark: Option<String>, //备注
+}
+
+/*
+吊销API密钥请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RevokeApiKeyReq {
+    #[validate(length(min = 1, message = "validation.required"))]
+    pub ids: Vec<i64>,
+}
+
+/*
+查询API密钥列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryApiKeyListReq {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
+    pub key_name: Option<String>, //密钥名称
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: Option<i8>, //状态(1:正常,0:已吊销)
+    #[serde(skip_deserializing)]
+    #[schema(ignore)]
+    pub user_id: i64, //当前用户id(由登录信息填充,不能通过请求参数指定)
+}
+
+/*
+添加API密钥响应参数(密钥只返回这一次)
+*/
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AddApiKeyResp {
+    pub id: i64,            //主键
+    pub key_prefix: String, //密钥前缀
+    pub api_key: String,    //密钥(请求头X-API-Key或者Authorization: Bearer使用)
+}
+
+/*
+查询API密钥列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyResp {
+    pub id: Option<i64>,          //主键
+    pub user_id: i64,             //所属用户id
+    pub key_name: String,         //密钥名称
+    pub key_prefix: String,       //密钥前缀
+    pub permissions: Vec<String>, //允许访问的接口
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub expire_time: Option<DateTime>, //过期时间
+    pub status: i8,               //状态(1:正常,0:已吊销)
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub last_used_time: Option<DateTime>, //最后使用时间
+    pub last_used_ip: String,     //最后使用IP
+    pub remark: Option<String>,   //备注
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub create_time: Option<DateTime>, //创建时间
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub update_time: Option<DateTime>, //修改时间
+}
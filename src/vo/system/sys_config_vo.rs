@@ -0,0 +1,83 @@
+// author：刘飞华
+// createTime：2026/10/18 10:12:36
+
+use crate::common::page::PageQuery;
+use crate::common::result::serialize_datetime;
+use rbatis::rbdc::DateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/*
+删除参数配置请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct DeleteConfigReq {
+    #[validate(length(min = 1, message = "validation.required"))]
+    pub ids: Vec<i64>,
+}
+
+/*
+更新参数配置请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigReq {
+    pub id: Option<i64>, //参数主键
+    #[validate(length(min = 1, max = 100, message = "validation.length_between"))]
+    pub config_name: String, //参数名称
+    #[validate(length(min = 1, max = 100, message = "validation.length_between"))]
+    pub config_key: String, //参数键名
+    #[validate(length(max = 500, message = "validation.length_max"))]
+    pub config_value: String, //参数键值
+    #[validate(range(min = 1, max = 4, message = "validation.range_between"))]
+    pub value_type: i8, //参数值类型（1:字符串,2:数字,3:布尔,4:JSON）
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub is_builtin: i8, //是否系统内置（0:否,1:是）
+    #[validate(length(max = 500, message = "validation.length_max"))]
+    pub remark: Option<String>, //备注
+}
+
+/*
+查询参数配置详情请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct QueryConfigDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub id: i64,
+}
+
+/*
+查询参数配置列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryConfigListReq {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
+    pub config_name: Option<String>, //参数名称
+    pub config_key: Option<String>,  //参数键名
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub is_builtin: Option<i8>, //是否系统内置（0:否,1:是）
+}
+/*
+查询参数配置列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigResp {
+    pub id: Option<i64>,        //参数主键
+    pub config_name: String,    //参数名称
+    pub config_key: String,     //参数键名
+    pub config_value: String,   //参数键值
+    pub value_type: i8,         //参数值类型（1:字符串,2:数字,3:布尔,4:JSON）
+    pub is_builtin: i8,         //是否系统内置（0:否,1:是）
+    pub remark: Option<String>, //备注
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub create_time: Option<DateTime>, //创建时间
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub update_time: Option<DateTime>, //修改时间
+}
@@ -6,12 +6,14 @@ use rbatis::rbdc::DateTime;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
+use utoipa::ToSchema;
 use validator::Validate;
 /*
 删除部门表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DeleteDeptReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
@@ -20,7 +22,7 @@ static PHONE_NUMBER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\d{11}$").u
 /*
 更新部门表请求参数
 */
-#[derive(Debug, Serialize, Deserialize, Validate)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DeptReq {
     pub id: Option<i64>, //部门id
@@ -35,46 +37,47 @@ pub struct DeptReq {
     pub phone: String, //联系电话
     #[validate(email, length(max = 50, message = "邮箱格式不正确且长度不能超过50"))]
     pub email: String, //邮箱
-    pub status: i8,      //部状态（0：停用，1:正常）
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: i8, //部状态（0：停用，1:正常）
 }
 
 /*
 更新部门表状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateDeptStatusReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: i8,
 }
 
 /*
 查询部门表详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct QueryDeptDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 查询部门表列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryDeptListReq {
     pub dept_name: Option<String>, //部门名称
     pub leader: Option<String>,    //负责人
     pub phone: Option<String>,     //联系电话
     pub email: Option<String>,     //邮箱
-    #[serde(default = "default_status")]
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: Option<i8>, //部状态（0：停用，1:正常）
 }
-fn default_status() -> Option<i8> {
-    Some(2)
-}
 /*
 查询部门表列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DeptResp {
     pub id: Option<i64>,           //部门id
@@ -87,7 +90,9 @@ pub struct DeptResp {
     pub email: String,             //邮箱
     pub status: i8,                //部状态（0：停用，1:正常）
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub create_time: Option<DateTime>, //创建时间
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub update_time: Option<DateTime>, //修改时间
 }
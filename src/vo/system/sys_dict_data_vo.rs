@@ -1,74 +1,91 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use crate::common::page::PageQuery;
 use crate::common::result::serialize_datetime;
 use rbatis::rbdc::DateTime;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use utoipa::ToSchema;
+use validator::Validate;
 
 /*
 删除字典数据表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DeleteDictDataReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
 }
 
+static YES_NO: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[YN]$").unwrap());
+
 /*
 更新字典数据表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DictDataReq {
-    pub id: Option<i64>,        //字典编码
-    pub dict_sort: i32,         //字典排序
-    pub dict_label: String,     //字典标签
-    pub dict_value: String,     //字典键值
-    pub dict_type: String,      //字典类型
-    pub css_class: String,      //样式属性（其他样式扩展）
-    pub list_class: String,     //表格回显样式
-    pub is_default: String,     //是否默认（Y是 N否）
-    pub status: i8,             //状态（0：停用，1:正常）
+    pub id: Option<i64>, //字典编码
+    pub dict_sort: i32,  //字典排序
+    #[validate(length(min = 1, max = 100, message = "validation.length_between"))]
+    pub dict_label: String, //字典标签
+    #[validate(length(min = 1, max = 100, message = "validation.length_between"))]
+    pub dict_value: String, //字典键值
+    #[validate(length(min = 1, max = 100, message = "validation.length_between"))]
+    pub dict_type: String, //字典类型
+    #[validate(length(max = 100, message = "validation.length_max"))]
+    pub css_class: String, //样式属性（其他样式扩展）
+    #[validate(length(max = 100, message = "validation.length_max"))]
+    pub list_class: String, //表格回显样式
+    #[validate(regex(path = *YES_NO, message = "validation.regex"))]
+    pub is_default: String, //是否默认（Y是 N否）
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: i8, //状态（0：停用，1:正常）
+    #[validate(length(max = 500, message = "validation.length_max"))]
     pub remark: Option<String>, //备注
 }
 
 /*
 更新字典数据表状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateDictDataStatusReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: i8,
 }
 
 /*
 查询字典数据表详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct QueryDictDataDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 查询字典数据表列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryDictDataListReq {
-    pub page_no: u64,
-    pub page_size: u64,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
     pub dict_label: Option<String>, //字典标签
     pub dict_value: Option<String>, //字典键值
     pub dict_type: Option<String>,  //字典类型
-    #[serde(default = "default_status")]
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: Option<i8>, //状态（0：停用，1:正常）
 }
-fn default_status() -> Option<i8> {
-    Some(2)
-}
 /*
 查询字典数据表列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DictDataResp {
     pub id: Option<i64>,        //字典编码
@@ -82,7 +99,9 @@ pub struct DictDataResp {
     pub status: i8,             //状态（0：停用，1:正常）
     pub remark: Option<String>, //备注
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub create_time: Option<DateTime>, //创建时间
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub update_time: Option<DateTime>, //修改时间
 }
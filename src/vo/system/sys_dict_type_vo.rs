@@ -1,68 +1,77 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use crate::common::page::PageQuery;
 use crate::common::result::serialize_datetime;
 use rbatis::rbdc::DateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 /*
 删除字典类型表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DeleteDictTypeReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
 }
 
 /*
 更新字典类型表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DictTypeReq {
-    pub id: Option<i64>,        //字典主键
-    pub dict_name: String,      //字典名称
-    pub dict_type: String,      //字典类型
-    pub status: i8,             //状态（0：停用，1:正常）
+    pub id: Option<i64>, //字典主键
+    #[validate(length(min = 1, max = 100, message = "validation.length_between"))]
+    pub dict_name: String, //字典名称
+    #[validate(length(min = 1, max = 100, message = "validation.length_between"))]
+    pub dict_type: String, //字典类型
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: i8, //状态（0：停用，1:正常）
+    #[validate(length(max = 500, message = "validation.length_max"))]
     pub remark: Option<String>, //备注
 }
 
 /*
 更新字典类型表状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateDictTypeStatusReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: i8,
 }
 
 /*
 查询字典类型表详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct QueryDictTypeDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 查询字典类型表列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryDictTypeListReq {
-    pub page_no: u64,
-    pub page_size: u64,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
     pub dict_name: Option<String>, //字典名称
     pub dict_type: Option<String>, //字典类型
-    #[serde(default = "default_status")]
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: Option<i8>, //状态（0：停用，1:正常）
 }
-fn default_status() -> Option<i8> {
-    Some(2)
-}
 /*
 查询字典类型表列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DictTypeResp {
     pub id: Option<i64>,        //字典主键
@@ -71,7 +80,9 @@ pub struct DictTypeResp {
     pub status: i8,             //状态（0：停用，1:正常）
     pub remark: Option<String>, //备注
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub create_time: Option<DateTime>, //创建时间
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub update_time: Option<DateTime>, //修改时间
 }
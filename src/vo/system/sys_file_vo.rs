@@ -0,0 +1,63 @@
+// author：刘飞华
+// createTime：2026/10/18 20:48:15
+
+use crate::common::page::PageQuery;
+use crate::common::result::serialize_datetime;
+use rbatis::rbdc::DateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/*
+删除文件请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct DeleteFileReq {
+    #[validate(length(min = 1, message = "validation.required"))]
+    pub ids: Vec<i64>,
+}
+
+/*
+查询文件详情请求参数(下载文件时也使用这个参数)
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct QueryFileDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub id: i64,
+}
+
+/*
+查询文件列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryFileListReq {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
+    pub file_name: Option<String>, //原始文件名
+    pub mime_type: Option<String>, //文件类型(前缀匹配,如image/)
+    pub owner_id: Option<i64>,     //上传者id
+}
+
+/*
+查询文件列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileResp {
+    pub id: Option<i64>,      //文件id
+    pub file_name: String,    //原始文件名
+    pub storage_type: String, //存储类型(local:本地文件系统,s3:对象存储)
+    pub file_size: i64,       //文件大小(字节)
+    pub mime_type: String,    //文件类型
+    pub file_hash: String,    //文件内容的sha256
+    pub owner_id: i64,        //上传者id
+    pub url: String,          //下载地址
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub create_time: Option<DateTime>, //创建时间
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub update_time: Option<DateTime>, //修改时间
+}
@@ -0,0 +1,68 @@
+// author：刘飞华
+// createTime：2026/10/18 16:40:18
+
+use crate::common::page::PageQuery;
+use crate::common::result::serialize_datetime;
+use rbatis::rbdc::DateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/*
+删除定时任务日志请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct DeleteJobLogReq {
+    #[validate(length(min = 1, message = "validation.required"))]
+    pub ids: Vec<i64>,
+}
+
+/*
+查询定时任务日志详情请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct QueryJobLogDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub id: i64,
+}
+
+/*
+查询定时任务日志列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryJobLogListReq {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
+    pub job_id: Option<i64>,           //任务ID
+    pub job_name: Option<String>,      //任务名称
+    pub job_group: Option<String>,     //任务组名
+    pub invoke_target: Option<String>, //调用目标
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: Option<i8>, //执行状态（0:失败,1:成功）
+}
+/*
+查询定时任务日志列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobLogResp {
+    pub id: Option<i64>,       //任务日志ID
+    pub job_id: i64,           //任务ID
+    pub job_name: String,      //任务名称
+    pub job_group: String,     //任务组名
+    pub invoke_target: String, //调用目标
+    pub job_params: String,    //任务参数
+    pub trigger_type: i8,      //触发方式（1:定时触发,2:手动执行,3:错过补偿）
+    pub job_message: String,   //日志信息
+    pub status: i8,            //执行状态（0:失败,1:成功）
+    pub error_msg: String,     //异常信息
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub start_time: Option<DateTime>, //开始时间
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub end_time: Option<DateTime>, //结束时间
+    pub duration: i64,         //执行耗时(毫秒)
+}
@@ -0,0 +1,116 @@
+// author：刘飞华
+// createTime：2026/10/18 16:40:18
+
+use crate::common::page::PageQuery;
+use crate::common::result::serialize_datetime;
+use crate::task::scheduler::{MISFIRE_FIRE_ALL, MISFIRE_IGNORE};
+use rbatis::rbdc::DateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/*
+删除定时任务请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct DeleteJobReq {
+    #[validate(length(min = 1, message = "validation.required"))]
+    pub ids: Vec<i64>,
+}
+
+/*
+更新定时任务请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobReq {
+    pub id: Option<i64>, //任务ID
+    #[validate(length(min = 1, max = 64, message = "validation.length_between"))]
+    pub job_name: String, //任务名称
+    #[validate(length(min = 1, max = 64, message = "validation.length_between"))]
+    pub job_group: String, //任务组名
+    #[validate(length(min = 1, max = 100, message = "validation.length_between"))]
+    pub invoke_target: String, //调用目标(代码中注册的任务名称)
+    #[serde(default)]
+    #[validate(length(max = 500, message = "validation.length_max"))]
+    pub job_params: String, //任务参数
+    #[validate(length(min = 1, max = 255, message = "validation.length_between"))]
+    pub cron_expression: String, //cron执行表达式
+    #[validate(range(min = MISFIRE_FIRE_ALL, max = MISFIRE_IGNORE, message = "validation.range_between"))]
+    pub misfire_policy: i8, //计划执行错误策略（1:立即执行,2:执行一次,3:放弃执行）
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub concurrent: i8, //是否禁止并发执行（0:允许,1:禁止）
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: i8, //状态（0:暂停,1:正常）
+    #[validate(length(max = 500, message = "validation.length_max"))]
+    pub remark: Option<String>, //备注
+}
+
+/*
+更新定时任务状态请求参数(暂停/恢复)
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateJobStatusReq {
+    #[validate(length(min = 1, message = "validation.required"))]
+    pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: i8,
+}
+
+/*
+立即执行定时任务请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RunJobReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub id: i64,
+}
+
+/*
+查询定时任务详情请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct QueryJobDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub id: i64,
+}
+
+/*
+查询定时任务列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryJobListReq {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
+    pub job_name: Option<String>,      //任务名称
+    pub job_group: Option<String>,     //任务组名
+    pub invoke_target: Option<String>, //调用目标
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: Option<i8>, //状态（0:暂停,1:正常）
+}
+/*
+查询定时任务列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobResp {
+    pub id: Option<i64>,         //任务ID
+    pub job_name: String,        //任务名称
+    pub job_group: String,       //任务组名
+    pub invoke_target: String,   //调用目标
+    pub job_params: String,      //任务参数
+    pub cron_expression: String, //cron执行表达式
+    pub misfire_policy: i8,      //计划执行错误策略（1:立即执行,2:执行一次,3:放弃执行）
+    pub concurrent: i8,          //是否禁止并发执行（0:允许,1:禁止）
+    pub status: i8,              //状态（0:暂停,1:正常）
+    pub next_valid_time: String, //下次执行时间
+    pub remark: Option<String>,  //备注
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub create_time: Option<DateTime>, //创建时间
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub update_time: Option<DateTime>, //修改时间
+}
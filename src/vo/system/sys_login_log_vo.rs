@@ -1,48 +1,88 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
+use crate::common::page::{CursorQuery, PageQuery};
 use crate::common::result::serialize_datetime;
+use crate::common::valid::cross_field_error;
 use rbatis::rbdc::DateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
 
 /*
 删除系统访问记录请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DeleteLoginLogReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
 }
 
+/*
+按时间范围删除系统访问记录请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_delete_by_time"))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteLoginLogByTimeReq {
+    #[schema(value_type = String, example = "2026-01-01 00:00:00")]
+    pub begin_time: DateTime, //开始时间
+    #[schema(value_type = String, example = "2026-01-01 00:00:00")]
+    pub end_time: DateTime, //结束时间
+}
+
 /*
 查询系统访问记录详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct QueryLoginLogDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 查询系统访问记录列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryLoginLogListReq {
-    pub page_no: u64,
-    pub page_size: u64,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
     pub login_name: Option<String>,     //登录账号
     pub ipaddr: Option<String>,         //登录IP地址
     pub login_location: Option<String>, //登录地点
     pub browser: Option<String>,        //浏览器类型
     pub os: Option<String>,             //操作系统
-    #[serde(default = "default_status")]
+    pub client_type: Option<String>,    //客户端类型(human:用户,bot:爬虫,api:接口调用)
+    #[validate(range(min = 1, max = 3, message = "validation.range_between"))]
+    pub risk_level: Option<i8>, //风险等级(查询大于等于该等级的记录,1:低,2:中,3:高)
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: Option<i8>, //登录状态(0:失败,1:成功)
 }
-fn default_status() -> Option<i8> {
-    Some(2)
+/*
+游标分页查询系统访问记录列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryLoginLogCursorListReq {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: CursorQuery, //游标分页和时间范围
+    pub login_name: Option<String>,     //登录账号
+    pub ipaddr: Option<String>,         //登录IP地址
+    pub login_location: Option<String>, //登录地点
+    pub browser: Option<String>,        //浏览器类型
+    pub os: Option<String>,             //操作系统
+    pub client_type: Option<String>,    //客户端类型(human:用户,bot:爬虫,api:接口调用)
+    #[validate(range(min = 1, max = 3, message = "validation.range_between"))]
+    pub risk_level: Option<i8>, //风险等级(查询大于等于该等级的记录,1:低,2:中,3:高)
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: Option<i8>, //登录状态(0:失败,1:成功)
 }
 /*
 查询系统访问记录列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct LoginLogResp {
     pub id: Option<i64>,        //访问ID
@@ -57,8 +97,20 @@ pub struct LoginLogResp {
     pub engine: String,         //渲染引擎信息
     pub engine_details: String, //渲染引擎详细信息
     pub extra: String,          //其他信息（可选）
+    pub client_type: String,    //客户端类型(human:用户,bot:爬虫,api:接口调用)
     pub status: i8,             //登录状态(0:失败,1:成功)
     pub msg: String,            //提示消息
+    pub risk_level: i8,         //风险等级(0:无,1:低,2:中,3:高)
+    pub risk_reason: String,    //风险原因
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub login_time: Option<DateTime>, //访问时间
 }
+
+// 结束时间必须晚于开始时间
+fn validate_delete_by_time(req: &DeleteLoginLogByTimeReq) -> Result<(), ValidationError> {
+    if !req.end_time.after(&req.begin_time) {
+        return Err(cross_field_error("endTime", "TIME_RANGE_INVALID"));
+    }
+    Ok(())
+}
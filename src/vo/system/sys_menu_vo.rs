@@ -2,66 +2,83 @@
 // createTime：2024/12/12 14:41:44
 
 use crate::common::result::serialize_datetime;
+use crate::common::valid::cross_field_error;
 use rbatis::rbdc::DateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
 
 /*
 删除菜单信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DeleteMenuReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 更新菜单信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_menu"))]
 #[serde(rename_all = "camelCase")]
 pub struct MenuReq {
-    pub id: Option<i64>,           //主键
-    pub menu_name: String,         //菜单名称
-    pub menu_type: i8,             //菜单类型(1：目录   2：菜单   3：按钮)
-    pub visible: i8,               //菜单状态（0:隐藏, 显示:1）
-    pub status: i8,                //状态(1:正常，0:禁用)
-    pub sort: i32,                 //排序
-    pub parent_id: Option<i64>,    //父ID
-    pub menu_url: Option<String>,  //路由路径
-    pub api_url: Option<String>,   //接口URL
+    pub id: Option<i64>, //主键
+    #[validate(length(min = 1, max = 50, message = "validation.length_between"))]
+    pub menu_name: String, //菜单名称
+    #[validate(range(min = 1, max = 3, message = "validation.range_between"))]
+    pub menu_type: i8, //菜单类型(1：目录   2：菜单   3：按钮)
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub visible: i8, //菜单状态（0:隐藏, 显示:1）
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: i8, //状态(1:正常，0:禁用)
+    pub sort: i32,       //排序
+    pub parent_id: Option<i64>, //父ID
+    #[validate(length(max = 255, message = "validation.length_max"))]
+    pub menu_url: Option<String>, //路由路径
+    #[validate(length(max = 255, message = "validation.length_max"))]
+    pub api_url: Option<String>, //接口URL
+    #[validate(length(max = 255, message = "validation.length_max"))]
     pub menu_icon: Option<String>, //菜单图标
-    pub remark: Option<String>,    //备注
+    #[validate(length(max = 255, message = "validation.length_max"))]
+    pub remark: Option<String>, //备注
 }
 
 /*
 更新菜单信息状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateMenuStatusReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: i8,
 }
 
 /*
 查询菜单信息详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct QueryMenuDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 查询菜单信息列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryMenuListReq {
+    #[validate(length(max = 50, message = "validation.length_max"))]
     pub menu_name: Option<String>, //菜单名称
 }
 
 /*
 查询菜单信息列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MenuResp {
     pub id: Option<i64>,           //主键
@@ -76,18 +93,32 @@ pub struct MenuResp {
     pub menu_icon: Option<String>, //菜单图标
     pub remark: Option<String>,    //备注
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub create_time: Option<DateTime>, //创建时间
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub update_time: Option<DateTime>, //修改时间
 }
 
 /*
 查询菜单信息列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MenuListSimpleDataResp {
     pub id: Option<i64>,        //主键
     pub menu_name: String,      //菜单名称
     pub parent_id: Option<i64>, //父ID
 }
+
+// 菜单必须填写路由路径,按钮必须填写接口URL
+fn validate_menu(req: &MenuReq) -> Result<(), ValidationError> {
+    let is_empty = |x: &Option<String>| x.as_deref().unwrap_or_default().trim().is_empty();
+    if req.menu_type == 2 && is_empty(&req.menu_url) {
+        return Err(cross_field_error("menuUrl", "validation.required"));
+    }
+    if req.menu_type == 3 && is_empty(&req.api_url) {
+        return Err(cross_field_error("apiUrl", "validation.required"));
+    }
+    Ok(())
+}
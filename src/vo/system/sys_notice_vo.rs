@@ -1,69 +1,91 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use crate::common::page::PageQuery;
 use crate::common::result::serialize_datetime;
+use crate::common::valid::cross_field_error;
 use rbatis::rbdc::DateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
 
 /*
 删除通知公告表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DeleteNoticeReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
 }
 
 /*
 更新通知公告表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_notice"))]
 #[serde(rename_all = "camelCase")]
 pub struct NoticeReq {
-    pub id: Option<i64>,        //公告ID
-    pub notice_title: String,   //公告标题
-    pub notice_type: i8,        //公告类型（1:通知,2:公告）
+    pub id: Option<i64>, //公告ID
+    #[validate(length(min = 1, max = 50, message = "validation.length_between"))]
+    pub notice_title: String, //公告标题
+    #[validate(range(min = 1, max = 2, message = "validation.range_between"))]
+    pub notice_type: i8, //公告类型（1:通知,2:公告）
+    #[validate(length(max = 255, message = "validation.length_max"))]
     pub notice_content: String, //公告内容
-    pub status: i8,             //公告状态（0:关闭,1:正常 ）
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: i8, //公告状态（0:关闭,1:正常 ）
+    #[serde(default)]
+    #[validate(range(min = 0, max = 3, message = "validation.range_between"))]
+    pub target_type: i8, //发送范围（0:全部用户,1:指定部门,2:指定角色,3:指定用户）
+    #[serde(default)]
+    pub target_ids: Vec<i64>, //发送对象id
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub publish_time: Option<DateTime>, //定时发布时间(为空或早于当前时间时立即生效)
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub expire_time: Option<DateTime>, //过期时间(到期后自动关闭)
+    #[validate(length(max = 255, message = "validation.length_max"))]
     pub remark: Option<String>, //备注
 }
 
 /*
 更新通知公告表状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateNoticeStatusReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: i8,
 }
 
 /*
 查询通知公告表详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct QueryNoticeDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 查询通知公告表列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryNoticeListReq {
-    pub page_no: u64,
-    pub page_size: u64,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
     pub notice_title: Option<String>, //公告标题
-    pub notice_type: Option<i8>,      //公告类型（1:通知,2:公告）
-    #[serde(default = "default_status")]
+    #[validate(range(min = 1, max = 2, message = "validation.range_between"))]
+    pub notice_type: Option<i8>, //公告类型（1:通知,2:公告）
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: Option<i8>, //公告状态（0:关闭,1:正常 ）
 }
-fn default_status() -> Option<i8> {
-    Some(2)
-}
 /*
 查询通知公告表列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NoticeResp {
     pub id: Option<i64>,        //公告ID
@@ -71,9 +93,135 @@ pub struct NoticeResp {
     pub notice_type: i8,        //公告类型（1:通知,2:公告）
     pub notice_content: String, //公告内容
     pub status: i8,             //公告状态（0:关闭,1:正常 ）
+    pub target_type: i8,        //发送范围（0:全部用户,1:指定部门,2:指定角色,3:指定用户）
+    pub target_ids: Vec<i64>,   //发送对象id
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub publish_time: Option<DateTime>, //定时发布时间
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub expire_time: Option<DateTime>, //过期时间
+    pub scheduled: i8,          //是否等待定时发布(0:否,1:是)
     pub remark: Option<String>, //备注
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub create_time: Option<DateTime>, //创建时间
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub update_time: Option<DateTime>, //修改时间
 }
+
+/*
+查询我的通知公告列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryMyNoticeListReq {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
+    #[validate(range(min = 1, max = 2, message = "validation.range_between"))]
+    pub notice_type: Option<i8>, //公告类型（1:通知,2:公告）
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub read_status: Option<i8>, //阅读状态（0:未读,1:已读,为空时查询全部）
+}
+
+/*
+查询我的通知公告列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MyNoticeResp {
+    pub id: Option<i64>,        //公告ID
+    pub notice_title: String,   //公告标题
+    pub notice_type: i8,        //公告类型（1:通知,2:公告）
+    pub notice_content: String, //公告内容
+    pub is_read: bool,          //是否已读
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub read_time: Option<DateTime>, //阅读时间
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub create_time: Option<DateTime>, //创建时间
+}
+
+/*
+查询我的未读数量响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MyUnreadCountResp {
+    pub total: u64,        //未读总数
+    pub notice: u64,       //未读通知数
+    pub announcement: u64, //未读公告数
+}
+
+/*
+标记通知公告已读请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ReadNoticeReq {
+    #[validate(length(min = 1, message = "validation.required"))]
+    pub ids: Vec<i64>,
+}
+
+/*
+查询通知公告阅读统计请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct QueryNoticeReadStatsReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub id: i64,
+}
+
+/*
+查询通知公告阅读统计响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeReadStatsResp {
+    pub notice_id: i64,    //公告ID
+    pub target_count: u64, //发送人数
+    pub read_count: u64,   //已读人数
+    pub unread_count: u64, //未读人数
+}
+
+/*
+查询通知公告已读用户列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryNoticeReadUserListReq {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub notice_id: i64, //公告ID
+}
+
+/*
+查询通知公告已读用户列表响应参数
+*/
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NoticeReadUserResp {
+    pub user_id: i64,      //用户ID
+    pub user_name: String, //用户账号
+    pub nick_name: String, //用户昵称
+    #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
+    pub read_time: Option<DateTime>, //阅读时间
+}
+
+// 指定发送范围时必须选择发送对象,过期时间必须晚于发布时间
+fn validate_notice(req: &NoticeReq) -> Result<(), ValidationError> {
+    if req.target_type != 0 && req.target_ids.is_empty() {
+        return Err(cross_field_error("targetIds", "NOTICE_TARGET_REQUIRED"));
+    }
+    if let (Some(publish_time), Some(expire_time)) = (&req.publish_time, &req.expire_time) {
+        if !expire_time.after(publish_time) {
+            return Err(cross_field_error("expireTime", "NOTICE_EXPIRE_BEFORE_PUBLISH"));
+        }
+    }
+    Ok(())
+}
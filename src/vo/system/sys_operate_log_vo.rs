@@ -1,53 +1,96 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use crate::common::page::{CursorQuery, PageQuery};
 use crate::common::result::serialize_datetime;
+use crate::common::valid::cross_field_error;
 use rbatis::rbdc::DateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
 /*
 删除操作日志记录请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DeleteOperateLogReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
 }
 
+/*
+按时间范围删除操作日志记录请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_delete_by_time"))]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteOperateLogByTimeReq {
+    #[schema(value_type = String, example = "2026-01-01 00:00:00")]
+    pub begin_time: DateTime, //开始时间
+    #[schema(value_type = String, example = "2026-01-01 00:00:00")]
+    pub end_time: DateTime, //结束时间
+}
+
 /*
 查询操作日志记录详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct QueryOperateLogDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 查询操作日志记录列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryOperateLogListReq {
-    pub page_no: u64,
-    pub page_size: u64,
-    pub title: Option<String>,            //模块标题
-    pub business_type: Option<i8>,        //业务类型（0其它 1新增 2修改 3删除）
-    pub method: Option<String>,           //方法名称
-    pub request_method: Option<String>,   //请求方式
-    pub operator_type: Option<i8>,        //操作类别（0其它 1后台用户 2手机端用户）
-    pub operate_name: Option<String>,     //操作人员
-    pub dept_name: Option<String>,        //部门名称
-    pub operate_url: Option<String>,      //请求URL
-    pub operate_ip: Option<String>,       //主机地址
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
+    pub title: Option<String>, //模块标题
+    #[validate(range(min = 0, max = 3, message = "validation.range_between"))]
+    pub business_type: Option<i8>, //业务类型（0其它 1新增 2修改 3删除）
+    pub method: Option<String>, //方法名称
+    pub request_method: Option<String>, //请求方式
+    #[validate(range(min = 0, max = 2, message = "validation.range_between"))]
+    pub operator_type: Option<i8>, //操作类别（0其它 1后台用户 2手机端用户）
+    pub operate_name: Option<String>, //操作人员
+    pub dept_name: Option<String>, //部门名称
+    pub operate_url: Option<String>, //请求URL
+    pub operate_ip: Option<String>, //主机地址
     pub operate_location: Option<String>, //操作地点
-    #[serde(default = "default_status")]
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: Option<i8>, //操作状态(0:异常,正常)
 }
-fn default_status() -> Option<i8> {
-    Some(2)
+/*
+游标分页查询操作日志记录列表请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOperateLogCursorListReq {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: CursorQuery, //游标分页和时间范围
+    pub title: Option<String>, //模块标题
+    #[validate(range(min = 0, max = 3, message = "validation.range_between"))]
+    pub business_type: Option<i8>, //业务类型（0其它 1新增 2修改 3删除）
+    pub method: Option<String>, //方法名称
+    pub request_method: Option<String>, //请求方式
+    #[validate(range(min = 0, max = 2, message = "validation.range_between"))]
+    pub operator_type: Option<i8>, //操作类别（0其它 1后台用户 2手机端用户）
+    pub operate_name: Option<String>, //操作人员
+    pub dept_name: Option<String>, //部门名称
+    pub operate_url: Option<String>, //请求URL
+    pub operate_ip: Option<String>, //主机地址
+    pub operate_location: Option<String>, //操作地点
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: Option<i8>, //操作状态(0:异常,正常)
 }
 /*
 查询操作日志记录列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct OperateLogResp {
     pub id: Option<i64>,                  //日志主键
@@ -66,6 +109,15 @@ pub struct OperateLogResp {
     pub status: Option<i8>,               //操作状态(0:异常,正常)
     pub error_msg: Option<String>,        //错误消息
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub operate_time: Option<DateTime>, //操作时间
     pub cost_time: Option<i64>,           //消耗时间
 }
+
+// 结束时间必须晚于开始时间
+fn validate_delete_by_time(req: &DeleteOperateLogByTimeReq) -> Result<(), ValidationError> {
+    if !req.end_time.after(&req.begin_time) {
+        return Err(cross_field_error("endTime", "TIME_RANGE_INVALID"));
+    }
+    Ok(())
+}
@@ -1,69 +1,78 @@
 // author：刘飞华
 // createTime：2024/12/25 10:01:11
 
+use crate::common::page::PageQuery;
 use crate::common::result::serialize_datetime;
 use rbatis::rbdc::DateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 /*
 删除岗位信息表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DeletePostReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
 }
 
 /*
 更新岗位信息表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PostReq {
-    pub id: Option<i64>,        //岗位id
-    pub post_code: String,      //岗位编码
-    pub post_name: String,      //岗位名称
-    pub sort: i32,              //显示顺序
-    pub status: i8,             //部状态（0：停用，1:正常）
+    pub id: Option<i64>, //岗位id
+    #[validate(length(min = 1, max = 64, message = "validation.length_between"))]
+    pub post_code: String, //岗位编码
+    #[validate(length(min = 1, max = 50, message = "validation.length_between"))]
+    pub post_name: String, //岗位名称
+    pub sort: i32,       //显示顺序
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: i8, //部状态（0：停用，1:正常）
+    #[validate(length(max = 500, message = "validation.length_max"))]
     pub remark: Option<String>, //备注
 }
 
 /*
 更新岗位信息表状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdatePostStatusReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: i8,
 }
 
 /*
 查询岗位信息表详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct QueryPostDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 查询岗位信息表列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryPostListReq {
-    pub page_no: u64,
-    pub page_size: u64,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
     pub post_code: Option<String>, //岗位编码
     pub post_name: Option<String>, //岗位名称
-    #[serde(default = "default_status")]
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: Option<i8>, //部状态（0：停用，1:正常）
 }
-fn default_status() -> Option<i8> {
-    Some(2)
-}
 /*
 查询岗位信息表列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PostResp {
     pub id: Option<i64>,        //岗位id
@@ -73,7 +82,9 @@ pub struct PostResp {
     pub status: i8,             //部状态（0：停用，1:正常）
     pub remark: Option<String>, //备注
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub create_time: Option<DateTime>, //创建时间
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub update_time: Option<DateTime>, //修改时间
 }
@@ -1,69 +1,79 @@
 // author：刘飞华
 // createTime：2024/12/12 14:41:44
 
+use crate::common::page::PageQuery;
 use crate::common::result::serialize_datetime;
 use rbatis::rbdc::DateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
 
 /*
 删除角色信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DeleteRoleReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
 }
 
 /*
 更新角色信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RoleReq {
-    pub id: Option<i64>,        //主键
-    pub role_name: String,      //名称
-    pub role_key: String,       //角色权限字符串
-    pub data_scope: i8,         //数据范围（1：全部数据权限 2：自定数据权限 3：本部门数据权限 4：本部门及以下数据权限）
-    pub status: i8,             //状态(1:正常，0:禁用)
+    pub id: Option<i64>, //主键
+    #[validate(length(min = 1, max = 50, message = "validation.length_between"))]
+    pub role_name: String, //名称
+    #[validate(length(min = 1, max = 100, message = "validation.length_between"))]
+    pub role_key: String, //角色权限字符串
+    #[validate(range(min = 1, max = 4, message = "validation.range_between"))]
+    pub data_scope: i8, //数据范围（1：全部数据权限 2：自定数据权限 3：本部门数据权限 4：本部门及以下数据权限）
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: i8, //状态(1:正常，0:禁用)
+    #[validate(length(max = 255, message = "validation.length_max"))]
     pub remark: Option<String>, //备注
 }
 
 /*
 更新角色信息状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateRoleStatusReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: i8,
 }
 
 /*
 查询角色信息详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct QueryRoleDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 查询角色信息列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryRoleListReq {
-    pub page_no: u64,
-    pub page_size: u64,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
     pub role_name: Option<String>, //名称
-    #[serde(default = "default_status")]
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: Option<i8>, //状态(1:正常，0:禁用)
     pub role_key: Option<String>,  //角色权限字符串
 }
-fn default_status() -> Option<i8> {
-    Some(2)
-}
 /*
 查询角色信息列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RoleResp {
     pub id: Option<i64>,        //主键
@@ -73,24 +83,27 @@ pub struct RoleResp {
     pub status: i8,             //状态(1:正常，0:禁用)
     pub remark: Option<String>, //备注
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub create_time: Option<DateTime>, //创建时间
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub update_time: Option<DateTime>, //修改时间
 }
 
 /*
 查询角色菜单信息参数
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryRoleMenuReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub role_id: i64, //角色id
 }
 
 /*
 角色菜单信息参数
 */
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryRoleMenuData {
     pub menu_ids: Vec<Option<i64>>,   //菜单Ids
@@ -100,7 +113,7 @@ pub struct QueryRoleMenuData {
 /*
 菜单信息参数
 */
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MenuDataList {
     pub id: Option<i64>,        //主键
@@ -114,21 +127,24 @@ pub struct MenuDataList {
 /*
 更新用户角色信息
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateRoleMenuReq {
     pub menu_ids: Vec<i64>,
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub role_id: i64,
 }
 
 /*
 查询已分配用户角色列表
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AllocatedListReq {
-    pub page_no: u64,
-    pub page_size: u64,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub role_id: i64,
     pub mobile: Option<String>,
     pub user_name: Option<String>,
@@ -137,11 +153,13 @@ pub struct AllocatedListReq {
 /*
 查询未分配用户角色列表
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UnallocatedListReq {
-    pub page_no: u64,
-    pub page_size: u64,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub role_id: i64,
     pub mobile: Option<String>,
     pub user_name: Option<String>,
@@ -150,29 +168,35 @@ pub struct UnallocatedListReq {
 /*
 取消授权用户
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelAuthUserReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub user_id: i64,
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub role_id: i64,
 }
 
 /*
 批量取消授权用户
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelAuthUserAllReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub user_ids: Vec<i64>,
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub role_id: i64,
 }
 
 /*
 批量选择用户授权
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SelectAuthUserAllReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub user_ids: Vec<i64>,
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub role_id: i64,
 }
@@ -1,79 +1,104 @@
 // author：刘飞华
 // createTime：2024/12/12 14:41:44
 
+use crate::common::page::PageQuery;
 use crate::common::result::serialize_datetime;
+use crate::common::valid::{cross_field_error, validate_optional_email, validate_optional_mobile};
 use crate::vo::system::sys_dept_vo::DeptResp;
+use crate::vo::system::sys_post_vo::PostResp;
 use crate::vo::system::sys_role_vo::RoleResp;
 use rbatis::rbdc::DateTime;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
 /*
 删除用户信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct DeleteUserReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
 }
 
 /*
 更新用户信息请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserReq {
-    pub id: Option<i64>,          //主键
-    pub mobile: String,           //手机
-    pub user_name: String,        //用户账号
-    pub nick_name: String,        //用户昵称
+    pub id: Option<i64>, //主键
+    #[validate(custom(function = "validate_optional_mobile"))]
+    pub mobile: String, //手机
+    #[validate(length(min = 1, max = 50, message = "validation.length_between"))]
+    pub user_name: String, //用户账号
+    #[validate(length(min = 1, max = 30, message = "validation.length_between"))]
+    pub nick_name: String, //用户昵称
+    #[validate(length(max = 64, message = "validation.length_max"))]
     pub password: Option<String>, //用户密码
-    pub email: String,            //用户邮箱
+    #[validate(custom(function = "validate_optional_email"), length(max = 50, message = "validation.length_max"))]
+    pub email: String, //用户邮箱
     #[serde(default = "default_avatar")]
+    #[validate(length(max = 100, message = "validation.length_max"))]
     pub avatar: Option<String>, //头像路径
-    pub status: i8,               //状态(1:正常，0:禁用)
-    pub dept_id: i64,             //部门ID
-    pub remark: Option<String>,   //备注
-    pub post_ids: Vec<i64>,       //岗位ids
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
+    pub status: i8, //状态(1:正常，0:禁用)
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub dept_id: i64, //部门ID
+    #[validate(length(max = 255, message = "validation.length_max"))]
+    pub remark: Option<String>, //备注
+    pub post_ids: Vec<i64>, //岗位ids
 }
 fn default_avatar() -> Option<String> {
     Some("https://gw.alipayobjects.com/zos/antfincdn/XAosXuNZyF/BiazfanxmamNRoxxVxka.png".to_string())
 }
+/*
+强制用户下线请求参数
+*/
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ForceLogoutReq {
+    #[validate(length(min = 1, message = "validation.required"))]
+    pub ids: Vec<i64>,
+}
+
 /*
 更新用户信息状态请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateUserStatusReq {
+    #[validate(length(min = 1, message = "validation.required"))]
     pub ids: Vec<i64>,
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: i8,
 }
 
 /*
 查询用户信息详情请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct QueryUserDetailReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub id: i64,
 }
 
 /*
 查询用户信息列表请求参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryUserListReq {
-    pub page_no: u64,
-    pub page_size: u64,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub page: PageQuery, //分页、排序和时间范围
     pub mobile: Option<String>,    //手机
     pub user_name: Option<String>, //姓名
-    #[serde(default = "default_status")]
+    #[validate(range(min = 0, max = 1, message = "validation.range_between"))]
     pub status: Option<i8>, //状态(1:正常，0:禁用)
     pub dept_id: Option<i64>,      //部门ID
 }
-fn default_status() -> Option<i8> {
-    Some(2)
-}
 /*
 查询用户信息列表响应参数
 */
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UserResp {
     pub id: Option<i64>,        //主键
@@ -87,15 +112,21 @@ pub struct UserResp {
     pub dept_id: i64,           //部门ID
     pub login_ip: String,       //最后登录IP
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub login_date: Option<DateTime>, //最后登录时间
     pub login_browser: String,  //浏览器类型
     pub login_os: String,       //操作系统
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub pwd_update_date: Option<DateTime>, //密码最后更新时间
+    pub pwd_must_change: i8,    //是否需要修改密码(0:否,1:是)
+    pub locale: String,         //语言偏好
     pub remark: Option<String>, //备注
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub create_time: Option<DateTime>, //创建时间
     #[serde(serialize_with = "serialize_datetime")]
+    #[schema(value_type = Option<String>, example = "2026-01-01 00:00:00")]
     pub update_time: Option<DateTime>, //修改时间
     pub dept_info: Option<DeptResp>, //部门详细信息
     pub post_ids: Option<Vec<i64>>, //岗位ids
@@ -103,16 +134,98 @@ pub struct UserResp {
 /*
 登录请求参数
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserLoginReq {
-    pub mobile: String,   //手机
+    #[serde(alias = "mobile")]
+    #[validate(length(min = 1, max = 50, message = "validation.length_between"))]
+    pub account: String, //登录账号(手机、用户账号或邮箱,兼容旧的mobile字段)
+    #[validate(length(min = 1, max = 64, message = "validation.length_between"))]
     pub password: String, //密码
+    pub account_type: Option<String>, //账号类型(mobile:手机,username:用户账号,email:邮箱),为空时自动识别
+}
+
+/*
+发送短信登录验证码请求参数
+*/
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SendLoginCodeReq {
+    #[validate(length(min = 1, max = 20, message = "validation.length_between"))]
+    pub mobile: String, //手机
+}
+
+/*
+短信验证码登录请求参数
+*/
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct LoginByCodeReq {
+    #[validate(length(min = 1, max = 20, message = "validation.length_between"))]
+    pub mobile: String, //手机
+    #[validate(length(min = 1, max = 10, message = "validation.length_between"))]
+    pub code: String, //短信验证码
+}
+
+/*
+单点登录回调请求参数
+*/
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct OidcCallbackReq {
+    #[validate(length(min = 1, max = 2048, message = "validation.length_between"))]
+    pub code: String, //身份提供方返回的授权码
+    #[validate(length(min = 1, max = 128, message = "validation.length_between"))]
+    pub state: String, //单点登录接口生成的state
+}
+
+/*
+登录二次验证请求参数
+*/
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginVerifyReq {
+    #[validate(length(min = 1, max = 128, message = "validation.length_between"))]
+    pub verify_token: String, //登录接口返回的验证标识
+    #[validate(length(min = 1, max = 10, message = "validation.length_between"))]
+    pub code: String, //验证码
+}
+
+/*
+登录时修改过期密码请求参数
+*/
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginUpdatePwdReq {
+    #[validate(length(min = 1, max = 128, message = "validation.length_between"))]
+    pub pwd_token: String, //登录接口返回的修改密码凭证
+    #[validate(length(min = 1, max = 64, message = "validation.length_between"))]
+    pub password: String, //新密码
+}
+
+/*
+找回密码请求参数
+*/
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ForgotPwdReq {
+    #[validate(email(message = "validation.email"), length(max = 50, message = "validation.length_max"))]
+    pub email: String, //用户邮箱
+}
+
+/*
+找回密码设置新密码请求参数
+*/
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ConfirmResetPwdReq {
+    #[validate(email(message = "validation.email"), length(max = 50, message = "validation.length_max"))]
+    pub email: String, //用户邮箱
+    #[validate(length(min = 1, max = 10, message = "validation.length_between"))]
+    pub code: String, //邮件中的验证码
+    #[validate(length(min = 1, max = 64, message = "validation.length_between"))]
+    pub password: String, //新密码
 }
 
 /*
 查询用户菜单响应参数
 */
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryUserMenuResp {
     pub sys_menu: Vec<MenuList>,
@@ -124,7 +237,7 @@ pub struct QueryUserMenuResp {
 /*
 用户菜单参数
 */
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MenuList {
     pub id: Option<i64>,
@@ -139,16 +252,17 @@ pub struct MenuList {
 /*
 查询用户关联角色请求参数
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryUserRoleReq {
+    #[validate(range(min = 1, message = "validation.range_min"))]
     pub user_id: i64,
 }
 
 /*
 用户关联角色响应参数
 */
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryUserRoleResp {
     pub sys_role_list: Vec<RoleResp>,
@@ -158,28 +272,76 @@ pub struct QueryUserRoleResp {
 /*
 更新用户关联角色请求参数
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateUserRoleReq {
-    pub user_id: i64,       //用户主键
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub user_id: i64, //用户主键
     pub role_ids: Vec<i64>, //角色主键
 }
 
 /*
 重置密码
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct ResetUserPwdReq {
-    pub id: i64,          //用户主键
+    #[validate(range(min = 1, message = "validation.range_min"))]
+    pub id: i64, //用户主键
+    #[validate(length(min = 1, max = 64, message = "validation.length_between"))]
     pub password: String, //用户密码
 }
 
 /*
 重置密码
 */
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_update_user_pwd"))]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateUserPwdReq {
-    pub pwd: String,    //用户密码
+    #[validate(length(min = 1, max = 64, message = "validation.length_between"))]
+    pub pwd: String, //用户密码
+    #[validate(length(min = 1, max = 64, message = "validation.length_between"))]
     pub re_pwd: String, //用户密码
 }
+
+/*
+个人信息响应参数
+*/
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileResp {
+    pub user: UserResp,           //用户信息(包含部门信息)
+    pub post_list: Vec<PostResp>, //岗位列表
+    pub role_list: Vec<RoleResp>, //角色列表
+}
+
+/*
+修改个人信息请求参数
+*/
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProfileReq {
+    #[validate(length(min = 1, max = 30, message = "validation.length_between"))]
+    pub nick_name: String, //用户昵称
+    #[validate(custom(function = "validate_optional_email"), length(max = 50, message = "validation.length_max"))]
+    pub email: String, //用户邮箱
+    #[validate(custom(function = "validate_optional_mobile"))]
+    pub mobile: String, //手机
+}
+
+/*
+修改语言偏好请求参数
+*/
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateLocaleReq {
+    #[validate(length(max = 10, message = "validation.length_max"))]
+    pub locale: String, //语言偏好(zh-CN,en-US,为空时根据请求头Accept-Language)
+}
+
+// 新密码不能与旧密码相同
+fn validate_update_user_pwd(req: &UpdateUserPwdReq) -> Result<(), ValidationError> {
+    if req.pwd == req.re_pwd {
+        return Err(cross_field_error("rePwd", "PASSWORD_UNCHANGED"));
+    }
+    Ok(())
+}